@@ -107,4 +107,523 @@ CREATE TABLE IF NOT EXISTS project (
 );
 
 CREATE INDEX IF NOT EXISTS idx_project_name ON project (name);
-CREATE INDEX IF NOT EXISTS idx_project_status ON project (status);
\ No newline at end of file
+CREATE INDEX IF NOT EXISTS idx_project_status ON project (status);
+
+DO $$ BEGIN
+  IF NOT EXISTS (
+    SELECT 1 FROM information_schema.columns WHERE table_name = 'users' AND column_name = 'is_compliant'
+  ) THEN
+    ALTER TABLE users ADD COLUMN is_compliant BOOLEAN NOT NULL DEFAULT false;
+    UPDATE users SET is_compliant = true WHERE type = 'Vendor';
+  END IF;
+END $$;
+
+CREATE TABLE IF NOT EXISTS compliance_documents (
+  id                SERIAL PRIMARY KEY,
+  user_id           VARCHAR(36) NOT NULL,
+  type              VARCHAR(16) NOT NULL,
+  number            VARCHAR(64),
+  issued_at         timestamp NOT NULL,
+  expires_at        timestamp NOT NULL,
+  reminded_days     INT,
+  created_at        timestamp NOT NULL DEFAULT NOW(),
+  updated_at        timestamp DEFAULT NOW(),
+
+  CONSTRAINT uq_compliance_documents_user_type UNIQUE (user_id, type),
+
+  CONSTRAINT fk_compliance_documents_user
+    FOREIGN KEY(user_id) 
+      REFERENCES users(id)
+        ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_compliance_documents_expires_at ON compliance_documents (expires_at);
+
+CREATE EXTENSION IF NOT EXISTS pg_trgm;
+
+ALTER TABLE users ADD COLUMN IF NOT EXISTS company VARCHAR(128);
+
+CREATE OR REPLACE FUNCTION user_search_text(first_name TEXT, last_name TEXT, company TEXT) RETURNS TEXT
+  LANGUAGE sql IMMUTABLE PARALLEL SAFE
+  AS $$ SELECT lower(concat_ws(' ', first_name, last_name, company)) $$;
+
+CREATE INDEX IF NOT EXISTS idx_users_search ON users
+  USING gin (user_search_text(first_name, last_name, company) gin_trgm_ops);
+CREATE INDEX IF NOT EXISTS idx_user_emails_search ON user_emails USING gin (lower(email) gin_trgm_ops);
+CREATE INDEX IF NOT EXISTS idx_users_created_at ON users (created_at);
+
+ALTER TABLE user_tokens ADD COLUMN IF NOT EXISTS created_at timestamp NOT NULL DEFAULT NOW();
+
+CREATE TABLE IF NOT EXISTS admin_audit (
+  id                SERIAL PRIMARY KEY,
+  admin_id          VARCHAR(36),
+  target_user_id    VARCHAR(36) NOT NULL,
+  action            VARCHAR(32) NOT NULL,
+  details           JSONB NOT NULL DEFAULT '{}',
+  created_at        timestamp NOT NULL DEFAULT NOW(),
+
+  CONSTRAINT fk_admin_audit_admin
+    FOREIGN KEY(admin_id) 
+      REFERENCES users(id)
+        ON DELETE SET NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_admin_audit_target ON admin_audit (target_user_id);
+
+CREATE TABLE IF NOT EXISTS user_preferences (
+  user_id           VARCHAR(36) NOT NULL PRIMARY KEY,
+  locale            VARCHAR(16) NOT NULL,
+  timezone          VARCHAR(64) NOT NULL,
+  date_format       VARCHAR(16) NOT NULL,
+  channels          JSONB NOT NULL DEFAULT '{}',
+  updated_at        timestamp DEFAULT NOW(),
+
+  CONSTRAINT fk_user_preferences_user
+    FOREIGN KEY(user_id) 
+      REFERENCES users(id)
+        ON DELETE CASCADE
+);
+
+CREATE TABLE IF NOT EXISTS organizations (
+  id                VARCHAR(36) NOT NULL PRIMARY KEY DEFAULT uuid_generate_v4(),
+  name              VARCHAR(128) NOT NULL,
+  type              VARCHAR(8) NOT NULL,
+  created_at        timestamp NOT NULL DEFAULT NOW(),
+  updated_at        timestamp DEFAULT NOW()
+);
+
+CREATE TABLE IF NOT EXISTS organization_members (
+  organization_id   VARCHAR(36) NOT NULL,
+  user_id           VARCHAR(36) NOT NULL,
+  role              VARCHAR(16) NOT NULL,
+  created_at        timestamp NOT NULL DEFAULT NOW(),
+
+  PRIMARY KEY (organization_id, user_id),
+
+  CONSTRAINT fk_organization_members_organization
+    FOREIGN KEY(organization_id) 
+      REFERENCES organizations(id)
+        ON DELETE CASCADE,
+
+  CONSTRAINT fk_organization_members_user
+    FOREIGN KEY(user_id) 
+      REFERENCES users(id)
+        ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_organization_members_user ON organization_members (user_id);
+
+ALTER TABLE project ADD COLUMN IF NOT EXISTS organization_id VARCHAR(36);
+
+DO $$ BEGIN
+  ALTER TABLE project ADD CONSTRAINT fk_project_organization
+    FOREIGN KEY(organization_id) REFERENCES organizations(id) ON DELETE SET NULL;
+EXCEPTION WHEN duplicate_object THEN NULL;
+END $$;
+
+CREATE INDEX IF NOT EXISTS idx_project_organization ON project (organization_id);
+
+ALTER TABLE project ADD COLUMN IF NOT EXISTS vendor_id VARCHAR(36);
+
+DO $$ BEGIN
+  ALTER TABLE project ADD CONSTRAINT fk_project_vendor
+    FOREIGN KEY(vendor_id) REFERENCES users(id) ON DELETE SET NULL;
+EXCEPTION WHEN duplicate_object THEN NULL;
+END $$;
+
+CREATE INDEX IF NOT EXISTS idx_project_vendor ON project (vendor_id);
+
+CREATE TABLE IF NOT EXISTS reviews (
+  id                SERIAL PRIMARY KEY,
+  project_id        VARCHAR(36) NOT NULL UNIQUE,
+  author_id         VARCHAR(36) NOT NULL,
+  vendor_id         VARCHAR(36) NOT NULL,
+  quality           SMALLINT NOT NULL CHECK (quality BETWEEN 1 AND 5),
+  communication     SMALLINT NOT NULL CHECK (communication BETWEEN 1 AND 5),
+  timeliness        SMALLINT NOT NULL CHECK (timeliness BETWEEN 1 AND 5),
+  value             SMALLINT NOT NULL CHECK (value BETWEEN 1 AND 5),
+  text              TEXT NOT NULL,
+  reply             TEXT,
+  replied_at        timestamp,
+  is_hidden         BOOLEAN NOT NULL DEFAULT false,
+  moderation_note   TEXT,
+  created_at        timestamp NOT NULL DEFAULT NOW(),
+  updated_at        timestamp DEFAULT NOW(),
+
+  CONSTRAINT fk_reviews_project
+    FOREIGN KEY(project_id) 
+      REFERENCES project(id)
+        ON DELETE CASCADE,
+
+  CONSTRAINT fk_reviews_author
+    FOREIGN KEY(author_id) 
+      REFERENCES users(id)
+        ON DELETE CASCADE,
+
+  CONSTRAINT fk_reviews_vendor
+    FOREIGN KEY(vendor_id) 
+      REFERENCES users(id)
+        ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_reviews_vendor ON reviews (vendor_id, is_hidden);
+
+CREATE INDEX IF NOT EXISTS idx_project_city ON project (lower(city));
+CREATE INDEX IF NOT EXISTS idx_project_completion_date ON project (completion_date);
+CREATE INDEX IF NOT EXISTS idx_project_created_at ON project (created_at);
+
+ALTER TABLE project ALTER COLUMN status TYPE VARCHAR(16);
+
+CREATE TABLE IF NOT EXISTS project_status_history (
+  id                SERIAL PRIMARY KEY,
+  project_id        VARCHAR(36) NOT NULL,
+  from_status       VARCHAR(16) NOT NULL,
+  to_status         VARCHAR(16) NOT NULL,
+  actor_id          VARCHAR(36),
+  reason            TEXT,
+  created_at        timestamp NOT NULL DEFAULT NOW(),
+
+  CONSTRAINT fk_project_status_history_project
+    FOREIGN KEY(project_id) 
+      REFERENCES project(id)
+        ON DELETE CASCADE,
+
+  CONSTRAINT fk_project_status_history_actor
+    FOREIGN KEY(actor_id) 
+      REFERENCES users(id)
+        ON DELETE SET NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_project_status_history_project ON project_status_history (project_id, created_at);
+
+CREATE INDEX IF NOT EXISTS idx_project_status_completion ON project (status, completion_date);
+
+ALTER TABLE project
+  ALTER COLUMN city DROP NOT NULL,
+  ALTER COLUMN building_type DROP NOT NULL,
+  ALTER COLUMN is_save_carbon DROP NOT NULL,
+  ALTER COLUMN appropriate_status DROP NOT NULL,
+  ALTER COLUMN budget_range DROP NOT NULL,
+  ALTER COLUMN square_range DROP NOT NULL,
+  ALTER COLUMN commercial_work DROP NOT NULL,
+  ALTER COLUMN has_financing_secured DROP NOT NULL,
+  ALTER COLUMN completion_date DROP NOT NULL;
+
+DO $$ BEGIN
+  ALTER TABLE project ADD CONSTRAINT chk_project_complete CHECK (
+    status = 'Draft' OR (
+      city IS NOT NULL
+      AND building_type IS NOT NULL
+      AND is_save_carbon IS NOT NULL
+      AND appropriate_status IS NOT NULL
+      AND budget_range IS NOT NULL
+      AND square_range IS NOT NULL
+      AND commercial_work IS NOT NULL
+      AND has_financing_secured IS NOT NULL
+      AND completion_date IS NOT NULL
+    )
+  );
+EXCEPTION WHEN duplicate_object THEN NULL;
+END $$;
+
+CREATE TABLE IF NOT EXISTS project_attachments (
+  id                VARCHAR(36) NOT NULL PRIMARY KEY DEFAULT uuid_generate_v4(),
+  file_id           VARCHAR(36) NOT NULL,
+  project_id        VARCHAR(36) NOT NULL,
+  category          VARCHAR(16) NOT NULL,
+  name              VARCHAR(255) NOT NULL,
+  content_type      VARCHAR(128) NOT NULL,
+  size              BIGINT NOT NULL,
+  version           INT NOT NULL,
+  uploaded_by       VARCHAR(36),
+  created_at        timestamp NOT NULL DEFAULT NOW(),
+
+  UNIQUE (file_id, version),
+
+  CONSTRAINT fk_project_attachments_project
+    FOREIGN KEY(project_id) 
+      REFERENCES project(id)
+        ON DELETE CASCADE,
+
+  CONSTRAINT fk_project_attachments_user
+    FOREIGN KEY(uploaded_by) 
+      REFERENCES users(id)
+        ON DELETE SET NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_project_attachments_project ON project_attachments (project_id);
+
+CREATE TABLE IF NOT EXISTS bids (
+  id                VARCHAR(36) NOT NULL PRIMARY KEY DEFAULT uuid_generate_v4(),
+  project_id        VARCHAR(36) NOT NULL,
+  vendor_id         VARCHAR(36) NOT NULL,
+  organization_id   VARCHAR(36),
+  price             BIGINT NOT NULL CHECK (price > 0),
+  duration_days     INT NOT NULL CHECK (duration_days > 0),
+  start_date        timestamp NOT NULL,
+  message           TEXT NOT NULL,
+  status            VARCHAR(16) NOT NULL DEFAULT 'Submitted',
+  created_at        timestamp NOT NULL DEFAULT NOW(),
+  updated_at        timestamp NOT NULL DEFAULT NOW(),
+
+  CONSTRAINT fk_bids_project
+    FOREIGN KEY(project_id) 
+      REFERENCES project(id)
+        ON DELETE CASCADE,
+
+  CONSTRAINT fk_bids_vendor
+    FOREIGN KEY(vendor_id) 
+      REFERENCES users(id)
+        ON DELETE CASCADE,
+
+  CONSTRAINT fk_bids_organization
+    FOREIGN KEY(organization_id) 
+      REFERENCES organizations(id)
+        ON DELETE SET NULL
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_bids_open ON bids (project_id, vendor_id) WHERE status <> 'Withdrawn';
+CREATE UNIQUE INDEX IF NOT EXISTS idx_bids_open_organization ON bids (project_id, organization_id) WHERE status <> 'Withdrawn';
+CREATE INDEX IF NOT EXISTS idx_bids_vendor ON bids (vendor_id);
+
+ALTER TABLE project_attachments ADD COLUMN IF NOT EXISTS bid_id VARCHAR(36);
+
+DO $$ BEGIN
+  ALTER TABLE project_attachments ADD CONSTRAINT fk_project_attachments_bid
+    FOREIGN KEY(bid_id) REFERENCES bids(id) ON DELETE CASCADE;
+EXCEPTION WHEN duplicate_object THEN NULL;
+END $$;
+
+CREATE INDEX IF NOT EXISTS idx_project_attachments_bid ON project_attachments (bid_id);
+
+ALTER TABLE bids ADD COLUMN IF NOT EXISTS awarded_at timestamp;
+ALTER TABLE bids ADD COLUMN IF NOT EXISTS awarded_by VARCHAR(36);
+ALTER TABLE bids ADD COLUMN IF NOT EXISTS award_note TEXT;
+
+DO $$ BEGIN
+  ALTER TABLE bids ADD CONSTRAINT fk_bids_awarded_by
+    FOREIGN KEY(awarded_by) REFERENCES users(id) ON DELETE SET NULL;
+EXCEPTION WHEN duplicate_object THEN NULL;
+END $$;
+
+CREATE TABLE IF NOT EXISTS milestones (
+  id                VARCHAR(36) NOT NULL PRIMARY KEY DEFAULT uuid_generate_v4(),
+  project_id        VARCHAR(36) NOT NULL,
+  title             VARCHAR(255) NOT NULL,
+  due_date          timestamp NOT NULL,
+  owner             VARCHAR(16) NOT NULL,
+  status            VARCHAR(16) NOT NULL DEFAULT 'Pending',
+  position          INT NOT NULL,
+  completed_at      timestamp,
+  slip_notified_at  timestamp,
+  created_at        timestamp NOT NULL DEFAULT NOW(),
+  updated_at        timestamp NOT NULL DEFAULT NOW(),
+
+  CONSTRAINT fk_milestones_project
+    FOREIGN KEY(project_id) 
+      REFERENCES project(id)
+        ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_milestones_project ON milestones (project_id, position);
+CREATE INDEX IF NOT EXISTS idx_milestones_due ON milestones (status, due_date) WHERE slip_notified_at IS NULL;
+
+CREATE TABLE IF NOT EXISTS milestone_tasks (
+  id                SERIAL PRIMARY KEY,
+  milestone_id      VARCHAR(36) NOT NULL,
+  title             VARCHAR(255) NOT NULL,
+  position          INT NOT NULL,
+  is_done           BOOLEAN NOT NULL DEFAULT false,
+  completed_at      timestamp,
+  created_at        timestamp NOT NULL DEFAULT NOW(),
+
+  CONSTRAINT fk_milestone_tasks_milestone
+    FOREIGN KEY(milestone_id) 
+      REFERENCES milestones(id)
+        ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_milestone_tasks_milestone ON milestone_tasks (milestone_id, position);
+
+ALTER TABLE project ADD COLUMN IF NOT EXISTS archived_at timestamp;
+ALTER TABLE project ADD COLUMN IF NOT EXISTS deleted_at timestamp;
+ALTER TABLE project ADD COLUMN IF NOT EXISTS deleted_by VARCHAR(36);
+
+DO $$ BEGIN
+  ALTER TABLE project ADD CONSTRAINT fk_project_deleted_by
+    FOREIGN KEY(deleted_by) REFERENCES users(id) ON DELETE SET NULL;
+EXCEPTION WHEN duplicate_object THEN NULL;
+END $$;
+
+DO $$ BEGIN
+  IF EXISTS (SELECT 1 FROM pg_constraint WHERE conname = 'fk_project_creator' AND confdeltype = 'c') THEN
+    ALTER TABLE project DROP CONSTRAINT fk_project_creator;
+    ALTER TABLE project ADD CONSTRAINT fk_project_creator
+      FOREIGN KEY(user_id) REFERENCES users(id) ON DELETE RESTRICT;
+  END IF;
+END $$;
+
+CREATE INDEX IF NOT EXISTS idx_project_deleted ON project (deleted_at) WHERE deleted_at IS NOT NULL;
+
+CREATE TABLE IF NOT EXISTS project_changes (
+  id                SERIAL PRIMARY KEY,
+  project_id        VARCHAR(36) NOT NULL,
+  field             VARCHAR(32) NOT NULL,
+  old_value         JSONB NOT NULL DEFAULT 'null',
+  new_value         JSONB NOT NULL DEFAULT 'null',
+  actor_id          VARCHAR(36),
+  created_at        timestamp NOT NULL DEFAULT NOW(),
+
+  CONSTRAINT fk_project_changes_project
+    FOREIGN KEY(project_id) 
+      REFERENCES project(id)
+        ON DELETE CASCADE,
+
+  CONSTRAINT fk_project_changes_actor
+    FOREIGN KEY(actor_id) 
+      REFERENCES users(id)
+        ON DELETE SET NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_project_changes_project ON project_changes (project_id, created_at);
+
+CREATE TABLE IF NOT EXISTS gazetteer (
+  id                SERIAL PRIMARY KEY,
+  country_code      VARCHAR(2) NOT NULL,
+  zip_code          VARCHAR(20) NOT NULL,
+  city              VARCHAR(180) NOT NULL,
+  latitude          DOUBLE PRECISION NOT NULL,
+  longitude         DOUBLE PRECISION NOT NULL,
+
+  CONSTRAINT uq_gazetteer_place UNIQUE (country_code, zip_code, city)
+);
+
+CREATE INDEX IF NOT EXISTS idx_gazetteer_zip_code ON gazetteer (zip_code);
+CREATE INDEX IF NOT EXISTS idx_gazetteer_city ON gazetteer (lower(city));
+
+ALTER TABLE project
+  ADD COLUMN IF NOT EXISTS latitude DOUBLE PRECISION,
+  ADD COLUMN IF NOT EXISTS longitude DOUBLE PRECISION;
+
+CREATE INDEX IF NOT EXISTS idx_project_location ON project (latitude, longitude);
+
+ALTER TABLE users
+  ADD COLUMN IF NOT EXISTS city VARCHAR(180),
+  ADD COLUMN IF NOT EXISTS zip_code VARCHAR(20),
+  ADD COLUMN IF NOT EXISTS latitude DOUBLE PRECISION,
+  ADD COLUMN IF NOT EXISTS longitude DOUBLE PRECISION;
+
+CREATE TABLE IF NOT EXISTS vendor_capabilities (
+  user_id                 VARCHAR(36) NOT NULL PRIMARY KEY,
+  commercial_works        text[] NOT NULL DEFAULT '{}',
+  architectural_services  text[] NOT NULL DEFAULT '{}',
+  budget_ranges           text[] NOT NULL DEFAULT '{}',
+  square_ranges           text[] NOT NULL DEFAULT '{}',
+  is_save_carbon          BOOLEAN NOT NULL DEFAULT false,
+  service_cities          text[] NOT NULL DEFAULT '{}',
+  service_radius          INT,
+  updated_at              timestamp NOT NULL DEFAULT NOW(),
+
+  CONSTRAINT fk_vendor_capabilities_user
+    FOREIGN KEY(user_id) 
+      REFERENCES users(id)
+        ON DELETE CASCADE
+);
+
+DO $$ BEGIN
+  IF NOT EXISTS (
+    SELECT 1 FROM information_schema.columns WHERE table_name = 'project' AND column_name = 'alerted_at'
+  ) THEN
+    ALTER TABLE project ADD COLUMN alerted_at timestamp;
+    UPDATE project SET alerted_at = NOW() WHERE status <> 'Draft';
+  END IF;
+END $$;
+
+CREATE TABLE IF NOT EXISTS saved_searches (
+  id                VARCHAR(36) NOT NULL PRIMARY KEY DEFAULT uuid_generate_v4(),
+  user_id           VARCHAR(36) NOT NULL,
+  name              VARCHAR(64) NOT NULL,
+  query             JSONB NOT NULL DEFAULT '{}',
+  frequency         VARCHAR(16) NOT NULL,
+  created_at        timestamp NOT NULL DEFAULT NOW(),
+  updated_at        timestamp NOT NULL DEFAULT NOW(),
+
+  CONSTRAINT fk_saved_searches_user
+    FOREIGN KEY(user_id) 
+      REFERENCES users(id)
+        ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_saved_searches_user ON saved_searches (user_id);
+
+CREATE TABLE IF NOT EXISTS saved_search_matches (
+  id                SERIAL PRIMARY KEY,
+  search_id         VARCHAR(36) NOT NULL,
+  project_id        VARCHAR(36) NOT NULL,
+  created_at        timestamp NOT NULL DEFAULT NOW(),
+  emailed_at        timestamp,
+
+  CONSTRAINT uq_saved_search_matches UNIQUE (search_id, project_id),
+
+  CONSTRAINT fk_saved_search_matches_search
+    FOREIGN KEY(search_id) 
+      REFERENCES saved_searches(id)
+        ON DELETE CASCADE,
+
+  CONSTRAINT fk_saved_search_matches_project
+    FOREIGN KEY(project_id) 
+      REFERENCES project(id)
+        ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_saved_search_matches_pending ON saved_search_matches (emailed_at);
+
+CREATE TABLE IF NOT EXISTS project_watchers (
+  project_id        VARCHAR(36) NOT NULL,
+  user_id           VARCHAR(36) NOT NULL,
+  created_at        timestamp NOT NULL DEFAULT NOW(),
+
+  PRIMARY KEY (project_id, user_id),
+
+  CONSTRAINT fk_project_watchers_project
+    FOREIGN KEY(project_id) 
+      REFERENCES project(id)
+        ON DELETE CASCADE,
+
+  CONSTRAINT fk_project_watchers_user
+    FOREIGN KEY(user_id) 
+      REFERENCES users(id)
+        ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_project_watchers_user ON project_watchers (user_id, created_at);
+
+CREATE TABLE IF NOT EXISTS project_templates (
+  id                      VARCHAR(36) NOT NULL PRIMARY KEY DEFAULT uuid_generate_v4(),
+  name                    VARCHAR(64) NOT NULL,
+  description             VARCHAR,
+  building_type           VARCHAR(64) NOT NULL,
+  is_save_carbon          BOOLEAN NOT NULL,
+  appropriate_status      VARCHAR(64) NOT NULL,
+  has_financing_secured   BOOLEAN NOT NULL,
+  budget_range            VARCHAR(32) NOT NULL,
+  square_range            VARCHAR(32) NOT NULL,
+  commercial_work         VARCHAR(64) NOT NULL,
+  architectural_services  text[],
+  user_id                 VARCHAR(36) NOT NULL,
+  organization_id         VARCHAR(36),
+  created_at              timestamp NOT NULL DEFAULT NOW(),
+
+  CONSTRAINT fk_project_templates_creator
+    FOREIGN KEY(user_id) 
+      REFERENCES users(id)
+        ON DELETE CASCADE,
+
+  CONSTRAINT fk_project_templates_organization
+    FOREIGN KEY(organization_id) 
+      REFERENCES organizations(id)
+        ON DELETE SET NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_project_templates_user ON project_templates (user_id);
+CREATE INDEX IF NOT EXISTS idx_project_templates_organization ON project_templates (organization_id);
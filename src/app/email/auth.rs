@@ -7,6 +7,7 @@ pub struct AuthEvents {
     email: Box<dyn TEmail + Sync + Send>,
 }
 
+// Account mails are requested by the user themselves, so they bypass the notification opt-ins
 impl AuthEvents {
     pub fn default() -> Self {
         let client_url = std::env::var("CLIENT_URL").expect("set CLIENT_URL env variable");
@@ -16,7 +17,7 @@ impl AuthEvents {
         }
     }
 
-    pub async fn on_create_user(&self, email: &str, _admins: Vec<&str>) -> Result<(), String> {
+    pub async fn on_create_user(&self, email: &str) -> Result<(), String> {
         println!("on_create_user: user : {:?}", email);
         Ok(())
     }
@@ -0,0 +1,89 @@
+use crate::app::{
+    entities::{
+        compliance::ComplianceDocument,
+        preferences::{notification_category::NotificationCategory, Preferences},
+    },
+    traits::send_email::TEmail,
+};
+
+use super::Email;
+
+pub struct ComplianceEvents {
+    client_url: String,
+    email: Box<dyn TEmail + Sync + Send>,
+}
+
+impl ComplianceEvents {
+    pub fn default() -> Self {
+        let client_url = std::env::var("CLIENT_URL").expect("set CLIENT_URL env variable");
+        Self {
+            client_url,
+            email: Box::new(Email::default()),
+        }
+    }
+
+    pub async fn on_document_expiring(
+        &self,
+        document: &ComplianceDocument,
+        days: i32,
+        preferences: &Preferences,
+    ) -> Result<(), String> {
+        if !preferences.allows_email(&NotificationCategory::Compliance) {
+            return Ok(());
+        }
+
+        let url = format!("{}/profile/compliance", self.client_url);
+        let doc_type = document.doc_type.as_str().to_lowercase();
+        let expires_at = preferences.format_date(&document.expires_at);
+        let period = if days == 1 { "1 day".to_string() } else { format!("{days} days") };
+        let html = format!(
+            "<div>
+                <p> Hello {}, </p>
+                <p> Your {doc_type} expires in {period}, on {expires_at}. </p>
+                <p> Please upload a renewed {doc_type} to keep your account compliant. </p>
+                <p>
+                    <a style='text-decoration: none' href={url}> 
+                        Update your documents 
+                    </a>
+                </p>
+            </div>",
+            document.vendor.first_name
+        );
+        let _res = self
+            .email
+            .send(vec![&document.vendor.email], format!("Your {doc_type} expires in {period}"), html)
+            .await;
+
+        Ok(())
+    }
+
+    pub async fn on_vendor_non_compliant(
+        &self,
+        email: &str,
+        first_name: &str,
+        preferences: &Preferences,
+    ) -> Result<(), String> {
+        if !preferences.allows_email(&NotificationCategory::Compliance) {
+            return Ok(());
+        }
+
+        let url = format!("{}/profile/compliance", self.client_url);
+        let html = format!(
+            "<div>
+                <p> Hello {first_name}, </p>
+                <p> Your license or insurance has expired and your account is no longer compliant. </p>
+                <p>
+                    <a style='text-decoration: none' href={url}> 
+                        Update your documents 
+                    </a>
+                </p>
+            </div>"
+        );
+        let _res = self
+            .email
+            .send(vec![email], String::from("Your account is no longer compliant"), html)
+            .await;
+
+        Ok(())
+    }
+}
@@ -1,10 +1,35 @@
-use super::traits::send_email::TEmail;
+use super::{entities::{preferences::Preferences, user::user_type::UserType}, traits::send_email::TEmail};
 use async_trait::async_trait;
 use mailgun_rs::{EmailAddress, Mailgun, Message};
 
 pub mod auth;
+pub mod compliance;
 pub mod project;
 
+// User-supplied text is escaped before it is formatted into an email body
+pub fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+pub struct Recipient {
+    pub user_id: String,
+    pub email: String,
+    pub first_name: String,
+    pub u_type: UserType,
+    pub preferences: Preferences,
+}
+
 pub struct Email {
     key: String,
     domain: String,
@@ -50,3 +75,18 @@ impl TEmail for Email {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escape_replaces_html_special_characters() {
+        assert_eq!(escape("Smith & Sons"), "Smith &amp; Sons");
+        assert_eq!(
+            escape("<a href=\"x\" title='y'>"),
+            "&lt;a href=&quot;x&quot; title=&#39;y&#39;&gt;"
+        );
+        assert_eq!(escape("Berlin office"), "Berlin office");
+    }
+}
@@ -1,6 +1,18 @@
-use crate::app::{entities::user::User, traits::send_email::TEmail};
+use chrono::{DateTime, Utc};
 
-use super::Email;
+use crate::app::{
+    entities::{
+        preferences::notification_category::NotificationCategory,
+        bid::Bid,
+        milestone::Milestone,
+        project::{status::ProjectStatus, Project},
+        saved_search::SearchMatch,
+        user::{user_type::UserType, User},
+    },
+    traits::send_email::TEmail,
+};
+
+use super::{escape, Email, Recipient};
 
 pub struct ProjectEvents {
     client_url: String,
@@ -16,26 +28,271 @@ impl ProjectEvents {
         }
     }
 
-    pub async fn on_create_project(&self, user: User, title: &str, id: &str, admin_emails: Vec<&str>) -> Result<(), String> {
+    pub async fn on_create_project(
+        &self,
+        user: User,
+        title: &str,
+        id: &str,
+        completion_date: &DateTime<Utc>,
+        admins: Vec<Recipient>,
+    ) -> Result<(), String> {
         let url = format!("{}/a/projects/{}", self.client_url, id);
         let user_name = format!("{} {}", user.first_name, user.last_name);
+
+        for admin in admins {
+            if !admin.preferences.allows_email(&NotificationCategory::Projects) {
+                continue;
+            }
+
+            let date = admin.preferences.format_date(completion_date);
+            let html = format!(
+                "<div>
+                    <p> {user_name} created a new project {title}</p>
+                    <p> Expected completion: {date} </p>
+                    <p>
+                        <a style='text-decoration: none' href={url}> 
+                            Review the project 
+                        </a>
+                    </p>
+                </div>", 
+            );
+            let _res = self
+                .email
+                .send(vec![&admin.email], String::from("Project Created"), html)
+                .await;
+        }
+
+        Ok(())
+    }
+
+    fn project_url(&self, recipient: &Recipient, id: &str) -> String {
+        match recipient.u_type {
+            UserType::Admin => format!("{}/a/projects/{}", self.client_url, id),
+            _ => format!("{}/projects/{}", self.client_url, id),
+        }
+    }
+
+    pub async fn on_update_project(
+        &self,
+        user: &User,
+        title: &str,
+        id: &str,
+        changes: &[&str],
+        recipients: Vec<Recipient>,
+    ) -> Result<(), String> {
+        let user_name = escape(&format!("{} {}", user.first_name, user.last_name));
+        let title = escape(title);
+        let changes = changes.join(", ");
+
+        for recipient in recipients {
+            if !recipient.preferences.allows_email(&NotificationCategory::Projects) {
+                continue;
+            }
+
+            let url = self.project_url(&recipient, id);
+
+            let html = format!(
+                "<div>
+                    <p> {user_name} updated the project {title}</p>
+                    <p> Changed: {changes} </p>
+                    <p>
+                        <a style='text-decoration: none' href={url}> 
+                            Review the project 
+                        </a>
+                    </p>
+                </div>", 
+            );
+            let _res = self
+                .email
+                .send(vec![&recipient.email], String::from("Project Updated"), html)
+                .await;
+        }
+
+        Ok(())
+    }
+
+    pub async fn on_status_changed(
+        &self,
+        project: &Project,
+        to: &ProjectStatus,
+        reason: Option<&str>,
+        recipients: Vec<Recipient>,
+    ) -> Result<(), String> {
+        let title = escape(&project.name);
+        let from = project.status.as_str();
+        let to = to.as_str();
+        let reason = match reason {
+            Some(reason) => format!("<p> Reason: {} </p>", escape(reason)),
+            None => String::new(),
+        };
+
+        for recipient in recipients {
+            if !recipient.preferences.allows_email(&NotificationCategory::Projects) {
+                continue;
+            }
+
+            let url = self.project_url(&recipient, &project.id);
+            let html = format!(
+                "<div>
+                    <p> Hi {}, the project {title} moved from {from} to {to}</p>
+                    {reason}
+                    <p>
+                        <a style='text-decoration: none' href={url}> 
+                            Open the project 
+                        </a>
+                    </p>
+                </div>", 
+                escape(&recipient.first_name)
+            );
+            let _res = self
+                .email
+                .send(vec![&recipient.email], format!("Project {to}"), html)
+                .await;
+        }
+
+        Ok(())
+    }
+
+    pub async fn on_bid_submitted(
+        &self,
+        project: &Project,
+        bid: &Bid,
+        recipients: Vec<Recipient>,
+    ) -> Result<(), String> {
+        let title = escape(&project.name);
+        let vendor_name = match &bid.vendor.company {
+            Some(company) => escape(company),
+            None => escape(&format!("{} {}", bid.vendor.first_name, bid.vendor.last_name)),
+        };
+        let price = bid.formatted_price();
+        let days = bid.duration_days;
+
+        for recipient in recipients {
+            if !recipient.preferences.allows_email(&NotificationCategory::Projects) {
+                continue;
+            }
+
+            let url = format!("{}/bids", self.project_url(&recipient, &project.id));
+            let start = recipient.preferences.format_date(&bid.start_date);
+            let html = format!(
+                "<div>
+                    <p> Hi {}, {vendor_name} placed a bid on the project {title}</p>
+                    <p> Price: {price}, duration: {days} days, start: {start} </p>
+                    <p>
+                        <a style='text-decoration: none' href={url}> 
+                            Review the bids 
+                        </a>
+                    </p>
+                </div>", 
+                escape(&recipient.first_name)
+            );
+            let _res = self
+                .email
+                .send(vec![&recipient.email], String::from("New Bid"), html)
+                .await;
+        }
+
+        Ok(())
+    }
+
+    pub async fn on_bid_declined(&self, project: &Project, recipients: Vec<Recipient>) -> Result<(), String> {
+        let url = format!("{}/bids", self.client_url);
+        let title = &project.name;
+
+        for recipient in recipients {
+            if !recipient.preferences.allows_email(&NotificationCategory::Projects) {
+                continue;
+            }
+
+            let html = format!(
+                "<div>
+                    <p> Hi {}, thank you for your bid on the project {title}.</p>
+                    <p> The client has chosen another proposal this time. We hope to see your bids on future projects. </p>
+                    <p>
+                        <a style='text-decoration: none' href={url}> 
+                            Review your bids 
+                        </a>
+                    </p>
+                </div>", 
+                recipient.first_name
+            );
+            let _res = self
+                .email
+                .send(vec![&recipient.email], format!("Update on {title}"), html)
+                .await;
+        }
+
+        Ok(())
+    }
+
+    pub async fn on_milestone_slipped(
+        &self,
+        project: &Project,
+        milestone: &Milestone,
+        recipients: Vec<Recipient>,
+    ) -> Result<(), String> {
+        let title = escape(&project.name);
+        let milestone_title = escape(&milestone.title);
+
+        for recipient in recipients {
+            if !recipient.preferences.allows_email(&NotificationCategory::Projects) {
+                continue;
+            }
+
+            let url = format!("{}/milestones", self.project_url(&recipient, &project.id));
+            let date = recipient.preferences.format_date(&milestone.due_date);
+            let html = format!(
+                "<div>
+                    <p> Hi {}, the milestone {milestone_title} on the project {title} is past its due date.</p>
+                    <p> Due date: {date} </p>
+                    <p>
+                        <a style='text-decoration: none' href={url}> 
+                            Review the milestones 
+                        </a>
+                    </p>
+                </div>", 
+                escape(&recipient.first_name)
+            );
+            let _res = self
+                .email
+                .send(vec![&recipient.email], String::from("Milestone Overdue"), html)
+                .await;
+        }
+
+        Ok(())
+    }
+
+    pub async fn on_saved_search_digest(&self, recipient: Recipient, matches: &[&SearchMatch]) -> Result<(), String> {
+        if !recipient.preferences.allows_email(&NotificationCategory::Projects) {
+            return Ok(());
+        }
+
+        let items = matches
+            .iter()
+            .map(|m| {
+                format!(
+                    "<li><a style='text-decoration: none' href={}>{}</a>, {} ({})</li>",
+                    self.project_url(&recipient, &m.project_id),
+                    escape(&m.project_name),
+                    escape(&m.project_city),
+                    escape(&m.search_name)
+                )
+            })
+            .collect::<Vec<String>>()
+            .join("");
+
         let html = format!(
             "<div>
-                <p> {user_name} created a new project {title}</p>
-                <p>
-                    <a style='text-decoration: none' href={url}> 
-                        Review the project 
-                    </a>
-                </p>
-            </div>", 
+                <p> Hi {}, new projects match your saved searches:</p>
+                <ul>{items}</ul>
+            </div>",
+            escape(&recipient.first_name)
         );
         let _res = self
             .email
-            .send(admin_emails, String::from("Project Created"), html)
+            .send(vec![&recipient.email], String::from("New Projects For You"), html)
             .await;
 
         Ok(())
     }
-
-    
 }
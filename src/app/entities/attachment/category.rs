@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize, Serializer};
+
+const IMAGE_TYPES: [&str; 4] = ["image/jpeg", "image/png", "image/webp", "image/heic"];
+const DOCUMENT_TYPES: [&str; 4] = ["application/pdf", "image/vnd.dwg", "image/vnd.dxf", "application/acad"];
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(untagged)]
+pub enum AttachmentCategory {
+    Drawing,
+    Permit,
+    Photo,
+    Other,
+}
+
+impl AttachmentCategory {
+    pub fn from_str(value: &str) -> Result<AttachmentCategory, String> {
+        match value {
+            "Drawing" => Ok(AttachmentCategory::Drawing),
+            "Permit" => Ok(AttachmentCategory::Permit),
+            "Photo" => Ok(AttachmentCategory::Photo),
+            "Other" => Ok(AttachmentCategory::Other),
+            _ => Err("Attachment Category is not correct".to_string()),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AttachmentCategory::Drawing => "Drawing",
+            AttachmentCategory::Permit => "Permit",
+            AttachmentCategory::Photo => "Photo",
+            AttachmentCategory::Other => "Other",
+        }
+    }
+
+    pub fn allows(&self, content_type: &str) -> bool {
+        match self {
+            AttachmentCategory::Photo => IMAGE_TYPES.contains(&content_type),
+            AttachmentCategory::Drawing | AttachmentCategory::Permit => {
+                IMAGE_TYPES.contains(&content_type) || DOCUMENT_TYPES.contains(&content_type)
+            }
+            AttachmentCategory::Other => {
+                IMAGE_TYPES.contains(&content_type)
+                    || DOCUMENT_TYPES.contains(&content_type)
+                    || ["text/plain", "text/csv", "application/zip"].contains(&content_type)
+            }
+        }
+    }
+}
+
+impl Serialize for AttachmentCategory {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
@@ -0,0 +1,35 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use self::category::AttachmentCategory;
+
+pub mod category;
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Attachment {
+    pub id: String,
+    pub file_id: String,
+    pub project_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bid_id: Option<String>,
+    pub category: AttachmentCategory,
+    pub name: String,
+    pub content_type: String,
+    pub size: i64,
+    pub version: i32,
+    pub uploaded_by: Option<String>,
+    pub created_at: DateTime<Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+impl Attachment {
+    pub fn project_prefix(project_id: &str) -> String {
+        format!("projects/{project_id}")
+    }
+
+    pub fn storage_key(&self) -> String {
+        format!("{}/{}", Self::project_prefix(&self.project_id), self.id)
+    }
+}
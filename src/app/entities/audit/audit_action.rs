@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize, Serializer};
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(untagged)]
+pub enum AuditAction {
+    ChangeType,
+    VerifyEmail,
+    ResetPassword,
+    DeleteUser,
+    ModerateReview,
+    RestoreProject,
+}
+
+impl AuditAction {
+    pub fn from_str(value: &str) -> Result<AuditAction, String> {
+        match value {
+            "ChangeType" => Ok(AuditAction::ChangeType),
+            "VerifyEmail" => Ok(AuditAction::VerifyEmail),
+            "ResetPassword" => Ok(AuditAction::ResetPassword),
+            "DeleteUser" => Ok(AuditAction::DeleteUser),
+            "ModerateReview" => Ok(AuditAction::ModerateReview),
+            "RestoreProject" => Ok(AuditAction::RestoreProject),
+            _ => Err("Audit Action is not correct".to_string()),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AuditAction::ChangeType => "ChangeType",
+            AuditAction::VerifyEmail => "VerifyEmail",
+            AuditAction::ResetPassword => "ResetPassword",
+            AuditAction::DeleteUser => "DeleteUser",
+            AuditAction::ModerateReview => "ModerateReview",
+            AuditAction::RestoreProject => "RestoreProject",
+        }
+    }
+}
+
+impl Serialize for AuditAction {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
@@ -0,0 +1,37 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use serde_json::Value;
+
+use self::audit_action::AuditAction;
+
+pub mod audit_action;
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditEntry {
+    pub id: i32,
+    pub admin_id: Option<String>,
+    pub target_user_id: String,
+    pub action: AuditAction,
+    pub details: Value,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct AuditRecord {
+    pub admin_id: String,
+    pub target_user_id: String,
+    pub action: AuditAction,
+    pub details: Value,
+}
+
+impl AuditRecord {
+    pub fn new(admin_id: &str, target_user_id: &str, action: AuditAction, details: Value) -> Self {
+        AuditRecord {
+            admin_id: admin_id.to_string(),
+            target_user_id: target_user_id.to_string(),
+            action,
+            details,
+        }
+    }
+}
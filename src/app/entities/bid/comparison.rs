@@ -0,0 +1,43 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use super::Bid;
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ComparisonRow {
+    pub bid: Bid,
+    pub finish_date: DateTime<Utc>,
+    pub price_over_lowest: i64,
+}
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BidComparison {
+    pub rows: Vec<ComparisonRow>,
+    pub lowest_price_id: Option<String>,
+    pub shortest_duration_id: Option<String>,
+    pub earliest_start_id: Option<String>,
+    pub earliest_finish_id: Option<String>,
+}
+
+impl BidComparison {
+    pub fn from_bids(bids: Vec<Bid>) -> Self {
+        let lowest_price = bids.iter().map(|bid| bid.price).min().unwrap_or(0);
+
+        BidComparison {
+            lowest_price_id: bids.iter().min_by_key(|bid| bid.price).map(|bid| bid.id.clone()),
+            shortest_duration_id: bids.iter().min_by_key(|bid| bid.duration_days).map(|bid| bid.id.clone()),
+            earliest_start_id: bids.iter().min_by_key(|bid| bid.start_date).map(|bid| bid.id.clone()),
+            earliest_finish_id: bids.iter().min_by_key(|bid| bid.finish_date()).map(|bid| bid.id.clone()),
+            rows: bids
+                .into_iter()
+                .map(|bid| ComparisonRow {
+                    finish_date: bid.finish_date(),
+                    price_over_lowest: bid.price - lowest_price,
+                    bid,
+                })
+                .collect(),
+        }
+    }
+}
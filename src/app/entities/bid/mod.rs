@@ -0,0 +1,43 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+
+use self::status::BidStatus;
+
+use super::{attachment::Attachment, user::User};
+
+pub mod comparison;
+pub mod status;
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Bid {
+    pub id: String,
+    pub project_id: String,
+    pub organization_id: Option<String>,
+    pub price: i64,
+    pub duration_days: i32,
+    pub start_date: DateTime<Utc>,
+    pub message: String,
+    pub status: BidStatus,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub awarded_at: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub awarded_by: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub award_note: Option<String>,
+    pub vendor: User,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub attachments: Vec<Attachment>,
+}
+
+impl Bid {
+    pub fn formatted_price(&self) -> String {
+        format!("${}.{:02}", self.price / 100, self.price % 100)
+    }
+
+    pub fn finish_date(&self) -> DateTime<Utc> {
+        self.start_date + Duration::days(self.duration_days as i64)
+    }
+}
@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize, Serializer};
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(untagged)]
+pub enum BidStatus {
+    Submitted,
+    Shortlisted,
+    Awarded,
+    Declined,
+    Withdrawn,
+}
+
+impl BidStatus {
+    pub fn from_str(value: &str) -> Result<BidStatus, String> {
+        match value {
+            "Submitted" => Ok(BidStatus::Submitted),
+            "Shortlisted" => Ok(BidStatus::Shortlisted),
+            "Awarded" => Ok(BidStatus::Awarded),
+            "Declined" => Ok(BidStatus::Declined),
+            "Withdrawn" => Ok(BidStatus::Withdrawn),
+            _ => Err("Bid Status is not correct".to_string()),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BidStatus::Submitted => "Submitted",
+            BidStatus::Shortlisted => "Shortlisted",
+            BidStatus::Awarded => "Awarded",
+            BidStatus::Declined => "Declined",
+            BidStatus::Withdrawn => "Withdrawn",
+        }
+    }
+
+    pub fn is_open(&self) -> bool {
+        matches!(self, BidStatus::Submitted | BidStatus::Shortlisted)
+    }
+}
+
+impl Serialize for BidStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
@@ -0,0 +1,39 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use super::{
+    project::{
+        architectural_service::ArchitecturalServices, budget_range::BudgetRange,
+        commercial_work::CommercialWork, square_range::SquareRange,
+    },
+    user::User,
+};
+
+pub mod recommendation;
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct VendorCapabilities {
+    pub user_id: String,
+    pub commercial_works: Vec<CommercialWork>,
+    pub architectural_services: Vec<ArchitecturalServices>,
+    pub budget_ranges: Vec<BudgetRange>,
+    pub square_ranges: Vec<SquareRange>,
+    pub is_save_carbon: bool,
+    pub service_cities: Vec<String>,
+    pub service_radius: Option<i32>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl VendorCapabilities {
+    pub fn has_service_area(&self) -> bool {
+        !self.service_cities.is_empty() || self.service_radius.is_some()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct VendorCandidate {
+    pub vendor: User,
+    pub capabilities: VendorCapabilities,
+    pub distance: Option<f64>,
+}
@@ -0,0 +1,21 @@
+use serde::Serialize;
+
+use crate::app::entities::{project::Project, user::User};
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct VendorRecommendation {
+    pub vendor: User,
+    pub score: i32,
+    pub reasons: Vec<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distance: Option<f64>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectRecommendation {
+    pub project: Project,
+    pub score: i32,
+    pub reasons: Vec<&'static str>,
+}
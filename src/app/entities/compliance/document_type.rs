@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize, Serializer};
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(untagged)]
+pub enum DocumentType {
+    License,
+    Insurance,
+}
+
+impl DocumentType {
+    pub fn from_str(value: &str) -> Result<DocumentType, String> {
+        match value {
+            "License" => Ok(DocumentType::License),
+            "Insurance" => Ok(DocumentType::Insurance),
+            _ => Err("Document Type is not correct".to_string()),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DocumentType::License => "License",
+            DocumentType::Insurance => "Insurance",
+        }
+    }
+
+    pub fn required() -> Vec<DocumentType> {
+        vec![DocumentType::License, DocumentType::Insurance]
+    }
+}
+
+impl Serialize for DocumentType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
@@ -0,0 +1,21 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use self::document_type::DocumentType;
+
+use super::user::User;
+
+pub mod document_type;
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ComplianceDocument {
+    pub id: i32,
+    #[serde(rename = "type")]
+    pub doc_type: DocumentType,
+    pub number: Option<String>,
+    pub issued_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+    pub vendor: User,
+}
@@ -0,0 +1,63 @@
+use serde::Serialize;
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct GeoPoint {
+  pub latitude: f64,
+  pub longitude: f64,
+}
+
+const EARTH_RADIUS_KM: f64 = 6371.0;
+
+impl GeoPoint {
+  pub fn distance_km(&self, other: &GeoPoint) -> f64 {
+    let d_lat = (other.latitude - self.latitude).to_radians() / 2.0;
+    let d_lng = (other.longitude - self.longitude).to_radians() / 2.0;
+    let a = d_lat.sin().powi(2)
+      + self.latitude.to_radians().cos() * other.latitude.to_radians().cos() * d_lng.sin().powi(2);
+    EARTH_RADIUS_KM * 2.0 * a.sqrt().asin()
+  }
+}
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UserLocation {
+  pub city: Option<String>,
+  pub zip_code: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub latitude: Option<f64>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub longitude: Option<f64>,
+}
+
+impl UserLocation {
+  pub fn point(&self) -> Option<GeoPoint> {
+    match (self.latitude, self.longitude) {
+      (Some(latitude), Some(longitude)) => Some(GeoPoint { latitude, longitude }),
+      _ => None,
+    }
+  }
+}
+
+#[derive(Debug, Clone)]
+pub struct GazetteerEntry {
+  pub country_code: String,
+  pub zip_code: String,
+  pub city: String,
+  pub latitude: f64,
+  pub longitude: f64,
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn distance_km_matches_known_city_distances() {
+    let berlin = GeoPoint { latitude: 52.52, longitude: 13.405 };
+    let munich = GeoPoint { latitude: 48.137, longitude: 11.575 };
+
+    assert!((berlin.distance_km(&munich) - 504.0).abs() < 5.0);
+    assert_eq!(berlin.distance_km(&berlin), 0.0);
+  }
+}
@@ -0,0 +1,34 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use self::{owner::MilestoneOwner, status::MilestoneStatus};
+
+pub mod owner;
+pub mod status;
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MilestoneTask {
+    pub id: i32,
+    pub milestone_id: String,
+    pub title: String,
+    pub position: i32,
+    pub is_done: bool,
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Milestone {
+    pub id: String,
+    pub project_id: String,
+    pub title: String,
+    pub due_date: DateTime<Utc>,
+    pub owner: MilestoneOwner,
+    pub status: MilestoneStatus,
+    pub position: i32,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub tasks: Vec<MilestoneTask>,
+}
+
@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize, Serializer};
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(untagged)]
+pub enum MilestoneOwner {
+    Client,
+    Vendor,
+}
+
+impl MilestoneOwner {
+    pub fn from_str(value: &str) -> Result<MilestoneOwner, String> {
+        match value {
+            "Client" => Ok(MilestoneOwner::Client),
+            "Vendor" => Ok(MilestoneOwner::Vendor),
+            _ => Err("Milestone Owner is not correct".to_string()),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MilestoneOwner::Client => "Client",
+            MilestoneOwner::Vendor => "Vendor",
+        }
+    }
+}
+
+impl Serialize for MilestoneOwner {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize, Serializer};
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(untagged)]
+pub enum MilestoneStatus {
+    Pending,
+    InProgress,
+    Done,
+}
+
+impl MilestoneStatus {
+    pub fn from_str(value: &str) -> Result<MilestoneStatus, String> {
+        match value {
+            "Pending" => Ok(MilestoneStatus::Pending),
+            "InProgress" => Ok(MilestoneStatus::InProgress),
+            "Done" => Ok(MilestoneStatus::Done),
+            _ => Err("Milestone Status is not correct".to_string()),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MilestoneStatus::Pending => "Pending",
+            MilestoneStatus::InProgress => "InProgress",
+            MilestoneStatus::Done => "Done",
+        }
+    }
+}
+
+impl Serialize for MilestoneStatus {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
@@ -1,3 +1,15 @@
+pub mod attachment;
+pub mod audit;
+pub mod bid;
+pub mod capability;
+pub mod compliance;
+pub mod location;
+pub mod milestone;
 pub mod notification;
+pub mod organization;
+pub mod page;
+pub mod preferences;
+pub mod review;
+pub mod saved_search;
 pub mod user;
 pub mod project;
@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize, Serializer};
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(untagged)]
+pub enum MemberRole {
+    Owner,
+    Manager,
+    Member,
+}
+
+impl MemberRole {
+    pub fn from_str(value: &str) -> Result<MemberRole, String> {
+        match value {
+            "Owner" => Ok(MemberRole::Owner),
+            "Manager" => Ok(MemberRole::Manager),
+            "Member" => Ok(MemberRole::Member),
+            _ => Err("Member Role is not correct".to_string()),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MemberRole::Owner => "Owner",
+            MemberRole::Manager => "Manager",
+            MemberRole::Member => "Member",
+        }
+    }
+
+    pub fn can_manage(&self) -> bool {
+        matches!(self, MemberRole::Owner | MemberRole::Manager)
+    }
+}
+
+impl Serialize for MemberRole {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
@@ -0,0 +1,105 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use self::member_role::MemberRole;
+
+use super::user::{user_type::UserType, User};
+
+pub mod member_role;
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrganizationMember {
+    pub user: User,
+    pub role: MemberRole,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Organization {
+    pub id: String,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub o_type: UserType,
+    pub created_at: DateTime<Utc>,
+    pub members: Vec<OrganizationMember>,
+}
+
+impl Organization {
+    pub fn role_of(&self, user_id: &str) -> Option<MemberRole> {
+        self.members
+            .iter()
+            .find(|m| m.user.id == user_id)
+            .map(|m| m.role.clone())
+    }
+
+    // Whether at least one owner is left once `user_id` takes `role`, or leaves when it is None
+    pub fn keeps_an_owner(&self, user_id: &str, role: Option<&MemberRole>) -> bool {
+        role == Some(&MemberRole::Owner)
+            || self
+                .members
+                .iter()
+                .any(|m| m.user.id != user_id && m.role == MemberRole::Owner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn organization(roles: &[(&str, MemberRole)]) -> Organization {
+        Organization {
+            id: "organization".to_string(),
+            name: "Acme".to_string(),
+            o_type: UserType::Vendor,
+            created_at: Utc::now(),
+            members: roles
+                .iter()
+                .map(|(id, role)| OrganizationMember {
+                    user: User {
+                        id: id.to_string(),
+                        email: String::new(),
+                        first_name: "Test".to_string(),
+                        last_name: "User".to_string(),
+                        company: None,
+                        phone: None,
+                        u_type: UserType::Vendor,
+                        password_alg: String::new(),
+                        password_hash: String::new(),
+                        is_compliant: true,
+                        created_at: Utc::now(),
+                        tokens: vec![],
+                    },
+                    role: role.clone(),
+                    created_at: Utc::now(),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn the_last_owner_can_neither_leave_nor_be_demoted() {
+        let organization = organization(&[("owner", MemberRole::Owner), ("manager", MemberRole::Manager)]);
+
+        assert!(!organization.keeps_an_owner("owner", None));
+        assert!(!organization.keeps_an_owner("owner", Some(&MemberRole::Manager)));
+        assert!(organization.keeps_an_owner("owner", Some(&MemberRole::Owner)));
+    }
+
+    #[test]
+    fn an_owner_can_leave_while_another_owner_remains() {
+        let organization = organization(&[("first", MemberRole::Owner), ("second", MemberRole::Owner)]);
+
+        assert!(organization.keeps_an_owner("first", None));
+        assert!(organization.keeps_an_owner("second", Some(&MemberRole::Member)));
+    }
+
+    #[test]
+    fn other_members_can_always_leave_or_change_role() {
+        let organization = organization(&[("owner", MemberRole::Owner), ("member", MemberRole::Member)]);
+
+        assert!(organization.keeps_an_owner("member", None));
+        assert!(organization.keeps_an_owner("member", Some(&MemberRole::Manager)));
+    }
+}
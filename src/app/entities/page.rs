@@ -0,0 +1,9 @@
+use serde::Serialize;
+
+#[derive(Debug, Serialize, Clone)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub total: i64,
+    pub limit: Option<i64>,
+    pub skip: i64,
+}
@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize, Serializer};
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(untagged)]
+pub enum DateFormat {
+    MonthDayYear,
+    DayMonthYear,
+    DayMonthYearDotted,
+    YearMonthDay,
+}
+
+impl DateFormat {
+    pub fn from_str(value: &str) -> Result<DateFormat, String> {
+        match value {
+            "MM/DD/YYYY" => Ok(DateFormat::MonthDayYear),
+            "DD/MM/YYYY" => Ok(DateFormat::DayMonthYear),
+            "DD.MM.YYYY" => Ok(DateFormat::DayMonthYearDotted),
+            "YYYY-MM-DD" => Ok(DateFormat::YearMonthDay),
+            _ => Err("Date Format is not correct".to_string()),
+        }
+    }
+
+    pub fn for_locale(locale: &str) -> DateFormat {
+        match locale {
+            "en-US" => DateFormat::MonthDayYear,
+            "en-GB" | "fr-FR" | "es-ES" => DateFormat::DayMonthYear,
+            "de-DE" | "uk-UA" => DateFormat::DayMonthYearDotted,
+            _ => DateFormat::YearMonthDay,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DateFormat::MonthDayYear => "MM/DD/YYYY",
+            DateFormat::DayMonthYear => "DD/MM/YYYY",
+            DateFormat::DayMonthYearDotted => "DD.MM.YYYY",
+            DateFormat::YearMonthDay => "YYYY-MM-DD",
+        }
+    }
+
+    pub fn pattern(&self) -> &'static str {
+        match self {
+            DateFormat::MonthDayYear => "%m/%d/%Y",
+            DateFormat::DayMonthYear => "%d/%m/%Y",
+            DateFormat::DayMonthYearDotted => "%d.%m.%Y",
+            DateFormat::YearMonthDay => "%Y-%m-%d",
+        }
+    }
+}
+
+impl Serialize for DateFormat {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
@@ -0,0 +1,107 @@
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+
+use self::{date_format::DateFormat, notification_category::NotificationCategory};
+
+pub mod date_format;
+pub mod notification_category;
+
+pub const LOCALES: [&str; 6] = ["en-US", "en-GB", "de-DE", "fr-FR", "es-ES", "uk-UA"];
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ChannelPreference {
+    pub email: bool,
+    pub in_app: bool,
+}
+
+impl ChannelPreference {
+    pub fn default() -> Self {
+        Self {
+            email: true,
+            in_app: true,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Preferences {
+    pub locale: String,
+    pub timezone: String,
+    pub date_format: DateFormat,
+    pub channels: BTreeMap<String, ChannelPreference>,
+}
+
+impl Preferences {
+    pub fn default() -> Self {
+        Self {
+            locale: "en-US".to_string(),
+            timezone: "UTC".to_string(),
+            date_format: DateFormat::for_locale("en-US"),
+            channels: BTreeMap::new(),
+        }
+    }
+
+    pub fn channel(&self, category: &NotificationCategory) -> ChannelPreference {
+        match self.channels.get(category.as_str()) {
+            Some(channel) => channel.clone(),
+            None => ChannelPreference::default(),
+        }
+    }
+
+    pub fn allows_email(&self, category: &NotificationCategory) -> bool {
+        self.channel(category).email
+    }
+
+    pub fn allows_in_app(&self, category: &NotificationCategory) -> bool {
+        self.channel(category).in_app
+    }
+
+    pub fn format_date(&self, date: &DateTime<Utc>) -> String {
+        let tz: Tz = self.timezone.parse().unwrap_or(Tz::UTC);
+        date.with_timezone(&tz)
+            .format(self.date_format.pattern())
+            .to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    #[test]
+    fn categories_without_a_choice_allow_every_channel() {
+        let mut preferences = Preferences::default();
+        preferences.channels.insert(
+            "Projects".to_string(),
+            ChannelPreference {
+                email: false,
+                in_app: true,
+            },
+        );
+
+        assert!(!preferences.allows_email(&NotificationCategory::Projects));
+        assert!(preferences.allows_in_app(&NotificationCategory::Projects));
+        assert!(preferences.allows_email(&NotificationCategory::Compliance));
+    }
+
+    #[test]
+    fn format_date_uses_the_timezone_and_date_format() {
+        let date = Utc.with_ymd_and_hms(2024, 3, 31, 23, 30, 0).unwrap();
+        let mut preferences = Preferences::default();
+        assert_eq!(preferences.format_date(&date), "03/31/2024");
+
+        preferences.timezone = "Europe/Berlin".to_string();
+        preferences.date_format = DateFormat::for_locale("de-DE");
+        assert_eq!(preferences.format_date(&date), "01.04.2024");
+
+        preferences.timezone = "Mars/Olympus".to_string();
+        assert_eq!(preferences.format_date(&date), "31.03.2024");
+    }
+}
@@ -0,0 +1,34 @@
+use serde::{Deserialize, Serialize, Serializer};
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(untagged)]
+pub enum NotificationCategory {
+    Projects,
+    Compliance,
+}
+
+impl NotificationCategory {
+    pub fn from_str(value: &str) -> Result<NotificationCategory, String> {
+        match value {
+            "Projects" => Ok(NotificationCategory::Projects),
+            "Compliance" => Ok(NotificationCategory::Compliance),
+            _ => Err("Notification Category is not correct".to_string()),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NotificationCategory::Projects => "Projects",
+            NotificationCategory::Compliance => "Compliance",
+        }
+    }
+}
+
+impl Serialize for NotificationCategory {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
@@ -16,7 +16,7 @@ impl BudgetRange {
       match role {
           "Under $100k" => Ok(BudgetRange::Under100k),
           "$100k - $500k" => Ok(BudgetRange::From100kTo500k),
-          "$500k - $1m" => Ok(BudgetRange::From100kTo500k),
+          "$500k - $1m" => Ok(BudgetRange::From500kTo1m),
           "$1m - $5m" => Ok(BudgetRange::From1mTo5m),
           "Over $5m" => Ok(BudgetRange::Over5m),
           _ => Err("Budget Range is not correct".to_string())
@@ -0,0 +1,31 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::app::entities::user::User;
+
+use super::{appropriate_status::AppropriateStatus, architectural_service::ArchitecturalServices, budget_range::BudgetRange, square_range::SquareRange, commercial_work::CommercialWork};
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectDraft {
+    pub id: String,
+    pub name: String,
+    pub city: Option<String>,
+    pub street: Option<String>,
+    pub zip_code: Option<String>,
+    pub floor: Option<String>,
+    pub description: Option<String>,
+    pub building_type: Option<String>,
+    pub is_save_carbon: Option<bool>,
+    pub appropriate_status: Option<AppropriateStatus>,
+    pub has_financing_secured: Option<bool>,
+    pub budget_range: Option<BudgetRange>,
+    pub square_range: Option<SquareRange>,
+    pub commercial_work: Option<CommercialWork>,
+    pub architectural_services: Option<Vec<ArchitecturalServices>>,
+    pub completion_date: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub creator: User,
+    pub organization_id: Option<String>,
+}
@@ -0,0 +1,27 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use serde_json::Value;
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldChange {
+  pub id: i32,
+  pub field: String,
+  pub old_value: Value,
+  pub new_value: Value,
+  pub actor_id: Option<String>,
+  pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct FieldDiff {
+  pub field: &'static str,
+  pub old_value: Value,
+  pub new_value: Value,
+}
+
+impl FieldDiff {
+  pub fn new(field: &'static str, old_value: Value, new_value: Value) -> Self {
+    FieldDiff { field, old_value, new_value }
+  }
+}
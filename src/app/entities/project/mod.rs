@@ -3,14 +3,20 @@ use serde::{Deserialize, Serialize};
 
 use self::{appropriate_status::AppropriateStatus, architectural_service::ArchitecturalServices, budget_range::BudgetRange, square_range::SquareRange, commercial_work::CommercialWork, status::ProjectStatus};
 
-use super::user::User;
+use super::{attachment::Attachment, user::User};
 
 pub mod architectural_service;
 pub mod appropriate_status;
 pub mod budget_range;
 pub mod commercial_work;
+pub mod draft;
+pub mod field_change;
+pub mod scope;
 pub mod square_range;
 pub mod status;
+pub mod status_change;
+pub mod template;
+pub mod transition;
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
 #[serde(rename_all = "camelCase")]
@@ -33,5 +39,28 @@ pub struct Project {
     pub architectural_services: Vec<ArchitecturalServices>,
     pub completion_date: DateTime<Utc>,
     pub created_at: DateTime<Utc>,
-    pub creator: User
+    pub creator: User,
+    pub organization_id: Option<String>,
+    pub vendor_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latitude: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub longitude: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distance: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deleted_at: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub progress: Option<f64>,
+    #[serde(skip_deserializing, skip_serializing_if = "Vec::is_empty")]
+    pub attachments: Vec<Attachment>,
+}
+
+impl Project {
+    pub fn redact_contacts(&mut self) {
+        self.creator.email.clear();
+        self.creator.phone = None;
+    }
 }
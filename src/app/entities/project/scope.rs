@@ -0,0 +1,90 @@
+use crate::app::entities::user::{user_type::UserType, User};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ProjectScope {
+  All,
+  Member(String),
+  Vendor(String),
+  ApprovedVendor(String),
+}
+
+impl ProjectScope {
+  pub fn for_user(user: &User) -> Self {
+    match user.u_type {
+      UserType::Admin => ProjectScope::All,
+      UserType::Client => ProjectScope::Member(user.id.to_string()),
+      UserType::Vendor if user.is_compliant => ProjectScope::ApprovedVendor(user.id.to_string()),
+      UserType::Vendor => ProjectScope::Vendor(user.id.to_string()),
+    }
+  }
+
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      ProjectScope::All => "All",
+      ProjectScope::Member(_) => "Member",
+      ProjectScope::Vendor(_) => "Vendor",
+      ProjectScope::ApprovedVendor(_) => "ApprovedVendor",
+    }
+  }
+
+  pub fn user_id(&self) -> Option<&str> {
+    match self {
+      ProjectScope::All => None,
+      ProjectScope::Member(id) | ProjectScope::Vendor(id) | ProjectScope::ApprovedVendor(id) => Some(id),
+    }
+  }
+
+  pub fn sees_contacts(&self, vendor_id: Option<&str>) -> bool {
+    match self {
+      ProjectScope::Vendor(id) | ProjectScope::ApprovedVendor(id) => vendor_id == Some(id.as_str()),
+      _ => true,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use chrono::Utc;
+
+  use super::*;
+
+  fn user(u_type: UserType, is_compliant: bool) -> User {
+    User {
+      id: "user".to_string(),
+      email: String::new(),
+      first_name: "Test".to_string(),
+      last_name: "User".to_string(),
+      company: None,
+      phone: None,
+      u_type,
+      password_alg: String::new(),
+      password_hash: String::new(),
+      is_compliant,
+      created_at: Utc::now(),
+      tokens: vec![],
+    }
+  }
+
+  #[test]
+  fn for_user_maps_user_types_to_scopes() {
+    assert_eq!(ProjectScope::for_user(&user(UserType::Admin, false)), ProjectScope::All);
+    assert_eq!(ProjectScope::for_user(&user(UserType::Client, false)), ProjectScope::Member("user".to_string()));
+    assert_eq!(ProjectScope::for_user(&user(UserType::Vendor, true)), ProjectScope::ApprovedVendor("user".to_string()));
+    assert_eq!(ProjectScope::for_user(&user(UserType::Vendor, false)), ProjectScope::Vendor("user".to_string()));
+  }
+
+  #[test]
+  fn members_and_admins_see_contacts() {
+    assert!(ProjectScope::All.sees_contacts(None));
+    assert!(ProjectScope::Member("user".to_string()).sees_contacts(Some("vendor")));
+  }
+
+  #[test]
+  fn vendors_see_contacts_only_on_projects_awarded_to_them() {
+    for scope in [ProjectScope::Vendor("vendor".to_string()), ProjectScope::ApprovedVendor("vendor".to_string())] {
+      assert!(scope.sees_contacts(Some("vendor")));
+      assert!(!scope.sees_contacts(Some("other")));
+      assert!(!scope.sees_contacts(None));
+    }
+  }
+}
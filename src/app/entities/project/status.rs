@@ -41,6 +41,10 @@ impl ProjectStatus {
         ProjectStatus::OnHold => "OnHold",
       }
   }
+
+  pub fn is_terminal(&self) -> bool {
+      matches!(self, ProjectStatus::Complete | ProjectStatus::Withdrawn)
+  }
 }
 
 
@@ -0,0 +1,15 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use super::status::ProjectStatus;
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct StatusChange {
+  pub id: i32,
+  pub from: ProjectStatus,
+  pub to: ProjectStatus,
+  pub actor_id: Option<String>,
+  pub reason: Option<String>,
+  pub created_at: DateTime<Utc>,
+}
@@ -0,0 +1,23 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use super::{appropriate_status::AppropriateStatus, architectural_service::ArchitecturalServices, budget_range::BudgetRange, square_range::SquareRange, commercial_work::CommercialWork};
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectTemplate {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub building_type: String,
+    pub is_save_carbon: bool,
+    pub appropriate_status: AppropriateStatus,
+    pub has_financing_secured: bool,
+    pub budget_range: BudgetRange,
+    pub square_range: SquareRange,
+    pub commercial_work: CommercialWork,
+    pub architectural_services: Vec<ArchitecturalServices>,
+    pub creator_id: String,
+    pub organization_id: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
@@ -0,0 +1,107 @@
+use super::status::ProjectStatus;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Actor {
+  Owner,
+  Vendor,
+  Admin,
+  System,
+}
+
+pub struct Transition {
+  pub from: ProjectStatus,
+  pub to: ProjectStatus,
+  pub actors: &'static [Actor],
+  pub requires_reason: bool,
+  pub requires_vendor: bool,
+}
+
+const fn transition(
+  from: ProjectStatus,
+  to: ProjectStatus,
+  actors: &'static [Actor],
+  requires_reason: bool,
+  requires_vendor: bool,
+) -> Transition {
+  Transition { from, to, actors, requires_reason, requires_vendor }
+}
+
+const OWNER: &[Actor] = &[Actor::Owner, Actor::Admin];
+const VENDOR: &[Actor] = &[Actor::Vendor, Actor::Admin];
+const SYSTEM: &[Actor] = &[Actor::System, Actor::Admin];
+
+pub const TRANSITIONS: &[Transition] = &[
+  transition(ProjectStatus::Active, ProjectStatus::Ongoing, OWNER, false, true),
+  transition(ProjectStatus::Active, ProjectStatus::OnHold, OWNER, true, false),
+  transition(ProjectStatus::Active, ProjectStatus::Withdrawn, OWNER, true, false),
+  transition(ProjectStatus::Ongoing, ProjectStatus::Delivered, VENDOR, false, true),
+  transition(ProjectStatus::Ongoing, ProjectStatus::Overdue, SYSTEM, false, false),
+  transition(ProjectStatus::Ongoing, ProjectStatus::OnHold, OWNER, true, false),
+  transition(ProjectStatus::Ongoing, ProjectStatus::Withdrawn, OWNER, true, false),
+  transition(ProjectStatus::Overdue, ProjectStatus::Delivered, VENDOR, false, true),
+  transition(ProjectStatus::Overdue, ProjectStatus::Ongoing, OWNER, false, true),
+  transition(ProjectStatus::Overdue, ProjectStatus::Withdrawn, OWNER, true, false),
+  transition(ProjectStatus::Delivered, ProjectStatus::Complete, OWNER, false, true),
+  transition(ProjectStatus::Delivered, ProjectStatus::Ongoing, OWNER, true, true),
+  transition(ProjectStatus::OnHold, ProjectStatus::Active, OWNER, false, false),
+  transition(ProjectStatus::OnHold, ProjectStatus::Ongoing, OWNER, false, true),
+  transition(ProjectStatus::OnHold, ProjectStatus::Withdrawn, OWNER, true, false),
+];
+
+impl Transition {
+  pub fn find(from: &ProjectStatus, to: &ProjectStatus) -> Option<&'static Transition> {
+    TRANSITIONS.iter().find(|t| &t.from == from && &t.to == to)
+  }
+
+  pub fn allows(&self, actors: &[Actor]) -> bool {
+    self.actors.iter().any(|a| actors.contains(a))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn find_returns_only_declared_transitions() {
+    assert!(Transition::find(&ProjectStatus::Active, &ProjectStatus::Ongoing).is_some());
+    assert!(Transition::find(&ProjectStatus::Draft, &ProjectStatus::Complete).is_none());
+    assert!(Transition::find(&ProjectStatus::Complete, &ProjectStatus::Active).is_none());
+    assert!(Transition::find(&ProjectStatus::Withdrawn, &ProjectStatus::Active).is_none());
+  }
+
+  #[test]
+  fn drafts_leave_only_through_submit() {
+    assert!(TRANSITIONS.iter().all(|t| t.from != ProjectStatus::Draft && t.to != ProjectStatus::Draft));
+  }
+
+  #[test]
+  fn delivery_is_reserved_to_the_vendor() {
+    let transition = Transition::find(&ProjectStatus::Ongoing, &ProjectStatus::Delivered).unwrap();
+
+    assert!(transition.allows(&[Actor::Vendor]));
+    assert!(!transition.allows(&[Actor::Owner]));
+    assert!(transition.requires_vendor);
+  }
+
+  #[test]
+  fn overdue_is_reserved_to_the_system() {
+    let transition = Transition::find(&ProjectStatus::Ongoing, &ProjectStatus::Overdue).unwrap();
+
+    assert!(transition.allows(&[Actor::System]));
+    assert!(!transition.allows(&[Actor::Owner, Actor::Vendor]));
+  }
+
+  #[test]
+  fn admins_may_take_every_transition() {
+    assert!(TRANSITIONS.iter().all(|t| t.allows(&[Actor::Admin])));
+  }
+
+  #[test]
+  fn holding_and_withdrawing_require_a_reason() {
+    assert!(TRANSITIONS
+      .iter()
+      .filter(|t| t.to == ProjectStatus::OnHold || t.to == ProjectStatus::Withdrawn)
+      .all(|t| t.requires_reason));
+  }
+}
@@ -0,0 +1,48 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use super::user::user_profile::UserProfile;
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ReviewScores {
+    pub quality: i16,
+    pub communication: i16,
+    pub timeliness: i16,
+    pub value: i16,
+}
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Review {
+    pub id: i32,
+    pub project_id: String,
+    pub scores: ReviewScores,
+    pub rating: f64,
+    pub text: String,
+    pub reply: Option<String>,
+    pub replied_at: Option<DateTime<Utc>>,
+    pub is_hidden: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub moderation_note: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub author: UserProfile,
+    pub vendor: UserProfile,
+}
+
+impl Review {
+    pub fn redact_moderation(&mut self) {
+        self.moderation_note = None;
+    }
+}
+
+#[derive(Debug, Serialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct RatingSummary {
+    pub count: i64,
+    pub average: Option<f64>,
+    pub quality: Option<f64>,
+    pub communication: Option<f64>,
+    pub timeliness: Option<f64>,
+    pub value: Option<f64>,
+}
@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize, Serializer};
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(untagged)]
+pub enum AlertFrequency {
+    Instant,
+    Daily,
+    InstantAndDaily,
+    Off,
+}
+
+impl AlertFrequency {
+    pub fn from_str(value: &str) -> Result<AlertFrequency, String> {
+        match value {
+            "Instant" => Ok(AlertFrequency::Instant),
+            "Daily" => Ok(AlertFrequency::Daily),
+            "InstantAndDaily" => Ok(AlertFrequency::InstantAndDaily),
+            "Off" => Ok(AlertFrequency::Off),
+            _ => Err("Alert Frequency is not correct".to_string()),
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AlertFrequency::Instant => "Instant",
+            AlertFrequency::Daily => "Daily",
+            AlertFrequency::InstantAndDaily => "InstantAndDaily",
+            AlertFrequency::Off => "Off",
+        }
+    }
+
+    pub fn is_instant(&self) -> bool {
+        matches!(self, AlertFrequency::Instant | AlertFrequency::InstantAndDaily)
+    }
+}
+
+impl Serialize for AlertFrequency {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
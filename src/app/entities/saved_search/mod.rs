@@ -0,0 +1,30 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use serde_json::Value;
+
+use self::frequency::AlertFrequency;
+
+use super::user::User;
+
+pub mod frequency;
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SavedSearch {
+    pub id: String,
+    pub user_id: String,
+    pub name: String,
+    pub query: Value,
+    pub frequency: AlertFrequency,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SearchMatch {
+    pub search_name: String,
+    pub project_id: String,
+    pub project_name: String,
+    pub project_city: String,
+    pub user: User,
+}
@@ -1,4 +1,7 @@
+pub mod user_detail;
 pub mod user_email;
+pub mod user_profile;
+pub mod user_session;
 pub mod user_token;
 pub mod user_type;
 use chrono::{DateTime, Utc};
@@ -10,16 +13,35 @@ use self::{user_token::UserToken, user_type::UserType};
 #[serde(rename_all = "camelCase")]
 pub struct User {
     pub id: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
     pub email: String,
     pub first_name: String,
     pub last_name: String,
+    pub company: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
     #[serde(rename = "type")]
     pub u_type: UserType,
     #[serde(skip_serializing)]
     pub password_alg: String,
     #[serde(skip_serializing)]
     pub password_hash: String,
+    pub is_compliant: bool,
     pub created_at: DateTime<Utc>,
     #[serde(skip_serializing)]
     pub tokens: Vec<UserToken>,
 }
+
+#[derive(Debug, Clone)]
+pub struct NewUser<'a> {
+    pub first_name: &'a str,
+    pub last_name: &'a str,
+    pub company: Option<&'a str>,
+    pub phone: Option<&'a str>,
+    pub email: &'a str,
+    pub password_hash: &'a str,
+    pub password_alg: &'a str,
+    pub u_type: &'a str,
+    pub is_verified: bool,
+    pub is_primary: bool,
+}
@@ -0,0 +1,11 @@
+use serde::Serialize;
+
+use super::{user_email::UserEmail, user_session::UserSession, User};
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UserDetail {
+    pub user: User,
+    pub emails: Vec<UserEmail>,
+    pub sessions: Vec<UserSession>,
+}
@@ -0,0 +1,50 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::app::entities::{location::UserLocation, review::RatingSummary};
+
+use super::{user_type::UserType, User};
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UserProfile {
+    pub id: String,
+    pub first_name: String,
+    pub last_name: String,
+    #[serde(rename = "type")]
+    pub u_type: UserType,
+    pub created_at: DateTime<Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub company: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_compliant: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed_projects: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rating: Option<RatingSummary>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<UserLocation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+}
+
+impl UserProfile {
+    pub fn from_user(user: &User) -> Self {
+        UserProfile {
+            id: user.id.clone(),
+            first_name: user.first_name.clone(),
+            last_name: user.last_name.clone(),
+            u_type: user.u_type.clone(),
+            created_at: user.created_at,
+            company: None,
+            is_compliant: None,
+            completed_projects: None,
+            rating: None,
+            location: None,
+            email: None,
+            phone: None,
+        }
+    }
+}
@@ -0,0 +1,10 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UserSession {
+    pub id: i32,
+    pub used_for: String,
+    pub created_at: DateTime<Utc>,
+}
@@ -0,0 +1,48 @@
+pub fn line(fields: &[String]) -> String {
+    let mut out = fields.iter().map(|field| escape(field)).collect::<Vec<_>>().join(",");
+    out.push_str("\r\n");
+    out
+}
+
+fn escape(field: &str) -> String {
+    let field = match field.chars().next() {
+        Some('=' | '+' | '-' | '@') if field.parse::<f64>().is_err() => format!("'{field}"),
+        _ => field.to_string(),
+    };
+
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escape_keeps_plain_fields() {
+        assert_eq!(escape("Berlin"), "Berlin");
+        assert_eq!(escape("-42.5"), "-42.5");
+    }
+
+    #[test]
+    fn escape_quotes_separators_and_quotes() {
+        assert_eq!(escape("Berlin, Mitte"), "\"Berlin, Mitte\"");
+        assert_eq!(escape("the \"new\" office"), "\"the \"\"new\"\" office\"");
+        assert_eq!(escape("first\nsecond"), "\"first\nsecond\"");
+    }
+
+    #[test]
+    fn escape_neutralises_formulas() {
+        assert_eq!(escape("=SUM(A1:A2)"), "'=SUM(A1:A2)");
+        assert_eq!(escape("@cmd"), "'@cmd");
+        assert_eq!(escape("+1 555, ext"), "\"'+1 555, ext\"");
+    }
+
+    #[test]
+    fn line_joins_fields_with_crlf() {
+        assert_eq!(line(&["a".to_string(), "b,c".to_string()]), "a,\"b,c\"\r\n");
+    }
+}
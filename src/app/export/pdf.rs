@@ -0,0 +1,167 @@
+const PAGE_WIDTH: f32 = 595.0;
+const PAGE_HEIGHT: f32 = 842.0;
+const MARGIN: f32 = 50.0;
+
+#[derive(Clone, Copy)]
+pub enum Style {
+    Title,
+    Heading,
+    Text,
+}
+
+impl Style {
+    fn font(&self) -> &'static str {
+        match self {
+            Style::Title | Style::Heading => "F2",
+            Style::Text => "F1",
+        }
+    }
+
+    fn size(&self) -> f32 {
+        match self {
+            Style::Title => 16.0,
+            Style::Heading => 11.0,
+            Style::Text => 9.0,
+        }
+    }
+
+    fn leading(&self) -> f32 {
+        match self {
+            Style::Title => 24.0,
+            Style::Heading => 18.0,
+            Style::Text => 12.0,
+        }
+    }
+
+    fn max_chars(&self) -> usize {
+        ((PAGE_WIDTH - 2.0 * MARGIN) / (self.size() * 0.5)) as usize
+    }
+}
+
+#[derive(Default)]
+pub struct SinglePage {
+    lines: Vec<(Style, String)>,
+}
+
+impl SinglePage {
+    pub fn line(&mut self, style: Style, text: &str) {
+        let max_chars = style.max_chars();
+        let mut current = String::new();
+
+        for word in text.split_whitespace() {
+            if !current.is_empty() && current.chars().count() + word.chars().count() + 1 > max_chars {
+                self.lines.push((style, std::mem::take(&mut current)));
+            }
+            if !current.is_empty() {
+                current.push(' ');
+            }
+            current.push_str(word);
+        }
+
+        self.lines.push((style, current));
+    }
+
+    pub fn render(&self) -> Vec<u8> {
+        let mut content = Vec::new();
+        let mut y = PAGE_HEIGHT - MARGIN;
+
+        content.extend_from_slice(b"BT\n");
+        for (i, (style, text)) in self.lines.iter().enumerate() {
+            let reserved = if i + 1 < self.lines.len() { Style::Text.leading() } else { 0.0 };
+            if y - style.leading() - reserved < MARGIN {
+                y -= Style::Text.leading();
+                content.extend_from_slice(format!("/F1 9 Tf\n1 0 0 1 {MARGIN} {y} Tm\n").as_bytes());
+                content.extend_from_slice(b"(...) Tj\n");
+                break;
+            }
+            y -= style.leading();
+            content.extend_from_slice(format!("/{} {} Tf\n1 0 0 1 {MARGIN} {y} Tm\n(", style.font(), style.size()).as_bytes());
+            content.extend(encode(text));
+            content.extend_from_slice(b") Tj\n");
+        }
+        content.extend_from_slice(b"ET\n");
+
+        let objects = [
+            "<< /Type /Catalog /Pages 2 0 R >>".as_bytes().to_vec(),
+            "<< /Type /Pages /Kids [3 0 R] /Count 1 >>".as_bytes().to_vec(),
+            format!("<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {PAGE_WIDTH} {PAGE_HEIGHT}] /Resources << /Font << /F1 4 0 R /F2 5 0 R >> >> /Contents 6 0 R >>").into_bytes(),
+            "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica /Encoding /WinAnsiEncoding >>".as_bytes().to_vec(),
+            "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica-Bold /Encoding /WinAnsiEncoding >>".as_bytes().to_vec(),
+            [format!("<< /Length {} >>\nstream\n", content.len()).into_bytes(), content, b"\nendstream".to_vec()].concat(),
+        ];
+
+        let mut out = b"%PDF-1.4\n".to_vec();
+        let mut offsets = vec![];
+        for (i, object) in objects.iter().enumerate() {
+            offsets.push(out.len());
+            out.extend_from_slice(format!("{} 0 obj\n", i + 1).as_bytes());
+            out.extend_from_slice(object);
+            out.extend_from_slice(b"\nendobj\n");
+        }
+
+        let xref = out.len();
+        out.extend_from_slice(format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1).as_bytes());
+        for offset in offsets {
+            out.extend_from_slice(format!("{offset:010} 00000 n \n").as_bytes());
+        }
+        out.extend_from_slice(format!("trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{xref}\n%%EOF\n", objects.len() + 1).as_bytes());
+
+        out
+    }
+}
+
+fn encode(text: &str) -> Vec<u8> {
+    let mut out = Vec::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '(' | ')' | '\\' => {
+                out.push(b'\\');
+                out.push(c as u8);
+            }
+            ' '..='~' => out.push(c as u8),
+            '\u{a0}'..='\u{ff}' => out.extend_from_slice(format!("\\{:03o}", c as u32).as_bytes()),
+            _ => out.push(b'?'),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn line_keeps_short_text_on_one_line() {
+        let mut page = SinglePage::default();
+        page.line(Style::Text, "Office renovation in Berlin");
+
+        assert_eq!(page.lines.len(), 1);
+        assert_eq!(page.lines[0].1, "Office renovation in Berlin");
+    }
+
+    #[test]
+    fn line_wraps_long_text_at_word_boundaries() {
+        let text = "word ".repeat(100);
+        let mut page = SinglePage::default();
+        page.line(Style::Text, &text);
+
+        assert!(page.lines.len() > 1);
+        assert!(page.lines.iter().all(|(_, line)| line.chars().count() <= Style::Text.max_chars()));
+        assert_eq!(page.lines.iter().map(|(_, line)| line.split(' ').count()).sum::<usize>(), 100);
+    }
+
+    #[test]
+    fn line_keeps_an_overlong_word_whole() {
+        let word = "x".repeat(Style::Title.max_chars() + 10);
+        let mut page = SinglePage::default();
+        page.line(Style::Title, &format!("a {word} b"));
+
+        assert_eq!(page.lines.iter().map(|(_, line)| line.as_str()).collect::<Vec<_>>(), vec!["a", word.as_str(), "b"]);
+    }
+
+    #[test]
+    fn encode_escapes_pdf_delimiters() {
+        assert_eq!(encode("(a\\b)"), b"\\(a\\\\b\\)".to_vec());
+        assert_eq!(encode("€"), b"?".to_vec());
+    }
+}
@@ -0,0 +1,125 @@
+use chrono::{DateTime, Utc};
+
+use crate::app::entities::{attachment::Attachment, project::{status_change::StatusChange, Project}};
+
+use super::{csv, pdf::{SinglePage, Style}};
+
+const COLUMNS: [&str; 26] = [
+    "id",
+    "name",
+    "status",
+    "city",
+    "street",
+    "zipCode",
+    "floor",
+    "description",
+    "buildingType",
+    "isSaveCarbon",
+    "appropriateStatus",
+    "hasFinancingSecured",
+    "budgetRange",
+    "squareRange",
+    "commercialWork",
+    "architecturalServices",
+    "completionDate",
+    "createdAt",
+    "creatorName",
+    "creatorEmail",
+    "creatorPhone",
+    "organizationId",
+    "vendorId",
+    "latitude",
+    "longitude",
+    "progress",
+];
+
+pub fn csv_header() -> String {
+    csv::line(&COLUMNS.map(String::from))
+}
+
+pub fn csv_row(project: &Project) -> String {
+    csv::line(&fields(project).map(|(_, value)| value))
+}
+
+pub fn summary_pdf(project: &Project, history: &[StatusChange], attachments: &[Attachment]) -> Vec<u8> {
+    let mut page = SinglePage::default();
+    page.line(Style::Title, &project.name);
+
+    page.line(Style::Heading, "Project");
+    for (column, value) in fields(project).iter().filter(|(column, value)| *column != "name" && !value.is_empty()) {
+        page.line(Style::Text, &format!("{column}: {value}"));
+    }
+
+    page.line(Style::Heading, "Status history");
+    if history.is_empty() {
+        page.line(Style::Text, "No status changes");
+    }
+    for change in history {
+        let reason = change.reason.as_deref().map(|r| format!(" ({r})")).unwrap_or_default();
+        page.line(Style::Text, &format!(
+            "{}: {} -> {}{}",
+            date_time(&change.created_at),
+            change.from.as_str(),
+            change.to.as_str(),
+            reason,
+        ));
+    }
+
+    page.line(Style::Heading, "Attachments");
+    if attachments.is_empty() {
+        page.line(Style::Text, "No attachments");
+    }
+    for attachment in attachments {
+        page.line(Style::Text, &format!(
+            "{} [{}] v{}, {} KB, {}",
+            attachment.name,
+            attachment.category.as_str(),
+            attachment.version,
+            (attachment.size + 1023) / 1024,
+            date_time(&attachment.created_at),
+        ));
+    }
+
+    page.render()
+}
+
+fn fields(project: &Project) -> [(&'static str, String); 26] {
+    let optional = |value: &Option<String>| value.clone().unwrap_or_default();
+    let number = |value: Option<f64>| value.map(|v| v.to_string()).unwrap_or_default();
+
+    let values = [
+        project.id.clone(),
+        project.name.clone(),
+        project.status.as_str().to_string(),
+        project.city.clone(),
+        optional(&project.street),
+        optional(&project.zip_code),
+        optional(&project.floor),
+        optional(&project.description),
+        project.building_type.clone(),
+        project.is_save_carbon.to_string(),
+        project.appropriate_status.as_str().to_string(),
+        project.has_financing_secured.to_string(),
+        project.budget_range.as_str().to_string(),
+        project.square_range.as_str().to_string(),
+        project.commercial_work.as_str().to_string(),
+        project.architectural_services.iter().map(|s| s.as_str()).collect::<Vec<_>>().join("; "),
+        project.completion_date.format("%Y-%m-%d").to_string(),
+        date_time(&project.created_at),
+        format!("{} {}", project.creator.first_name, project.creator.last_name),
+        project.creator.email.clone(),
+        optional(&project.creator.phone),
+        optional(&project.organization_id),
+        optional(&project.vendor_id),
+        number(project.latitude),
+        number(project.longitude),
+        number(project.progress),
+    ];
+
+    let mut values = values.into_iter();
+    COLUMNS.map(|column| (column, values.next().unwrap_or_default()))
+}
+
+fn date_time(value: &DateTime<Utc>) -> String {
+    value.format("%Y-%m-%d %H:%M UTC").to_string()
+}
@@ -1,6 +1,8 @@
 mod email;
 pub mod entities;
 mod errors;
+mod export;
 pub mod services;
+mod storage;
 pub mod traits;
 mod utils;
@@ -0,0 +1,320 @@
+use serde::Deserialize;
+use serde_json::{json, Value};
+use validator::{Validate, ValidationError};
+
+use crate::app::{
+    entities::{
+        audit::{audit_action::AuditAction, AuditEntry, AuditRecord},
+        page::Page,
+        project::{draft::ProjectDraft, Project},
+        user::{user_detail::UserDetail, user_type::UserType, User},
+    },
+    errors::BaseError,
+    traits::repositories::{
+        audit::TAuditRepositories, project::TProjectRepositories,
+        review::TReviewRepositories, user::TUserRepositories,
+    },
+    utils::validate_params::validate,
+};
+
+use super::{auth::AuthService, user::UserService};
+
+#[derive(Debug, Validate, Deserialize)]
+pub struct ChangeTypeParams {
+    #[validate(custom(function = "validate_user_role", message = "User type is invalid"))]
+    #[serde(rename = "type")]
+    u_type: String,
+}
+
+fn validate_user_role(role: &str) -> Result<(), ValidationError> {
+    if [UserType::Client.to_string(), UserType::Vendor.to_string(), UserType::Admin.to_string()].contains(&role.to_string()) {
+        return Ok(());
+    }
+    Err(ValidationError::new(""))
+}
+
+#[derive(Debug, Validate, Deserialize)]
+pub struct VerifyEmailParams {
+    #[validate(email(message = "Email is invalid"))]
+    email: String,
+}
+
+#[derive(Debug, Validate, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModerateReviewParams {
+    is_hidden: bool,
+    #[validate(length(max = 1000, message = "The note length should be max 1000 symbols"))]
+    note: Option<String>,
+}
+
+#[derive(Debug, Validate, Deserialize)]
+pub struct TrashParams {
+    #[validate(range(min = 1, max = 100, message = "Limit should be between 1 and 100"))]
+    limit: Option<i64>,
+    #[validate(range(min = 0, message = "Skip should be positive"))]
+    skip: Option<i64>,
+}
+
+pub struct AdminService<'a> {
+    user_rep: &'a (dyn TUserRepositories + Send + Sync),
+    audit_rep: &'a (dyn TAuditRepositories + Send + Sync),
+    review_rep: &'a (dyn TReviewRepositories + Send + Sync),
+    project_rep: &'a (dyn TProjectRepositories + Send + Sync),
+    user_service: UserService<'a>,
+    auth_service: AuthService<'a>,
+}
+
+impl<'a> AdminService<'a> {
+    pub fn new(
+        user_rep: &'a (dyn TUserRepositories + Send + Sync),
+        audit_rep: &'a (dyn TAuditRepositories + Send + Sync),
+        review_rep: &'a (dyn TReviewRepositories + Send + Sync),
+        project_rep: &'a (dyn TProjectRepositories + Send + Sync),
+        token: &'a str,
+    ) -> Self {
+        Self {
+            user_rep,
+            audit_rep,
+            review_rep,
+            project_rep,
+            user_service: UserService::new(user_rep, token),
+            auth_service: AuthService::default(user_rep),
+        }
+    }
+
+    pub async fn get_user(&self, id: &str) -> Result<UserDetail, BaseError> {
+        match self.get_current_admin().await {
+            Ok(admin) => admin,
+            Err(err) => return Err(err),
+        };
+
+        let user = match self.user_rep.find_by_id(id, false).await {
+            Some(user) => user,
+            None => return Err(BaseError::new("User not found".to_string())),
+        };
+
+        Ok(UserDetail {
+            emails: self.user_rep.find_emails(id).await,
+            sessions: self.user_rep.find_sessions(id).await,
+            user,
+        })
+    }
+
+    pub async fn get_audit(&self, id: &str) -> Result<Vec<AuditEntry>, BaseError> {
+        match self.get_current_admin().await {
+            Ok(admin) => admin,
+            Err(err) => return Err(err),
+        };
+
+        Ok(self.audit_rep.find_by_target(id).await)
+    }
+
+    pub async fn change_type(&self, id: &str, params: ChangeTypeParams) -> Result<(), BaseError> {
+        match validate(&params) {
+            Ok(_) => (),
+            Err(e) => return Err(e),
+        };
+
+        let (admin, user) = match self.get_target(id).await {
+            Ok(res) => res,
+            Err(err) => return Err(err),
+        };
+
+        if admin.id == user.id {
+            return Err(BaseError::new("You can not change your own type".to_string()));
+        }
+
+        let audit = AuditRecord::new(
+            &admin.id,
+            id,
+            AuditAction::ChangeType,
+            json!({ "from": user.u_type.to_string(), "to": params.u_type }),
+        );
+
+        match self.user_rep.update_type(id, &params.u_type, &audit).await {
+            Ok(true) => Ok(()),
+            Ok(false) => Err(BaseError::new("User not found".to_string())),
+            Err(e) => Err(BaseError::new(e)),
+        }
+    }
+
+    pub async fn verify_email(&self, id: &str, params: VerifyEmailParams) -> Result<(), BaseError> {
+        match validate(&params) {
+            Ok(_) => (),
+            Err(e) => return Err(e),
+        };
+
+        let (admin, _) = match self.get_target(id).await {
+            Ok(res) => res,
+            Err(err) => return Err(err),
+        };
+
+        let emails = self.user_rep.find_emails(id).await;
+        match emails.iter().find(|e| e.email == params.email) {
+            Some(e) if e.is_verified => return Err(BaseError::new("Email is already verified".to_string())),
+            Some(_) => (),
+            None => return Err(BaseError::new("Email not found".to_string())),
+        };
+
+        let audit = AuditRecord::new(&admin.id, id, AuditAction::VerifyEmail, json!({ "email": params.email }));
+
+        match self.user_rep.force_verify_email(id, &params.email, &audit).await {
+            Ok(true) => Ok(()),
+            Ok(false) => Err(BaseError::new("Email is already verified".to_string())),
+            Err(e) => Err(BaseError::new(e)),
+        }
+    }
+
+    pub async fn send_password_reset(&self, id: &str) -> Result<(), BaseError> {
+        let (admin, user) = match self.get_target(id).await {
+            Ok(res) => res,
+            Err(err) => return Err(err),
+        };
+
+        match self.auth_service.send_password_reset(&user, &user.email).await {
+            Ok(_) => (),
+            Err(err) => return Err(err),
+        };
+
+        self.audit(&admin, id, AuditAction::ResetPassword, json!({ "email": user.email }))
+            .await
+    }
+
+    pub async fn delete_user(&self, id: &str) -> Result<(), BaseError> {
+        let (admin, user) = match self.get_target(id).await {
+            Ok(res) => res,
+            Err(err) => return Err(err),
+        };
+
+        if admin.id == user.id {
+            return Err(BaseError::new("You can not delete yourself".to_string()));
+        }
+
+        if self.project_rep.owns_any(id).await {
+            return Err(BaseError::new(
+                "The user still owns projects, including drafts and deleted projects awaiting purge".to_string(),
+            ));
+        }
+
+        let audit = AuditRecord::new(
+            &admin.id,
+            id,
+            AuditAction::DeleteUser,
+            json!({
+                "email": user.email,
+                "firstName": user.first_name,
+                "lastName": user.last_name,
+                "type": user.u_type.to_string(),
+            }),
+        );
+
+        match self.user_rep.delete(id, &audit).await {
+            Ok(true) => Ok(()),
+            Ok(false) => Err(BaseError::new("User not found".to_string())),
+            Err(e) => Err(BaseError::new(e)),
+        }
+    }
+
+    pub async fn moderate_review(&self, id: i32, params: ModerateReviewParams) -> Result<(), BaseError> {
+        match validate(&params) {
+            Ok(_) => (),
+            Err(e) => return Err(e),
+        };
+
+        let admin = match self.get_current_admin().await {
+            Ok(admin) => admin,
+            Err(err) => return Err(err),
+        };
+
+        let review = match self.review_rep.find_by_id(id).await {
+            Some(review) => review,
+            None => return Err(BaseError::new("Review not found".to_string())),
+        };
+
+        let audit = AuditRecord::new(
+            &admin.id,
+            &review.vendor.id,
+            AuditAction::ModerateReview,
+            json!({ "reviewId": id, "isHidden": params.is_hidden, "note": params.note }),
+        );
+
+        match self.review_rep.set_hidden(id, params.is_hidden, params.note.as_deref(), &audit).await {
+            Ok(true) => Ok(()),
+            Ok(false) => Err(BaseError::new("Review not found".to_string())),
+            Err(e) => Err(BaseError::new(e)),
+        }
+    }
+
+    pub async fn get_trash(&self, params: TrashParams) -> Result<Page<Project>, BaseError> {
+        match validate(&params) {
+            Ok(_) => (),
+            Err(e) => return Err(e),
+        };
+
+        match self.get_current_admin().await {
+            Ok(admin) => admin,
+            Err(err) => return Err(err),
+        };
+
+        Ok(self.project_rep.find_deleted(params.limit.unwrap_or(20), params.skip.unwrap_or(0)).await)
+    }
+
+    pub async fn get_draft_trash(&self, params: TrashParams) -> Result<Page<ProjectDraft>, BaseError> {
+        match validate(&params) {
+            Ok(_) => (),
+            Err(e) => return Err(e),
+        };
+
+        match self.get_current_admin().await {
+            Ok(admin) => admin,
+            Err(err) => return Err(err),
+        };
+
+        Ok(self.project_rep.find_deleted_drafts(params.limit.unwrap_or(20), params.skip.unwrap_or(0)).await)
+    }
+
+    pub async fn restore_project(&self, id: &str) -> Result<(), BaseError> {
+        let admin = match self.get_current_admin().await {
+            Ok(admin) => admin,
+            Err(err) => return Err(err),
+        };
+
+        match self.project_rep.restore(id, &admin.id).await {
+            Ok(true) => Ok(()),
+            Ok(false) => Err(BaseError::new("Project not found".to_string())),
+            Err(e) => Err(BaseError::new(e)),
+        }
+    }
+
+    async fn get_current_admin(&self) -> Result<User, BaseError> {
+        let user = match self.user_service.get_current_user().await {
+            Ok(user) => user,
+            Err(err) => return Err(err),
+        };
+
+        if user.u_type != UserType::Admin {
+            return Err(BaseError::new("Forbidden".to_string()));
+        }
+
+        Ok(user)
+    }
+
+    async fn get_target(&self, id: &str) -> Result<(User, User), BaseError> {
+        let admin = match self.get_current_admin().await {
+            Ok(admin) => admin,
+            Err(err) => return Err(err),
+        };
+
+        match self.user_rep.find_by_id(id, false).await {
+            Some(user) => Ok((admin, user)),
+            None => Err(BaseError::new("User not found".to_string())),
+        }
+    }
+
+    async fn audit(&self, admin: &User, target_id: &str, action: AuditAction, details: Value) -> Result<(), BaseError> {
+        match self.audit_rep.insert(&admin.id, target_id, action, details).await {
+            Ok(_) => Ok(()),
+            Err(e) => Err(BaseError::new(e)),
+        }
+    }
+}
@@ -0,0 +1,301 @@
+use validator::{Validate, ValidationError};
+
+use crate::app::{
+    entities::{
+        attachment::{category::AttachmentCategory, Attachment},
+        project::{scope::ProjectScope, status::ProjectStatus, Project},
+        user::User,
+    },
+    errors::BaseError,
+    storage::LocalStorage,
+    traits::{
+        repositories::{
+            attachment::TAttachmentRepositories, bid::TBidRepositories,
+            organization::TOrganizationRepositories,
+            project::TProjectRepositories, user::TUserRepositories,
+        },
+        storage::TStorage,
+    },
+    utils::{
+        jwt::{ClaimType, JWT},
+        validate_params::validate,
+    },
+};
+
+use super::{bid::can_manage_bid, project::can_manage_project, user::UserService};
+
+pub const MAX_ATTACHMENT_SIZE: usize = 20 * 1024 * 1024;
+
+#[derive(Debug, Validate)]
+pub struct UploadParams {
+    #[validate(custom(function = "validate_category", message = "Attachment category is invalid"))]
+    pub category: String,
+    #[validate(length(min = 1, max = 255, message = "The file name length should be 1 to 255 symbols"))]
+    pub name: String,
+    pub content_type: String,
+    pub data: Vec<u8>,
+}
+
+fn validate_category(value: &str) -> Result<(), ValidationError> {
+    match AttachmentCategory::from_str(value) {
+        Ok(_) => Ok(()),
+        Err(_) => Err(ValidationError::new("")),
+    }
+}
+
+pub fn with_urls(attachments: Vec<Attachment>) -> Vec<Attachment> {
+    let jwt = JWT::default();
+
+    attachments
+        .into_iter()
+        .map(|mut attachment| {
+            attachment.url = jwt
+                .download(&attachment.id)
+                .ok()
+                .map(|token| format!("/attachments/{}/download?token={token}", attachment.id));
+            attachment
+        })
+        .collect()
+}
+
+pub struct AttachmentService<'a> {
+    project_rep: &'a (dyn TProjectRepositories + Send + Sync),
+    organization_rep: &'a (dyn TOrganizationRepositories + Send + Sync),
+    attachment_rep: &'a (dyn TAttachmentRepositories + Send + Sync),
+    bid_rep: &'a (dyn TBidRepositories + Send + Sync),
+    user_service: UserService<'a>,
+    storage: Box<dyn TStorage + Send + Sync>,
+}
+
+impl<'a> AttachmentService<'a> {
+    pub fn new(
+        user_rep: &'a (dyn TUserRepositories + Send + Sync),
+        project_rep: &'a (dyn TProjectRepositories + Send + Sync),
+        organization_rep: &'a (dyn TOrganizationRepositories + Send + Sync),
+        attachment_rep: &'a (dyn TAttachmentRepositories + Send + Sync),
+        bid_rep: &'a (dyn TBidRepositories + Send + Sync),
+        token: &'a str,
+    ) -> Self {
+        Self {
+            project_rep,
+            organization_rep,
+            attachment_rep,
+            bid_rep,
+            user_service: UserService::new(user_rep, token),
+            storage: Box::new(LocalStorage::default()),
+        }
+    }
+
+    pub async fn upload(&self, project_id: &str, params: UploadParams) -> Result<Attachment, BaseError> {
+        match Self::check_file(&params) {
+            Ok(_) => (),
+            Err(e) => return Err(e),
+        };
+
+        let user = match self.get_uploader(project_id).await {
+            Ok(user) => user,
+            Err(err) => return Err(err),
+        };
+
+        self.store(project_id, None, None, &user.id, params).await
+    }
+
+    pub async fn upload_to_bid(&self, bid_id: &str, params: UploadParams) -> Result<Attachment, BaseError> {
+        match Self::check_file(&params) {
+            Ok(_) => (),
+            Err(e) => return Err(e),
+        };
+
+        let user = match self.user_service.get_current_user().await {
+            Ok(user) => user,
+            Err(err) => return Err(err),
+        };
+
+        let bid = match self.bid_rep.find_by_id(bid_id).await {
+            Some(bid) => bid,
+            None => return Err(BaseError::new("Not Found".to_string())),
+        };
+
+        if !can_manage_bid(self.organization_rep, &user, &bid).await {
+            return Err(BaseError::new("Forbidden".to_string()));
+        }
+
+        let is_active = match self.project_rep.find_by_id(&bid.project_id).await {
+            Some(project) => project.status == ProjectStatus::Active,
+            None => false,
+        };
+
+        if !bid.status.is_open() || !is_active {
+            return Err(BaseError::new("The bid can no longer be changed".to_string()));
+        }
+
+        self.store(&bid.project_id, Some(bid_id), None, &user.id, params).await
+    }
+
+    pub async fn replace(&self, project_id: &str, file_id: &str, params: UploadParams) -> Result<Attachment, BaseError> {
+        match Self::check_file(&params) {
+            Ok(_) => (),
+            Err(e) => return Err(e),
+        };
+
+        let user = match self.get_uploader(project_id).await {
+            Ok(user) => user,
+            Err(err) => return Err(err),
+        };
+
+        let current = match self.attachment_rep.find_versions(project_id, file_id).await.into_iter().next() {
+            Some(current) => current,
+            None => return Err(BaseError::new("Attachment not found".to_string())),
+        };
+
+        if current.category.as_str() != params.category {
+            return Err(BaseError::new("The new version should keep the attachment category".to_string()));
+        }
+
+        self.store(project_id, None, Some(file_id), &user.id, params).await
+    }
+
+    pub async fn get_by_project(&self, project_id: &str) -> Result<Vec<Attachment>, BaseError> {
+        match self.get_project(project_id).await {
+            Ok(_) => Ok(with_urls(self.attachment_rep.find_by_project(project_id).await)),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub async fn get_versions(&self, project_id: &str, file_id: &str) -> Result<Vec<Attachment>, BaseError> {
+        match self.get_project(project_id).await {
+            Ok(_) => Ok(with_urls(self.attachment_rep.find_versions(project_id, file_id).await)),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub async fn download(&self, id: &str, token: &str) -> Result<(Attachment, Vec<u8>), BaseError> {
+        match JWT::default().parse(token, Some(ClaimType::Download)) {
+            Ok(claims) if claims.sub == id => (),
+            _ => return Err(BaseError::new("Token is not valid".to_string())),
+        };
+
+        let attachment = match self.attachment_rep.find_by_id(id).await {
+            Some(attachment) => attachment,
+            None => return Err(BaseError::new("Attachment not found".to_string())),
+        };
+
+        match self.storage.get(&attachment.storage_key()).await {
+            Ok(data) => Ok((attachment, data)),
+            Err(e) => Err(BaseError::new(e)),
+        }
+    }
+
+    async fn store(
+        &self,
+        project_id: &str,
+        bid_id: Option<&str>,
+        file_id: Option<&str>,
+        user_id: &str,
+        params: UploadParams,
+    ) -> Result<Attachment, BaseError> {
+        let attachment = match self.attachment_rep.insert(project_id, bid_id, file_id, user_id, &params).await {
+            Ok(attachment) => attachment,
+            Err(e) => return Err(BaseError::new(e)),
+        };
+
+        if let Err(e) = self.storage.put(&attachment.storage_key(), &params.data).await {
+            let _ = self.attachment_rep.delete(&attachment.id).await;
+            return Err(BaseError::new(e));
+        }
+
+        Ok(with_urls(vec![attachment]).remove(0))
+    }
+
+    fn check_file(params: &UploadParams) -> Result<(), BaseError> {
+        match validate(params) {
+            Ok(_) => (),
+            Err(e) => return Err(e),
+        };
+
+        if params.data.is_empty() || params.data.len() > MAX_ATTACHMENT_SIZE {
+            return Err(BaseError::new(format!(
+                "The file size should be between 1 byte and {} MB",
+                MAX_ATTACHMENT_SIZE / 1024 / 1024
+            )));
+        }
+
+        let category = AttachmentCategory::from_str(&params.category).unwrap();
+        if !category.allows(&params.content_type) {
+            return Err(BaseError::new(format!(
+                "{} files are not allowed for the {} category",
+                params.content_type,
+                category.as_str()
+            )));
+        }
+
+        Ok(())
+    }
+
+    async fn get_uploader(&self, project_id: &str) -> Result<User, BaseError> {
+        let (user, project) = match self.get_project(project_id).await {
+            Ok(res) => res,
+            Err(err) => return Err(err),
+        };
+
+        if self.can_upload(&user, &project).await {
+            Ok(user)
+        } else {
+            Err(BaseError::new("Forbidden".to_string()))
+        }
+    }
+
+    async fn get_project(&self, project_id: &str) -> Result<(User, Project), BaseError> {
+        let user = match self.user_service.get_current_user().await {
+            Ok(user) => user,
+            Err(err) => return Err(err),
+        };
+
+        match self.project_rep.find_visible_by_id(project_id, &ProjectScope::for_user(&user)).await {
+            Some(project) => Ok((user, project)),
+            None => Err(BaseError::new("Not Found".to_string())),
+        }
+    }
+
+    async fn can_upload(&self, user: &User, project: &Project) -> bool {
+        project.vendor_id.as_deref() == Some(user.id.as_str())
+            || can_manage_project(self.organization_rep, user, project).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn upload(category: &str, content_type: &str, size: usize) -> UploadParams {
+        UploadParams {
+            category: category.to_string(),
+            name: "file".to_string(),
+            content_type: content_type.to_string(),
+            data: vec![0; size],
+        }
+    }
+
+    fn error(params: UploadParams) -> String {
+        AttachmentService::check_file(&params).unwrap_err().message
+    }
+
+    #[test]
+    fn check_file_limits_content_types_by_category() {
+        assert!(AttachmentService::check_file(&upload("Drawing", "image/vnd.dwg", 10)).is_ok());
+        assert!(AttachmentService::check_file(&upload("Other", "text/csv", 10)).is_ok());
+        assert_eq!(
+            error(upload("Photo", "application/pdf", 10)),
+            "application/pdf files are not allowed for the Photo category"
+        );
+        assert!(AttachmentService::check_file(&upload("Invoice", "application/pdf", 10)).is_err());
+    }
+
+    #[test]
+    fn check_file_rejects_empty_and_oversized_files() {
+        let message = "The file size should be between 1 byte and 20 MB";
+        assert_eq!(error(upload("Permit", "application/pdf", 0)), message);
+        assert_eq!(error(upload("Permit", "application/pdf", MAX_ATTACHMENT_SIZE + 1)), message);
+        assert!(AttachmentService::check_file(&upload("Permit", "application/pdf", MAX_ATTACHMENT_SIZE)).is_ok());
+    }
+}
@@ -2,7 +2,7 @@ use std::time::SystemTime;
 
 use crate::app::{
     email::auth::AuthEvents,
-    entities::user::{user_token::UserToken, user_type::UserType},
+    entities::user::{user_token::UserToken, user_type::UserType, NewUser, User},
     traits::repositories::user::TUserRepositories,
     utils::{
         hash_pwd::{hash_pwd, verify_pwd},
@@ -29,6 +29,8 @@ pub struct CreateInputData {
     role: String,
     #[validate(phone(message = "Phone is invalid"))]
     phone: Option<String>,
+    #[validate(length(min = 2, max = 128, message = "The company length should be 2 to 128 symbols"))]
+    company: Option<String>,
 }
 
 fn validate_user_role(role: &str) -> Result<(), ValidationError> {
@@ -91,17 +93,18 @@ impl<'a> AuthService<'a> {
 
         let user_insert_result = self
             .user_rep
-            .insert(
-                &signup_data.first_name,
-                &signup_data.last_name,
-                signup_data.phone.as_deref(),
-                &signup_data.email,
-                &password_hash,
-                &password_alg,
-                &signup_data.role,
-                false,
-                true,
-            )
+            .insert(&NewUser {
+                first_name: &signup_data.first_name,
+                last_name: &signup_data.last_name,
+                company: signup_data.company.as_deref(),
+                phone: signup_data.phone.as_deref(),
+                email: &signup_data.email,
+                password_hash: &password_hash,
+                password_alg: &password_alg,
+                u_type: &signup_data.role,
+                is_verified: false,
+                is_primary: true,
+            })
             .await;
 
         let user_id = match user_insert_result {
@@ -117,10 +120,7 @@ impl<'a> AuthService<'a> {
             Err(e) => return Err(e),
         }
 
-        let admins = self.user_rep.find(vec![UserType::Admin], None, None, None).await;
-        let emails: Vec<&str> = admins.iter().map(|u| u.email.as_str()).collect();
-
-        match self.events.on_create_user(&signup_data.email, emails).await {
+        match self.events.on_create_user(&signup_data.email).await {
             Ok(()) => Ok(user_id),
             Err(e) => Err(BaseError::new(e.to_string())),
         }
@@ -253,7 +253,11 @@ impl<'a> AuthService<'a> {
             Err(err) => return Err(err),
         }
 
-        let code = match JWT::default().forgot_password(&user) {
+        self.send_password_reset(&user, &data.email).await
+    }
+
+    pub async fn send_password_reset(&self, user: &User, email: &str) -> Result<(), BaseError> {
+        let code = match JWT::default().forgot_password(user) {
             Ok(token) => token,
             Err(e) => return Err(BaseError::new(e)),
         };
@@ -267,7 +271,7 @@ impl<'a> AuthService<'a> {
             return Err(BaseError::new(result.err().unwrap()));
         }
 
-        match self.events.on_forgot_password(&data.email, &code).await {
+        match self.events.on_forgot_password(email, &code).await {
             Ok(()) => Ok(()),
             Err(e) => Err(BaseError::new(e.to_string())),
         }
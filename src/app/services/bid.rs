@@ -0,0 +1,491 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use validator::{Validate, ValidationError};
+
+use crate::app::{
+    email::project::ProjectEvents,
+    entities::{
+        bid::{comparison::BidComparison, status::BidStatus, Bid},
+        preferences::notification_category::NotificationCategory,
+        project::{scope::ProjectScope, status::ProjectStatus, Project},
+        user::{user_type::UserType, User},
+    },
+    errors::BaseError,
+    traits::repositories::{
+        bid::TBidRepositories, notification::TNotificationRepositories,
+        organization::TOrganizationRepositories, preferences::TPreferenceRepositories,
+        project::TProjectRepositories, user::TUserRepositories,
+    },
+    utils::validate_params::validate,
+};
+
+use super::{
+    attachment::with_urls,
+    preferences::{in_app_ids, recipients_for},
+    project::{can_manage_project, notify_status_change, notify_watchers},
+    user::UserService,
+};
+
+#[derive(Debug, Validate, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateBidParams {
+    #[validate(range(min = 1, max = 100_000_000_000i64, message = "The price should be a positive amount in cents"))]
+    pub price: i64,
+    #[validate(range(min = 1, max = 3650, message = "The duration should be 1 to 3650 days"))]
+    pub duration_days: i32,
+    #[validate(custom(function = "validate_start_date", message = "The start date should be in the future"))]
+    pub start_date: DateTime<Utc>,
+    #[validate(length(min = 10, max = 4000, message = "The message length should be 10 to 4000 symbols"))]
+    pub message: String,
+    pub organization_id: Option<String>,
+}
+
+#[derive(Debug, Validate, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateBidParams {
+    #[validate(range(min = 1, max = 100_000_000_000i64, message = "The price should be a positive amount in cents"))]
+    pub price: Option<i64>,
+    #[validate(range(min = 1, max = 3650, message = "The duration should be 1 to 3650 days"))]
+    pub duration_days: Option<i32>,
+    #[validate(custom(function = "validate_start_date", message = "The start date should be in the future"))]
+    pub start_date: Option<DateTime<Utc>>,
+    #[validate(length(min = 10, max = 4000, message = "The message length should be 10 to 4000 symbols"))]
+    pub message: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CompareBidsParams {
+    pub ids: Option<String>,
+}
+
+#[derive(Debug, Validate, Deserialize)]
+pub struct AwardParams {
+    #[validate(length(max = 2000, message = "The note length should be max 2000 symbols"))]
+    pub note: Option<String>,
+}
+
+fn validate_start_date(value: &DateTime<Utc>) -> Result<(), ValidationError> {
+    if *value > Utc::now() {
+        Ok(())
+    } else {
+        Err(ValidationError::new(""))
+    }
+}
+
+pub async fn can_manage_bid(
+    organization_rep: &(dyn TOrganizationRepositories + Send + Sync),
+    user: &User,
+    bid: &Bid,
+) -> bool {
+    if bid.vendor.id == user.id {
+        return true;
+    }
+
+    match &bid.organization_id {
+        Some(organization_id) => match organization_rep.find_role(organization_id, &user.id).await {
+            Some(role) => role.can_manage(),
+            None => false,
+        },
+        None => false,
+    }
+}
+
+pub struct BidService<'a> {
+    user_rep: &'a (dyn TUserRepositories + Send + Sync),
+    notification_rep: &'a (dyn TNotificationRepositories + Send + Sync),
+    project_rep: &'a (dyn TProjectRepositories + Send + Sync),
+    organization_rep: &'a (dyn TOrganizationRepositories + Send + Sync),
+    preference_rep: &'a (dyn TPreferenceRepositories + Send + Sync),
+    bid_rep: &'a (dyn TBidRepositories + Send + Sync),
+    user_service: UserService<'a>,
+}
+
+impl<'a> BidService<'a> {
+    pub fn new(
+        user_rep: &'a (dyn TUserRepositories + Send + Sync),
+        notification_rep: &'a (dyn TNotificationRepositories + Send + Sync),
+        project_rep: &'a (dyn TProjectRepositories + Send + Sync),
+        organization_rep: &'a (dyn TOrganizationRepositories + Send + Sync),
+        preference_rep: &'a (dyn TPreferenceRepositories + Send + Sync),
+        bid_rep: &'a (dyn TBidRepositories + Send + Sync),
+        token: &'a str,
+    ) -> Self {
+        Self {
+            user_rep,
+            notification_rep,
+            project_rep,
+            organization_rep,
+            preference_rep,
+            bid_rep,
+            user_service: UserService::new(user_rep, token),
+        }
+    }
+
+    pub async fn create(&self, project_id: &str, params: CreateBidParams) -> Result<String, BaseError> {
+        match validate(&params) {
+            Ok(_) => (),
+            Err(e) => return Err(e),
+        };
+
+        let user = match self.user_service.get_current_user().await {
+            Ok(user) => user,
+            Err(err) => return Err(err),
+        };
+
+        if user.u_type != UserType::Vendor {
+            return Err(BaseError::new("Forbidden".to_string()));
+        }
+
+        if !user.is_compliant {
+            return Err(BaseError::new("Only compliant vendors can place bids".to_string()));
+        }
+
+        if let Some(organization_id) = &params.organization_id {
+            match self.organization_rep.find_role(organization_id, &user.id).await {
+                Some(role) if role.can_manage() => (),
+                _ => return Err(BaseError::new("Forbidden".to_string())),
+            }
+        }
+
+        let project = match self.project_rep.find_visible_by_id(project_id, &ProjectScope::for_user(&user)).await {
+            Some(project) => project,
+            None => return Err(BaseError::new("Not Found".to_string())),
+        };
+
+        if project.status != ProjectStatus::Active {
+            return Err(BaseError::new("Bids are accepted only on active projects".to_string()));
+        }
+
+        let id = match self.bid_rep.insert(project_id, &user.id, &params).await {
+            Ok(id) => id,
+            Err(e) => return Err(BaseError::new(e)),
+        };
+
+        if let Some(bid) = self.bid_rep.find_by_id(&id).await {
+            self.notify_submitted(&project, &bid).await;
+        }
+
+        Ok(id)
+    }
+
+    pub async fn get_by_project(&self, project_id: &str) -> Result<Vec<Bid>, BaseError> {
+        let (user, project) = match self.get_project(project_id).await {
+            Ok(res) => res,
+            Err(err) => return Err(err),
+        };
+
+        let bids = self.bid_rep.find_by_project(project_id).await;
+
+        if can_manage_project(self.organization_rep, &user, &project).await {
+            return Ok(Self::with_urls(bids));
+        }
+
+        let mut own = vec![];
+        for bid in bids {
+            if can_manage_bid(self.organization_rep, &user, &bid).await {
+                own.push(bid);
+            }
+        }
+
+        Ok(Self::with_urls(own))
+    }
+
+    pub async fn get_current(&self) -> Result<Vec<Bid>, BaseError> {
+        let user = match self.user_service.get_current_user().await {
+            Ok(user) => user,
+            Err(err) => return Err(err),
+        };
+
+        Ok(Self::with_urls(self.bid_rep.find_by_vendor(&user.id).await))
+    }
+
+    pub async fn get_by_id(&self, id: &str) -> Result<Bid, BaseError> {
+        let (user, bid, project) = match self.get_bid(id).await {
+            Ok(res) => res,
+            Err(err) => return Err(err),
+        };
+
+        if can_manage_bid(self.organization_rep, &user, &bid).await
+            || can_manage_project(self.organization_rep, &user, &project).await
+        {
+            Ok(Self::with_urls(vec![bid]).remove(0))
+        } else {
+            Err(BaseError::new("Not Found".to_string()))
+        }
+    }
+
+    pub async fn update(&self, id: &str, params: UpdateBidParams) -> Result<(), BaseError> {
+        match validate(&params) {
+            Ok(_) => (),
+            Err(e) => return Err(e),
+        };
+
+        let (user, bid, project) = match self.get_revisable(id).await {
+            Ok(res) => res,
+            Err(err) => return Err(err),
+        };
+
+        match self.bid_rep.update(&bid.id, &params).await {
+            Ok(true) => (),
+            Ok(false) => return Err(BaseError::new("The bid can no longer be revised".to_string())),
+            Err(e) => return Err(BaseError::new(e)),
+        };
+
+        self.notify_client(&user, &project, format!("revised the bid on the project {}", project.name))
+            .await;
+
+        Ok(())
+    }
+
+    pub async fn withdraw(&self, id: &str) -> Result<(), BaseError> {
+        let (user, bid, project) = match self.get_revisable(id).await {
+            Ok(res) => res,
+            Err(err) => return Err(err),
+        };
+
+        match self.bid_rep.set_status(&bid.id, &bid.status, &BidStatus::Withdrawn).await {
+            Ok(true) => (),
+            Ok(false) => return Err(BaseError::new("The bid can no longer be withdrawn".to_string())),
+            Err(e) => return Err(BaseError::new(e)),
+        };
+
+        self.notify_client(&user, &project, format!("withdrew the bid on the project {}", project.name))
+            .await;
+
+        Ok(())
+    }
+
+    pub async fn shortlist(&self, id: &str, is_shortlisted: bool) -> Result<(), BaseError> {
+        let (bid, _, _) = match self.get_decidable(id).await {
+            Ok(res) => res,
+            Err(err) => return Err(err),
+        };
+
+        let (from, to) = match is_shortlisted {
+            true => (BidStatus::Submitted, BidStatus::Shortlisted),
+            false => (BidStatus::Shortlisted, BidStatus::Submitted),
+        };
+
+        match self.bid_rep.set_status(&bid.id, &from, &to).await {
+            Ok(true) => Ok(()),
+            Ok(false) => Err(BaseError::new(format!("Only {} bids can be moved to {}", from.as_str(), to.as_str()))),
+            Err(e) => Err(BaseError::new(e)),
+        }
+    }
+
+    pub async fn compare(&self, project_id: &str, params: CompareBidsParams) -> Result<BidComparison, BaseError> {
+        let (user, project) = match self.get_project(project_id).await {
+            Ok(res) => res,
+            Err(err) => return Err(err),
+        };
+
+        if !can_manage_project(self.organization_rep, &user, &project).await {
+            return Err(BaseError::new("Forbidden".to_string()));
+        }
+
+        let bids: Vec<Bid> = self
+            .bid_rep
+            .find_by_project(project_id)
+            .await
+            .into_iter()
+            .filter(|bid| bid.status.is_open())
+            .collect();
+
+        let bids = match params.ids {
+            Some(ids) => {
+                let ids: Vec<&str> = ids.split(',').map(|id| id.trim()).collect();
+                bids.into_iter().filter(|bid| ids.contains(&bid.id.as_str())).collect()
+            }
+            None if bids.iter().any(|bid| bid.status == BidStatus::Shortlisted) => bids
+                .into_iter()
+                .filter(|bid| bid.status == BidStatus::Shortlisted)
+                .collect(),
+            None => bids,
+        };
+
+        Ok(BidComparison::from_bids(Self::with_urls(bids)))
+    }
+
+    pub async fn award(&self, id: &str, params: AwardParams) -> Result<(), BaseError> {
+        match validate(&params) {
+            Ok(_) => (),
+            Err(e) => return Err(e),
+        };
+
+        let (bid, user, project) = match self.get_decidable(id).await {
+            Ok(res) => res,
+            Err(err) => return Err(err),
+        };
+
+        let declined: Vec<User> = self
+            .bid_rep
+            .find_by_project(&project.id)
+            .await
+            .into_iter()
+            .filter(|other| other.id != bid.id && other.status.is_open())
+            .map(|other| other.vendor)
+            .collect();
+
+        let res = self
+            .bid_rep
+            .award(&bid.id, &project.id, &bid.vendor.id, &user.id, params.note.as_deref())
+            .await;
+
+        match res {
+            Ok(true) => (),
+            Ok(false) => return Err(BaseError::new("The bid can no longer be awarded".to_string())),
+            Err(e) => return Err(BaseError::new(e)),
+        };
+
+        let mut started = project.clone();
+        started.vendor_id = Some(bid.vendor.id.clone());
+        notify_status_change(
+            self.user_rep,
+            self.notification_rep,
+            self.preference_rep,
+            &started,
+            &ProjectStatus::Ongoing,
+            Some("Bid awarded"),
+            Some(&user),
+        ).await;
+
+        notify_watchers(
+            self.project_rep,
+            self.notification_rep,
+            self.preference_rep,
+            &started,
+            format!("awarded the project {} you watch", project.name).as_str(),
+            Some(&user.id),
+        ).await;
+
+        self.notify_declined(&user, &project, &declined).await;
+
+        Ok(())
+    }
+
+    async fn get_project(&self, project_id: &str) -> Result<(User, Project), BaseError> {
+        let user = match self.user_service.get_current_user().await {
+            Ok(user) => user,
+            Err(err) => return Err(err),
+        };
+
+        match self.project_rep.find_visible_by_id(project_id, &ProjectScope::for_user(&user)).await {
+            Some(project) => Ok((user, project)),
+            None => Err(BaseError::new("Not Found".to_string())),
+        }
+    }
+
+    async fn get_bid(&self, id: &str) -> Result<(User, Bid, Project), BaseError> {
+        let bid = match self.bid_rep.find_by_id(id).await {
+            Some(bid) => bid,
+            None => return Err(BaseError::new("Not Found".to_string())),
+        };
+
+        match self.get_project(&bid.project_id).await {
+            Ok((user, project)) => Ok((user, bid, project)),
+            Err(err) => Err(err),
+        }
+    }
+
+    async fn get_revisable(&self, id: &str) -> Result<(User, Bid, Project), BaseError> {
+        let (user, bid, project) = match self.get_bid(id).await {
+            Ok(res) => res,
+            Err(err) => return Err(err),
+        };
+
+        if !can_manage_bid(self.organization_rep, &user, &bid).await {
+            return Err(BaseError::new("Forbidden".to_string()));
+        }
+
+        if !bid.status.is_open() || project.status != ProjectStatus::Active {
+            return Err(BaseError::new("The bid can no longer be changed".to_string()));
+        }
+
+        Ok((user, bid, project))
+    }
+
+    async fn get_decidable(&self, id: &str) -> Result<(Bid, User, Project), BaseError> {
+        let (user, bid, project) = match self.get_bid(id).await {
+            Ok(res) => res,
+            Err(err) => return Err(err),
+        };
+
+        if !can_manage_project(self.organization_rep, &user, &project).await {
+            return Err(BaseError::new("Forbidden".to_string()));
+        }
+
+        if project.status != ProjectStatus::Active {
+            return Err(BaseError::new("Bids can be decided only on active projects".to_string()));
+        }
+
+        Ok((bid, user, project))
+    }
+
+    async fn notify_submitted(&self, project: &Project, bid: &Bid) {
+        let mut users = self.user_rep.find(vec![UserType::Admin]).await;
+        users.push(project.creator.clone());
+
+        let recipients = recipients_for(self.preference_rep, &users).await;
+        let ids = in_app_ids(&recipients, &NotificationCategory::Projects);
+
+        if !ids.is_empty() {
+            let _ = self.notification_rep.insert(
+                format!("placed a bid on the project {}", project.name).as_str(),
+                format!("/projects/{}/bids", project.id).as_str(),
+                false,
+                false,
+                &bid.vendor.id,
+                ids,
+            ).await;
+        }
+
+        if !recipients.is_empty() {
+            let _ = ProjectEvents::default().on_bid_submitted(project, bid, recipients).await;
+        }
+    }
+
+    async fn notify_client(&self, user: &User, project: &Project, content: String) {
+        let recipients = recipients_for(self.preference_rep, std::slice::from_ref(&project.creator)).await;
+        let ids = in_app_ids(&recipients, &NotificationCategory::Projects);
+
+        if !ids.is_empty() {
+            let _ = self.notification_rep.insert(
+                content.as_str(),
+                format!("/projects/{}/bids", project.id).as_str(),
+                false,
+                false,
+                &user.id,
+                ids,
+            ).await;
+        }
+    }
+
+    async fn notify_declined(&self, user: &User, project: &Project, vendors: &[User]) {
+        let recipients = recipients_for(self.preference_rep, vendors).await;
+        let ids = in_app_ids(&recipients, &NotificationCategory::Projects);
+
+        if !ids.is_empty() {
+            let _ = self.notification_rep.insert(
+                format!("thanks you for your bid on the project {}. Another proposal was chosen this time", project.name).as_str(),
+                "/bids",
+                false,
+                false,
+                &user.id,
+                ids,
+            ).await;
+        }
+
+        if !recipients.is_empty() {
+            let _ = ProjectEvents::default().on_bid_declined(project, recipients).await;
+        }
+    }
+
+    fn with_urls(bids: Vec<Bid>) -> Vec<Bid> {
+        bids.into_iter()
+            .map(|mut bid| {
+                bid.attachments = with_urls(bid.attachments);
+                bid
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,211 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use validator::{Validate, ValidationError};
+
+use crate::app::{
+    email::compliance::ComplianceEvents,
+    entities::{
+        compliance::{document_type::DocumentType, ComplianceDocument},
+        preferences::{notification_category::NotificationCategory, Preferences},
+        user::user_type::UserType,
+    },
+    errors::BaseError,
+    traits::repositories::{
+        compliance::TComplianceRepositories, notification::TNotificationRepositories,
+        preferences::TPreferenceRepositories, user::TUserRepositories,
+    },
+    utils::validate_params::validate,
+};
+
+use super::{
+    preferences::{in_app_ids, recipients_for},
+    user::UserService,
+};
+
+const REMINDER_DAYS: [i32; 3] = [1, 7, 30];
+
+#[derive(Debug, Validate, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpsertDocumentParams {
+    #[validate(custom(function = "validate_document_type", message = "Document type is invalid"))]
+    #[serde(rename = "type")]
+    pub doc_type: String,
+    #[validate(length(min = 1, max = 64, message = "The number length should be 1 to 64 symbols"))]
+    pub number: Option<String>,
+    pub issued_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
+
+fn validate_document_type(value: &str) -> Result<(), ValidationError> {
+    match DocumentType::from_str(value) {
+        Ok(_) => Ok(()),
+        Err(_) => Err(ValidationError::new("")),
+    }
+}
+
+pub struct ComplianceService<'a> {
+    user_service: UserService<'a>,
+    compliance_rep: &'a (dyn TComplianceRepositories + Send + Sync),
+}
+
+impl<'a> ComplianceService<'a> {
+    pub fn new(
+        user_rep: &'a (dyn TUserRepositories + Send + Sync),
+        compliance_rep: &'a (dyn TComplianceRepositories + Send + Sync),
+        token: &'a str,
+    ) -> Self {
+        Self {
+            compliance_rep,
+            user_service: UserService::new(user_rep, token),
+        }
+    }
+
+    pub async fn upsert_document(&self, params: UpsertDocumentParams) -> Result<i32, BaseError> {
+        match validate(&params) {
+            Ok(_) => (),
+            Err(e) => return Err(e),
+        };
+
+        let user = match self.user_service.get_current_user().await {
+            Ok(user) => user,
+            Err(err) => return Err(err),
+        };
+
+        if user.u_type != UserType::Vendor {
+            return Err(BaseError::new("Forbidden".to_string()));
+        }
+
+        if params.expires_at <= params.issued_at {
+            return Err(BaseError::new("The expiry date should be after the issue date".to_string()));
+        }
+
+        let id = match self.compliance_rep.upsert(&user.id, &params).await {
+            Ok(id) => id,
+            Err(e) => return Err(BaseError::new(e)),
+        };
+
+        match self.compliance_rep.refresh_compliance(&user.id, DocumentType::required()).await {
+            Ok(_) => Ok(id),
+            Err(e) => Err(BaseError::new(e)),
+        }
+    }
+
+    pub async fn get_for_current_user(&self) -> Result<Vec<ComplianceDocument>, BaseError> {
+        let user = match self.user_service.get_current_user().await {
+            Ok(user) => user,
+            Err(err) => return Err(err),
+        };
+
+        Ok(self.compliance_rep.find_by_user(&user.id).await)
+    }
+
+    pub async fn get_by_user(&self, user_id: &str) -> Result<Vec<ComplianceDocument>, BaseError> {
+        let user = match self.user_service.get_current_user().await {
+            Ok(user) => user,
+            Err(err) => return Err(err),
+        };
+
+        if user.u_type != UserType::Admin && user.id != user_id {
+            return Err(BaseError::new("Forbidden".to_string()));
+        }
+
+        Ok(self.compliance_rep.find_by_user(user_id).await)
+    }
+}
+
+pub struct ComplianceMonitor<'a> {
+    user_rep: &'a (dyn TUserRepositories + Send + Sync),
+    notification_rep: &'a (dyn TNotificationRepositories + Send + Sync),
+    compliance_rep: &'a (dyn TComplianceRepositories + Send + Sync),
+    preference_rep: &'a (dyn TPreferenceRepositories + Send + Sync),
+    events: ComplianceEvents,
+}
+
+impl<'a> ComplianceMonitor<'a> {
+    pub fn new(
+        user_rep: &'a (dyn TUserRepositories + Send + Sync),
+        notification_rep: &'a (dyn TNotificationRepositories + Send + Sync),
+        compliance_rep: &'a (dyn TComplianceRepositories + Send + Sync),
+        preference_rep: &'a (dyn TPreferenceRepositories + Send + Sync),
+    ) -> Self {
+        Self {
+            user_rep,
+            notification_rep,
+            compliance_rep,
+            preference_rep,
+            events: ComplianceEvents::default(),
+        }
+    }
+
+    pub async fn send_reminders(&self) -> Result<(), BaseError> {
+        for days in REMINDER_DAYS {
+            let documents = self.compliance_rep.find_for_reminder(days).await;
+
+            for document in documents {
+                match self.compliance_rep.set_reminded(document.id, days).await {
+                    Ok(true) => (),
+                    Ok(false) => continue,
+                    Err(e) => return Err(BaseError::new(e)),
+                };
+
+                let preferences = self
+                    .preference_rep
+                    .find_by_user(&document.vendor.id)
+                    .await
+                    .unwrap_or(Preferences::default());
+                let days_left = (document.expires_at - Utc::now()).num_days() as i32 + 1;
+                let _ = self
+                    .events
+                    .on_document_expiring(&document, days_left.min(days), &preferences)
+                    .await;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub async fn expire_documents(&self) -> Result<(), BaseError> {
+        let vendor_ids = match self.compliance_rep.set_expired_non_compliant().await {
+            Ok(ids) => ids,
+            Err(e) => return Err(BaseError::new(e)),
+        };
+
+        if vendor_ids.is_empty() {
+            return Ok(());
+        }
+
+        let admins = self.user_rep.find(vec![UserType::Admin]).await;
+        let recipients = recipients_for(self.preference_rep, &admins).await;
+        let admin_ids = in_app_ids(&recipients, &NotificationCategory::Compliance);
+
+        for vendor_id in vendor_ids {
+            let vendor = match self.user_rep.find_by_id(&vendor_id, false).await {
+                Some(vendor) => vendor,
+                None => continue,
+            };
+
+            if !admin_ids.is_empty() {
+                let _ = self.notification_rep.insert(
+                    format!("{} {} is no longer compliant: a license or insurance has expired", vendor.first_name, vendor.last_name).as_str(),
+                    format!("/users/{vendor_id}").as_str(),
+                    false,
+                    false,
+                    &vendor.id,
+                    admin_ids.clone(),
+                ).await;
+            }
+
+            let preferences = self
+                .preference_rep
+                .find_by_user(&vendor.id)
+                .await
+                .unwrap_or(Preferences::default());
+            let _ = self
+                .events
+                .on_vendor_non_compliant(&vendor.email, &vendor.first_name, &preferences)
+                .await;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,119 @@
+use std::collections::HashSet;
+
+use crate::app::{
+    entities::location::GazetteerEntry,
+    errors::BaseError,
+    traits::repositories::{
+        gazetteer::TGazetteerRepositories, project::TProjectRepositories, user::TUserRepositories,
+    },
+};
+
+const CHUNK_SIZE: usize = 5000;
+
+// GeoNames postal code dump: country, postal code, place name, 6 admin columns, latitude, longitude, accuracy
+fn parse_line(line: &str) -> Option<GazetteerEntry> {
+    let columns: Vec<&str> = line.split('\t').collect();
+    if columns.len() < 11 {
+        return None;
+    }
+
+    let latitude = columns[9].trim().parse::<f64>().ok()?;
+    let longitude = columns[10].trim().parse::<f64>().ok()?;
+    if !(-90.0..=90.0).contains(&latitude) || !(-180.0..=180.0).contains(&longitude) {
+        return None;
+    }
+
+    let (country_code, zip_code, city) = (columns[0].trim(), columns[1].trim(), columns[2].trim());
+    if country_code.len() != 2 || zip_code.is_empty() || city.is_empty() {
+        return None;
+    }
+
+    Some(GazetteerEntry {
+        country_code: country_code.to_uppercase(),
+        zip_code: zip_code.to_string(),
+        city: city.to_string(),
+        latitude,
+        longitude,
+    })
+}
+
+pub struct GazetteerLoader<'a> {
+    gazetteer_rep: &'a (dyn TGazetteerRepositories + Send + Sync),
+    project_rep: &'a (dyn TProjectRepositories + Send + Sync),
+    user_rep: &'a (dyn TUserRepositories + Send + Sync),
+}
+
+impl<'a> GazetteerLoader<'a> {
+    pub fn new(
+        gazetteer_rep: &'a (dyn TGazetteerRepositories + Send + Sync),
+        project_rep: &'a (dyn TProjectRepositories + Send + Sync),
+        user_rep: &'a (dyn TUserRepositories + Send + Sync),
+    ) -> Self {
+        Self { gazetteer_rep, project_rep, user_rep }
+    }
+
+    pub async fn load(&self, path: &str) -> Result<usize, BaseError> {
+        if self.gazetteer_rep.count().await > 0 {
+            return Ok(0);
+        }
+
+        let content = match tokio::fs::read_to_string(path).await {
+            Ok(content) => content,
+            Err(e) => return Err(BaseError::new(format!("Can not read the gazetteer file: {e}"))),
+        };
+
+        let mut seen = HashSet::new();
+        let entries: Vec<GazetteerEntry> = content
+            .lines()
+            .filter_map(parse_line)
+            .filter(|e| seen.insert((e.country_code.clone(), e.zip_code.clone(), e.city.clone())))
+            .collect();
+
+        for chunk in entries.chunks(CHUNK_SIZE) {
+            match self.gazetteer_rep.upsert(chunk).await {
+                Ok(_) => (),
+                Err(e) => return Err(BaseError::new(e)),
+            };
+        }
+
+        match self.locate_missing().await {
+            Ok(_) => Ok(entries.len()),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub async fn locate_missing(&self) -> Result<(), BaseError> {
+        match self.project_rep.locate_missing().await {
+            Ok(_) => (),
+            Err(e) => return Err(BaseError::new(e)),
+        };
+
+        match self.user_rep.locate_missing().await {
+            Ok(_) => Ok(()),
+            Err(e) => Err(BaseError::new(e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_line_reads_geonames_columns() {
+        let entry = parse_line("de\t10115\tBerlin\tBerlin\tBE\t\t\t\t\t52.5323\t13.3846\t6").unwrap();
+
+        assert_eq!(entry.country_code, "DE");
+        assert_eq!(entry.zip_code, "10115");
+        assert_eq!(entry.city, "Berlin");
+        assert_eq!((entry.latitude, entry.longitude), (52.5323, 13.3846));
+    }
+
+    #[test]
+    fn parse_line_skips_incomplete_or_invalid_lines() {
+        assert!(parse_line("DE\t10115\tBerlin").is_none());
+        assert!(parse_line("DE\t\tBerlin\t\t\t\t\t\t\t52.5\t13.4\t6").is_none());
+        assert!(parse_line("DE\t10115\tBerlin\t\t\t\t\t\t\t95.0\t13.4\t6").is_none());
+        assert!(parse_line("DE\t10115\tBerlin\t\t\t\t\t\t\tnorth\t13.4\t6").is_none());
+    }
+}
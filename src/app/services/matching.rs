@@ -0,0 +1,487 @@
+use serde::Deserialize;
+use validator::{Validate, ValidationError};
+
+use crate::app::{
+    entities::{
+        capability::{
+            recommendation::{ProjectRecommendation, VendorRecommendation},
+            VendorCapabilities,
+        },
+        project::{
+            architectural_service::ArchitecturalServices, budget_range::BudgetRange,
+            commercial_work::CommercialWork, scope::ProjectScope, square_range::SquareRange,
+            Project,
+        },
+        location::GeoPoint,
+        user::{user_type::UserType, User},
+    },
+    errors::BaseError,
+    traits::repositories::{
+        capability::TCapabilityRepositories, organization::TOrganizationRepositories,
+        project::TProjectRepositories, user::TUserRepositories,
+    },
+    utils::validate_params::validate,
+};
+
+use super::{project::can_manage_project, user::UserService};
+
+const COMMERCIAL_WORK_WEIGHT: i32 = 30;
+const ARCHITECTURAL_SERVICES_WEIGHT: i32 = 25;
+const BUDGET_RANGE_WEIGHT: i32 = 15;
+const SQUARE_RANGE_WEIGHT: i32 = 10;
+const SAVE_CARBON_WEIGHT: i32 = 10;
+const SERVICE_AREA_WEIGHT: i32 = 10;
+const DEFAULT_LIMIT: i64 = 10;
+
+#[derive(Debug, Validate, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateCapabilitiesParams {
+    #[validate(
+        length(min = 1, message = "At least one commercial work is required"),
+        custom(function = "validate_commercial_works", message = "Commercial Work is invalid")
+    )]
+    pub commercial_works: Vec<String>,
+    #[serde(default)]
+    #[validate(custom(function = "validate_architectural_services", message = "Architectural Services is invalid"))]
+    pub architectural_services: Vec<String>,
+    #[serde(default)]
+    #[validate(custom(function = "validate_budget_ranges", message = "Budget range is invalid"))]
+    pub budget_ranges: Vec<String>,
+    #[serde(default)]
+    #[validate(custom(function = "validate_square_ranges", message = "Square Range is invalid"))]
+    pub square_ranges: Vec<String>,
+    #[serde(default)]
+    pub is_save_carbon: bool,
+    #[serde(default)]
+    #[validate(length(max = 50, message = "The service cities should be max 50"))]
+    pub service_cities: Vec<String>,
+    #[validate(range(min = 1, max = 1000, message = "Service radius should be between 1 and 1000 km"))]
+    pub service_radius: Option<i32>,
+}
+
+fn validate_all<T>(values: &[String], parse: fn(&str) -> Result<T, String>) -> Result<(), ValidationError> {
+    match values.iter().all(|v| parse(v).is_ok()) {
+        true => Ok(()),
+        false => Err(ValidationError::new("")),
+    }
+}
+
+fn validate_commercial_works(values: &[String]) -> Result<(), ValidationError> {
+    validate_all(values, CommercialWork::from_str)
+}
+
+fn validate_architectural_services(values: &[String]) -> Result<(), ValidationError> {
+    validate_all(values, ArchitecturalServices::from_str)
+}
+
+fn validate_budget_ranges(values: &[String]) -> Result<(), ValidationError> {
+    validate_all(values, BudgetRange::from_str)
+}
+
+fn validate_square_ranges(values: &[String]) -> Result<(), ValidationError> {
+    validate_all(values, SquareRange::from_str)
+}
+
+#[derive(Debug, Validate, Deserialize)]
+pub struct RecommendationParams {
+    #[validate(range(min = 1, max = 50, message = "Limit should be between 1 and 50"))]
+    limit: Option<i64>,
+}
+
+fn covers_work(works: &[CommercialWork], work: &CommercialWork) -> bool {
+    if works.contains(&CommercialWork::AllOfTheAbove) || works.contains(work) {
+        return true;
+    }
+
+    *work == CommercialWork::AllOfTheAbove
+        && [
+            CommercialWork::Renovation,
+            CommercialWork::SelectingFurnitureAndEquipment,
+            CommercialWork::EvaluatingForSustainability,
+        ]
+        .iter()
+        .all(|w| works.contains(w))
+}
+
+fn in_service_area(capabilities: &VendorCapabilities, project: &Project, distance: Option<f64>) -> bool {
+    let city = project.city.trim().to_lowercase();
+    capabilities.service_cities.iter().any(|c| c.to_lowercase() == city)
+        || capabilities
+            .service_radius
+            .is_some_and(|radius| distance.is_some_and(|d| d <= radius as f64))
+}
+
+fn score(capabilities: &VendorCapabilities, project: &Project, distance: Option<f64>) -> Option<(i32, Vec<&'static str>)> {
+    if !covers_work(&capabilities.commercial_works, &project.commercial_work) {
+        return None;
+    }
+
+    let in_area = in_service_area(capabilities, project, distance);
+    if capabilities.has_service_area() && !in_area {
+        return None;
+    }
+
+    let mut earned = COMMERCIAL_WORK_WEIGHT + SERVICE_AREA_WEIGHT;
+    let mut possible = COMMERCIAL_WORK_WEIGHT + SERVICE_AREA_WEIGHT;
+    let mut reasons = vec!["commercial work"];
+    if in_area {
+        reasons.push("service area");
+    }
+
+    if !project.architectural_services.is_empty() {
+        possible += ARCHITECTURAL_SERVICES_WEIGHT;
+        let matched = project
+            .architectural_services
+            .iter()
+            .filter(|s| capabilities.architectural_services.contains(s))
+            .count() as i32;
+        if matched > 0 {
+            earned += ARCHITECTURAL_SERVICES_WEIGHT * matched / project.architectural_services.len() as i32;
+            reasons.push("architectural services");
+        }
+    }
+
+    possible += BUDGET_RANGE_WEIGHT;
+    if capabilities.budget_ranges.is_empty() {
+        earned += BUDGET_RANGE_WEIGHT;
+    } else if capabilities.budget_ranges.contains(&project.budget_range) {
+        earned += BUDGET_RANGE_WEIGHT;
+        reasons.push("budget range");
+    }
+
+    possible += SQUARE_RANGE_WEIGHT;
+    if capabilities.square_ranges.is_empty() {
+        earned += SQUARE_RANGE_WEIGHT;
+    } else if capabilities.square_ranges.contains(&project.square_range) {
+        earned += SQUARE_RANGE_WEIGHT;
+        reasons.push("square range");
+    }
+
+    if project.is_save_carbon {
+        possible += SAVE_CARBON_WEIGHT;
+        if capabilities.is_save_carbon {
+            earned += SAVE_CARBON_WEIGHT;
+            reasons.push("carbon saving");
+        }
+    }
+
+    Some((earned * 100 / possible, reasons))
+}
+
+fn by_score_and_distance(a: (i32, Option<f64>), b: (i32, Option<f64>)) -> std::cmp::Ordering {
+    b.0.cmp(&a.0).then_with(|| {
+        a.1.unwrap_or(f64::MAX)
+            .partial_cmp(&b.1.unwrap_or(f64::MAX))
+            .unwrap_or(std::cmp::Ordering::Equal)
+    })
+}
+
+pub struct MatchingService<'a> {
+    user_rep: &'a (dyn TUserRepositories + Send + Sync),
+    project_rep: &'a (dyn TProjectRepositories + Send + Sync),
+    capability_rep: &'a (dyn TCapabilityRepositories + Send + Sync),
+    organization_rep: &'a (dyn TOrganizationRepositories + Send + Sync),
+    user_service: UserService<'a>,
+}
+
+impl<'a> MatchingService<'a> {
+    pub fn new(
+        user_rep: &'a (dyn TUserRepositories + Send + Sync),
+        project_rep: &'a (dyn TProjectRepositories + Send + Sync),
+        capability_rep: &'a (dyn TCapabilityRepositories + Send + Sync),
+        organization_rep: &'a (dyn TOrganizationRepositories + Send + Sync),
+        token: &'a str,
+    ) -> Self {
+        Self {
+            user_rep,
+            project_rep,
+            capability_rep,
+            organization_rep,
+            user_service: UserService::new(user_rep, token),
+        }
+    }
+
+    pub async fn get_capabilities(&self) -> Result<Option<VendorCapabilities>, BaseError> {
+        let user = match self.get_current_vendor().await {
+            Ok(user) => user,
+            Err(err) => return Err(err),
+        };
+
+        Ok(self.capability_rep.find_by_user(&user.id).await)
+    }
+
+    pub async fn update_capabilities(&self, mut params: UpdateCapabilitiesParams) -> Result<VendorCapabilities, BaseError> {
+        match validate(&params) {
+            Ok(_) => (),
+            Err(e) => return Err(e),
+        };
+
+        let user = match self.get_current_vendor().await {
+            Ok(user) => user,
+            Err(err) => return Err(err),
+        };
+
+        if params.service_radius.is_some()
+            && self.user_rep.find_location(&user.id).await.and_then(|l| l.point()).is_none()
+        {
+            return Err(BaseError::new("Set your location before declaring a service radius".to_string()));
+        }
+
+        let mut cities: Vec<String> = vec![];
+        for city in params.service_cities.iter().map(|c| c.trim()).filter(|c| !c.is_empty()) {
+            if !cities.iter().any(|c| c.eq_ignore_ascii_case(city)) {
+                cities.push(city.to_string());
+            }
+        }
+        params.service_cities = cities;
+
+        match self.capability_rep.upsert(&user.id, &params).await {
+            Ok(_) => (),
+            Err(e) => return Err(BaseError::new(e)),
+        };
+
+        match self.capability_rep.find_by_user(&user.id).await {
+            Some(capabilities) => Ok(capabilities),
+            None => Err(BaseError::new("Not Found".to_string())),
+        }
+    }
+
+    pub async fn recommend_vendors(&self, project_id: &str, params: RecommendationParams) -> Result<Vec<VendorRecommendation>, BaseError> {
+        match validate(&params) {
+            Ok(_) => (),
+            Err(e) => return Err(e),
+        };
+
+        let user = match self.user_service.get_current_user().await {
+            Ok(user) => user,
+            Err(err) => return Err(err),
+        };
+
+        let project = match self.project_rep.find_by_id(project_id).await {
+            Some(project) => project,
+            None => return Err(BaseError::new("Not Found".to_string())),
+        };
+
+        if user.u_type != UserType::Admin && !can_manage_project(self.organization_rep, &user, &project).await {
+            return Err(BaseError::new("Forbidden".to_string()));
+        }
+
+        let origin = project
+            .latitude
+            .zip(project.longitude)
+            .map(|(latitude, longitude)| GeoPoint { latitude, longitude });
+
+        let mut recommendations: Vec<VendorRecommendation> = self
+            .capability_rep
+            .find_candidates(origin.as_ref())
+            .await
+            .into_iter()
+            .filter_map(|c| {
+                score(&c.capabilities, &project, c.distance).map(|(score, reasons)| VendorRecommendation {
+                    vendor: c.vendor,
+                    score,
+                    reasons,
+                    distance: c.distance,
+                })
+            })
+            .collect();
+
+        recommendations.sort_by(|a, b| by_score_and_distance((a.score, a.distance), (b.score, b.distance)));
+        recommendations.truncate(params.limit.unwrap_or(DEFAULT_LIMIT) as usize);
+
+        Ok(recommendations)
+    }
+
+    pub async fn recommend_projects(&self, params: RecommendationParams) -> Result<Vec<ProjectRecommendation>, BaseError> {
+        match validate(&params) {
+            Ok(_) => (),
+            Err(e) => return Err(e),
+        };
+
+        let user = match self.get_current_vendor().await {
+            Ok(user) => user,
+            Err(err) => return Err(err),
+        };
+
+        let scope = ProjectScope::for_user(&user);
+        if !matches!(scope, ProjectScope::ApprovedVendor(_)) {
+            return Ok(vec![]);
+        }
+
+        let capabilities = match self.capability_rep.find_by_user(&user.id).await {
+            Some(capabilities) => capabilities,
+            None => return Err(BaseError::new("Set your capabilities to get recommendations".to_string())),
+        };
+
+        let origin = self.user_rep.find_location(&user.id).await.and_then(|l| l.point());
+
+        let mut recommendations: Vec<ProjectRecommendation> = self
+            .project_rep
+            .find_open_for_vendor(&scope, origin.as_ref())
+            .await
+            .into_iter()
+            .filter_map(|mut project| {
+                score(&capabilities, &project, project.distance).map(|(score, reasons)| {
+                    project.redact_contacts();
+                    ProjectRecommendation { project, score, reasons }
+                })
+            })
+            .collect();
+
+        recommendations.sort_by(|a, b| {
+            by_score_and_distance((a.score, a.project.distance), (b.score, b.project.distance))
+        });
+        recommendations.truncate(params.limit.unwrap_or(DEFAULT_LIMIT) as usize);
+
+        Ok(recommendations)
+    }
+
+    async fn get_current_vendor(&self) -> Result<User, BaseError> {
+        let user = match self.user_service.get_current_user().await {
+            Ok(user) => user,
+            Err(err) => return Err(err),
+        };
+
+        if user.u_type != UserType::Vendor {
+            return Err(BaseError::new("Forbidden".to_string()));
+        }
+
+        Ok(user)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use crate::app::entities::project::{appropriate_status::AppropriateStatus, status::ProjectStatus};
+
+    use super::*;
+
+    fn project() -> Project {
+        Project {
+            id: "project".to_string(),
+            name: "Test project".to_string(),
+            city: "Berlin".to_string(),
+            status: ProjectStatus::Active,
+            street: None,
+            zip_code: None,
+            floor: None,
+            description: None,
+            building_type: "Office".to_string(),
+            is_save_carbon: false,
+            appropriate_status: AppropriateStatus::ReadyToHire,
+            has_financing_secured: false,
+            budget_range: BudgetRange::Under100k,
+            square_range: SquareRange::Under2th,
+            commercial_work: CommercialWork::Renovation,
+            architectural_services: vec![],
+            completion_date: Utc::now(),
+            created_at: Utc::now(),
+            creator: User {
+                id: "client".to_string(),
+                email: String::new(),
+                first_name: "Test".to_string(),
+                last_name: "Client".to_string(),
+                company: None,
+                phone: None,
+                u_type: UserType::Client,
+                password_alg: String::new(),
+                password_hash: String::new(),
+                is_compliant: false,
+                created_at: Utc::now(),
+                tokens: vec![],
+            },
+            organization_id: None,
+            vendor_id: None,
+            latitude: None,
+            longitude: None,
+            distance: None,
+            archived_at: None,
+            deleted_at: None,
+            progress: None,
+            attachments: vec![],
+        }
+    }
+
+    fn capabilities(commercial_works: Vec<CommercialWork>) -> VendorCapabilities {
+        VendorCapabilities {
+            user_id: "vendor".to_string(),
+            commercial_works,
+            architectural_services: vec![],
+            budget_ranges: vec![],
+            square_ranges: vec![],
+            is_save_carbon: false,
+            service_cities: vec![],
+            service_radius: None,
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn covers_work_expands_all_of_the_above() {
+        assert!(covers_work(&[CommercialWork::AllOfTheAbove], &CommercialWork::Renovation));
+        assert!(!covers_work(&[CommercialWork::Renovation], &CommercialWork::AllOfTheAbove));
+        assert!(covers_work(
+            &[
+                CommercialWork::Renovation,
+                CommercialWork::SelectingFurnitureAndEquipment,
+                CommercialWork::EvaluatingForSustainability,
+            ],
+            &CommercialWork::AllOfTheAbove,
+        ));
+    }
+
+    #[test]
+    fn score_skips_uncovered_work_and_other_areas() {
+        let project = project();
+        assert!(score(&capabilities(vec![CommercialWork::EvaluatingForSustainability]), &project, None).is_none());
+
+        let mut far = capabilities(vec![CommercialWork::Renovation]);
+        far.service_cities = vec!["Hamburg".to_string()];
+        far.service_radius = Some(50);
+        assert!(score(&far, &project, Some(250.0)).is_none());
+        assert!(score(&far, &project, Some(20.0)).is_some());
+    }
+
+    #[test]
+    fn score_is_full_when_every_criterion_matches() {
+        let mut project = project();
+        project.is_save_carbon = true;
+        project.architectural_services = vec![ArchitecturalServices::Space, ArchitecturalServices::Drawings];
+
+        let mut vendor = capabilities(vec![CommercialWork::Renovation]);
+        vendor.architectural_services = vec![ArchitecturalServices::Space, ArchitecturalServices::Drawings];
+        vendor.budget_ranges = vec![BudgetRange::Under100k];
+        vendor.square_ranges = vec![SquareRange::Under2th];
+        vendor.is_save_carbon = true;
+        vendor.service_cities = vec!["berlin".to_string()];
+
+        let (score, reasons) = score(&vendor, &project, None).unwrap();
+        assert_eq!(score, 100);
+        assert_eq!(
+            reasons,
+            vec!["commercial work", "service area", "architectural services", "budget range", "square range", "carbon saving"],
+        );
+    }
+
+    #[test]
+    fn score_weights_partial_matches() {
+        let mut project = project();
+        project.architectural_services = vec![ArchitecturalServices::Space, ArchitecturalServices::Drawings];
+
+        let mut vendor = capabilities(vec![CommercialWork::Renovation]);
+        vendor.architectural_services = vec![ArchitecturalServices::Space];
+        vendor.budget_ranges = vec![BudgetRange::Over5m];
+
+        // 30 + 10 + 12 of 25 + 0 of 15 + 10 = 62 of 90
+        assert_eq!(score(&vendor, &project, None).map(|(score, _)| score), Some(68));
+    }
+
+    #[test]
+    fn by_score_and_distance_prefers_score_then_nearest() {
+        let mut results = vec![(70, None), (90, Some(40.0)), (90, Some(5.0)), (70, Some(1.0))];
+        results.sort_by(|a, b| by_score_and_distance(*a, *b));
+        assert_eq!(results, vec![(90, Some(5.0)), (90, Some(40.0)), (70, Some(1.0)), (70, None)]);
+    }
+}
@@ -0,0 +1,422 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use validator::{Validate, ValidationError};
+
+use crate::app::{
+    email::project::ProjectEvents,
+    entities::{
+        milestone::{owner::MilestoneOwner, status::MilestoneStatus, Milestone},
+        preferences::notification_category::NotificationCategory,
+        project::Project,
+        user::{user_type::UserType, User},
+    },
+    errors::BaseError,
+    traits::repositories::{
+        milestone::TMilestoneRepositories, notification::TNotificationRepositories,
+        organization::TOrganizationRepositories, preferences::TPreferenceRepositories,
+        project::TProjectRepositories, user::TUserRepositories,
+    },
+    utils::validate_params::validate,
+};
+
+use super::{
+    preferences::{in_app_ids, recipients_for},
+    project::can_manage_project,
+    user::UserService,
+};
+
+#[derive(Debug, Validate, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateMilestoneParams {
+    #[validate(length(min = 2, max = 255, message = "The title length should be 2 to 255 symbols"))]
+    pub title: String,
+    pub due_date: DateTime<Utc>,
+    #[validate(custom(function = "validate_owner", message = "Milestone owner is invalid"))]
+    pub owner: String,
+    #[validate(custom(function = "validate_task_titles", message = "The task title length should be 1 to 255 symbols"))]
+    pub tasks: Option<Vec<String>>,
+}
+
+#[derive(Debug, Validate, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateMilestoneParams {
+    #[validate(length(min = 2, max = 255, message = "The title length should be 2 to 255 symbols"))]
+    pub title: Option<String>,
+    pub due_date: Option<DateTime<Utc>>,
+    #[validate(custom(function = "validate_owner", message = "Milestone owner is invalid"))]
+    pub owner: Option<String>,
+    #[validate(custom(function = "validate_open_status", message = "Milestone status should be Pending or InProgress"))]
+    pub status: Option<String>,
+}
+
+#[derive(Debug, Validate, Deserialize)]
+pub struct CreateTaskParams {
+    #[validate(length(min = 1, max = 255, message = "The task title length should be 1 to 255 symbols"))]
+    pub title: String,
+}
+
+#[derive(Debug, Validate, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateTaskParams {
+    #[validate(length(min = 1, max = 255, message = "The task title length should be 1 to 255 symbols"))]
+    pub title: Option<String>,
+    pub is_done: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ReorderParams<T> {
+    pub ids: Vec<T>,
+}
+
+fn validate_owner(value: &str) -> Result<(), ValidationError> {
+    match MilestoneOwner::from_str(value) {
+        Ok(_) => Ok(()),
+        Err(_) => Err(ValidationError::new("")),
+    }
+}
+
+fn validate_open_status(value: &str) -> Result<(), ValidationError> {
+    match MilestoneStatus::from_str(value) {
+        Ok(MilestoneStatus::Done) | Err(_) => Err(ValidationError::new("")),
+        Ok(_) => Ok(()),
+    }
+}
+
+fn validate_task_titles(value: &[String]) -> Result<(), ValidationError> {
+    if value.iter().all(|title| !title.trim().is_empty() && title.len() <= 255) {
+        Ok(())
+    } else {
+        Err(ValidationError::new(""))
+    }
+}
+
+pub struct MilestoneService<'a> {
+    user_rep: &'a (dyn TUserRepositories + Send + Sync),
+    notification_rep: &'a (dyn TNotificationRepositories + Send + Sync),
+    project_rep: &'a (dyn TProjectRepositories + Send + Sync),
+    organization_rep: &'a (dyn TOrganizationRepositories + Send + Sync),
+    preference_rep: &'a (dyn TPreferenceRepositories + Send + Sync),
+    milestone_rep: &'a (dyn TMilestoneRepositories + Send + Sync),
+    user_service: UserService<'a>,
+}
+
+impl<'a> MilestoneService<'a> {
+    pub fn new(
+        user_rep: &'a (dyn TUserRepositories + Send + Sync),
+        notification_rep: &'a (dyn TNotificationRepositories + Send + Sync),
+        project_rep: &'a (dyn TProjectRepositories + Send + Sync),
+        organization_rep: &'a (dyn TOrganizationRepositories + Send + Sync),
+        preference_rep: &'a (dyn TPreferenceRepositories + Send + Sync),
+        milestone_rep: &'a (dyn TMilestoneRepositories + Send + Sync),
+        token: &'a str,
+    ) -> Self {
+        Self {
+            user_rep,
+            notification_rep,
+            project_rep,
+            organization_rep,
+            preference_rep,
+            milestone_rep,
+            user_service: UserService::new(user_rep, token),
+        }
+    }
+
+    pub async fn create(&self, project_id: &str, params: CreateMilestoneParams) -> Result<String, BaseError> {
+        match validate(&params) {
+            Ok(_) => (),
+            Err(e) => return Err(e),
+        };
+
+        let (_, project) = match self.get_editable(project_id).await {
+            Ok(res) => res,
+            Err(err) => return Err(err),
+        };
+
+        if params.owner == MilestoneOwner::Vendor.as_str() && project.vendor_id.is_none() {
+            return Err(BaseError::new("The project has no awarded vendor".to_string()));
+        }
+
+        match self.milestone_rep.insert(project_id, &params).await {
+            Ok(id) => Ok(id),
+            Err(e) => Err(BaseError::new(e)),
+        }
+    }
+
+    pub async fn get_by_project(&self, project_id: &str) -> Result<Vec<Milestone>, BaseError> {
+        match self.get_participant(project_id).await {
+            Ok(_) => Ok(self.milestone_rep.find_by_project(project_id).await),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub async fn update(&self, id: &str, params: UpdateMilestoneParams) -> Result<(), BaseError> {
+        match validate(&params) {
+            Ok(_) => (),
+            Err(e) => return Err(e),
+        };
+
+        let (milestone, user, project) = match self.get_owned_milestone(id).await {
+            Ok(res) => res,
+            Err(err) => return Err(err),
+        };
+
+        if params.owner.as_deref().is_some_and(|owner| owner != milestone.owner.as_str())
+            && !can_manage_project(self.organization_rep, &user, &project).await
+        {
+            return Err(BaseError::new("Only the project manager can change the milestone owner".to_string()));
+        }
+
+        if params.owner.as_deref() == Some(MilestoneOwner::Vendor.as_str()) && project.vendor_id.is_none() {
+            return Err(BaseError::new("The project has no awarded vendor".to_string()));
+        }
+
+        match self.milestone_rep.update(&milestone.id, &params).await {
+            Ok(true) => Ok(()),
+            Ok(false) => Err(BaseError::new("Completed milestones can't be changed".to_string())),
+            Err(e) => Err(BaseError::new(e)),
+        }
+    }
+
+    pub async fn complete(&self, id: &str) -> Result<(), BaseError> {
+        let (milestone, user, project) = match self.get_milestone(id).await {
+            Ok(res) => res,
+            Err(err) => return Err(err),
+        };
+
+        if !self.is_owner(&milestone, &user, &project).await {
+            return Err(BaseError::new("Forbidden".to_string()));
+        }
+
+        match self.milestone_rep.complete(&milestone.id).await {
+            Ok(true) => (),
+            Ok(false) => return Err(BaseError::new("The milestone is already completed".to_string())),
+            Err(e) => return Err(BaseError::new(e)),
+        };
+
+        let counterpart = match (&milestone.owner, &project.vendor_id) {
+            (MilestoneOwner::Client, Some(vendor_id)) => self.user_rep.find_by_id(vendor_id, false).await,
+            (MilestoneOwner::Client, None) => None,
+            (MilestoneOwner::Vendor, _) => Some(project.creator.clone()),
+        };
+
+        if let Some(counterpart) = counterpart {
+            let recipients = recipients_for(self.preference_rep, &[counterpart]).await;
+            let ids = in_app_ids(&recipients, &NotificationCategory::Projects);
+
+            if !ids.is_empty() {
+                let _ = self.notification_rep.insert(
+                    format!("completed the milestone {} on the project {}", milestone.title, project.name).as_str(),
+                    format!("/projects/{}/milestones", project.id).as_str(),
+                    false,
+                    false,
+                    &user.id,
+                    ids,
+                ).await;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub async fn reorder(&self, project_id: &str, params: ReorderParams<String>) -> Result<(), BaseError> {
+        match self.get_editable(project_id).await {
+            Ok(_) => (),
+            Err(err) => return Err(err),
+        };
+
+        match self.milestone_rep.reorder(project_id, &params.ids).await {
+            Ok(_) => Ok(()),
+            Err(e) => Err(BaseError::new(e)),
+        }
+    }
+
+    pub async fn add_task(&self, id: &str, params: CreateTaskParams) -> Result<i32, BaseError> {
+        match validate(&params) {
+            Ok(_) => (),
+            Err(e) => return Err(e),
+        };
+
+        let (milestone, _, _) = match self.get_owned_milestone(id).await {
+            Ok(res) => res,
+            Err(err) => return Err(err),
+        };
+
+        if milestone.status == MilestoneStatus::Done {
+            return Err(BaseError::new("Completed milestones can't be changed".to_string()));
+        }
+
+        match self.milestone_rep.insert_task(&milestone.id, &params.title).await {
+            Ok(id) => Ok(id),
+            Err(e) => Err(BaseError::new(e)),
+        }
+    }
+
+    pub async fn update_task(&self, id: &str, task_id: i32, params: UpdateTaskParams) -> Result<(), BaseError> {
+        match validate(&params) {
+            Ok(_) => (),
+            Err(e) => return Err(e),
+        };
+
+        let (milestone, _, _) = match self.get_owned_milestone(id).await {
+            Ok(res) => res,
+            Err(err) => return Err(err),
+        };
+
+        match self.milestone_rep.update_task(&milestone.id, task_id, &params).await {
+            Ok(true) => Ok(()),
+            Ok(false) => Err(BaseError::new("Task not found".to_string())),
+            Err(e) => Err(BaseError::new(e)),
+        }
+    }
+
+    pub async fn reorder_tasks(&self, id: &str, params: ReorderParams<i32>) -> Result<(), BaseError> {
+        let (milestone, _, _) = match self.get_owned_milestone(id).await {
+            Ok(res) => res,
+            Err(err) => return Err(err),
+        };
+
+        match self.milestone_rep.reorder_tasks(&milestone.id, &params.ids).await {
+            Ok(_) => Ok(()),
+            Err(e) => Err(BaseError::new(e)),
+        }
+    }
+
+    async fn is_owner(&self, milestone: &Milestone, user: &User, project: &Project) -> bool {
+        match milestone.owner {
+            MilestoneOwner::Client => can_manage_project(self.organization_rep, user, project).await,
+            MilestoneOwner::Vendor => {
+                user.u_type == UserType::Admin || project.vendor_id.as_deref() == Some(user.id.as_str())
+            }
+        }
+    }
+
+    async fn get_participant(&self, project_id: &str) -> Result<(User, Project), BaseError> {
+        let user = match self.user_service.get_current_user().await {
+            Ok(user) => user,
+            Err(err) => return Err(err),
+        };
+
+        let project = match self.project_rep.find_by_id(project_id).await {
+            Some(project) => project,
+            None => return Err(BaseError::new("Not Found".to_string())),
+        };
+
+        if project.vendor_id.as_deref() == Some(user.id.as_str())
+            || can_manage_project(self.organization_rep, &user, &project).await
+        {
+            Ok((user, project))
+        } else {
+            Err(BaseError::new("Forbidden".to_string()))
+        }
+    }
+
+    async fn get_editable(&self, project_id: &str) -> Result<(User, Project), BaseError> {
+        let (user, project) = match self.get_participant(project_id).await {
+            Ok(res) => res,
+            Err(err) => return Err(err),
+        };
+
+        if project.status.is_terminal() {
+            return Err(BaseError::new(format!("{} projects can't be changed", project.status.as_str())));
+        }
+
+        Ok((user, project))
+    }
+
+    async fn get_owned_milestone(&self, id: &str) -> Result<(Milestone, User, Project), BaseError> {
+        let (milestone, user, project) = match self.get_milestone(id).await {
+            Ok(res) => res,
+            Err(err) => return Err(err),
+        };
+
+        if !can_manage_project(self.organization_rep, &user, &project).await
+            && !self.is_owner(&milestone, &user, &project).await
+        {
+            return Err(BaseError::new("Forbidden".to_string()));
+        }
+
+        Ok((milestone, user, project))
+    }
+
+    async fn get_milestone(&self, id: &str) -> Result<(Milestone, User, Project), BaseError> {
+        let milestone = match self.milestone_rep.find_by_id(id).await {
+            Some(milestone) => milestone,
+            None => return Err(BaseError::new("Not Found".to_string())),
+        };
+
+        match self.get_editable(&milestone.project_id).await {
+            Ok((user, project)) => Ok((milestone, user, project)),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+pub struct MilestoneMonitor<'a> {
+    user_rep: &'a (dyn TUserRepositories + Send + Sync),
+    notification_rep: &'a (dyn TNotificationRepositories + Send + Sync),
+    project_rep: &'a (dyn TProjectRepositories + Send + Sync),
+    preference_rep: &'a (dyn TPreferenceRepositories + Send + Sync),
+    milestone_rep: &'a (dyn TMilestoneRepositories + Send + Sync),
+}
+
+impl<'a> MilestoneMonitor<'a> {
+    pub fn new(
+        user_rep: &'a (dyn TUserRepositories + Send + Sync),
+        notification_rep: &'a (dyn TNotificationRepositories + Send + Sync),
+        project_rep: &'a (dyn TProjectRepositories + Send + Sync),
+        preference_rep: &'a (dyn TPreferenceRepositories + Send + Sync),
+        milestone_rep: &'a (dyn TMilestoneRepositories + Send + Sync),
+    ) -> Self {
+        Self {
+            user_rep,
+            notification_rep,
+            project_rep,
+            preference_rep,
+            milestone_rep,
+        }
+    }
+
+    pub async fn notify_slipped(&self) -> Result<(), BaseError> {
+        for milestone in self.milestone_rep.find_slipped().await {
+            match self.milestone_rep.mark_slip_notified(&milestone.id).await {
+                Ok(true) => (),
+                Ok(false) => continue,
+                Err(e) => return Err(BaseError::new(e)),
+            };
+
+            let project = match self.project_rep.find_by_id(&milestone.project_id).await {
+                Some(project) => project,
+                None => continue,
+            };
+
+            let mut users = vec![project.creator.clone()];
+            if let Some(vendor_id) = &project.vendor_id {
+                if let Some(vendor) = self.user_rep.find_by_id(vendor_id, false).await {
+                    users.push(vendor);
+                }
+            }
+
+            let recipients = recipients_for(self.preference_rep, &users).await;
+            let ids = in_app_ids(&recipients, &NotificationCategory::Projects);
+
+            if !ids.is_empty() {
+                let _ = self.notification_rep.insert(
+                    format!("milestone {} on the project {} is past its due date", milestone.title, project.name).as_str(),
+                    format!("/projects/{}/milestones", project.id).as_str(),
+                    false,
+                    false,
+                    &project.creator.id,
+                    ids,
+                ).await;
+            }
+
+            if !recipients.is_empty() {
+                let _ = ProjectEvents::default()
+                    .on_milestone_slipped(&project, &milestone, recipients)
+                    .await;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -1,4 +1,16 @@
+pub mod admin;
+pub mod attachment;
 pub mod auth;
+pub mod bid;
+pub mod compliance;
+pub mod matching;
+pub mod gazetteer;
+pub mod milestone;
 pub mod notification;
+pub mod organization;
+pub mod preferences;
+pub mod profile;
+pub mod review;
+pub mod saved_search;
 pub mod user;
 pub mod project;
@@ -0,0 +1,228 @@
+use serde::Deserialize;
+use validator::{Validate, ValidationError};
+
+use crate::app::{
+    entities::{
+        organization::{member_role::MemberRole, Organization},
+        user::{user_type::UserType, User},
+    },
+    errors::BaseError,
+    traits::repositories::{organization::TOrganizationRepositories, user::TUserRepositories},
+    utils::validate_params::validate,
+};
+
+use super::user::UserService;
+
+#[derive(Debug, Validate, Deserialize)]
+pub struct CreateOrganizationParams {
+    #[validate(length(min = 2, max = 128, message = "The name length should be 2 to 128 symbols"))]
+    pub name: String,
+}
+
+#[derive(Debug, Validate, Deserialize)]
+pub struct AddMemberParams {
+    #[validate(email(message = "Email is invalid"))]
+    pub email: String,
+    #[validate(custom(function = "validate_member_role", message = "Member role is invalid"))]
+    pub role: String,
+}
+
+#[derive(Debug, Validate, Deserialize)]
+pub struct UpdateMemberParams {
+    #[validate(custom(function = "validate_member_role", message = "Member role is invalid"))]
+    pub role: String,
+}
+
+fn validate_member_role(value: &str) -> Result<(), ValidationError> {
+    match MemberRole::from_str(value) {
+        Ok(_) => Ok(()),
+        Err(_) => Err(ValidationError::new("")),
+    }
+}
+
+pub struct OrganizationService<'a> {
+    user_rep: &'a (dyn TUserRepositories + Send + Sync),
+    organization_rep: &'a (dyn TOrganizationRepositories + Send + Sync),
+    user_service: UserService<'a>,
+}
+
+impl<'a> OrganizationService<'a> {
+    pub fn new(
+        user_rep: &'a (dyn TUserRepositories + Send + Sync),
+        organization_rep: &'a (dyn TOrganizationRepositories + Send + Sync),
+        token: &'a str,
+    ) -> Self {
+        Self {
+            user_rep,
+            organization_rep,
+            user_service: UserService::new(user_rep, token),
+        }
+    }
+
+    pub async fn create(&self, params: CreateOrganizationParams) -> Result<String, BaseError> {
+        match validate(&params) {
+            Ok(_) => (),
+            Err(e) => return Err(e),
+        };
+
+        let user = match self.user_service.get_current_user().await {
+            Ok(user) => user,
+            Err(err) => return Err(err),
+        };
+
+        if user.u_type == UserType::Admin {
+            return Err(BaseError::new("Forbidden".to_string()));
+        }
+
+        match self.organization_rep.insert(&params.name, user.u_type, &user.id).await {
+            Ok(id) => Ok(id),
+            Err(e) => Err(BaseError::new(e)),
+        }
+    }
+
+    pub async fn get_for_current_user(&self) -> Result<Vec<Organization>, BaseError> {
+        let user = match self.user_service.get_current_user().await {
+            Ok(user) => user,
+            Err(err) => return Err(err),
+        };
+
+        Ok(self.organization_rep.find_by_user(&user.id).await)
+    }
+
+    pub async fn get_by_id(&self, id: &str) -> Result<Organization, BaseError> {
+        let user = match self.user_service.get_current_user().await {
+            Ok(user) => user,
+            Err(err) => return Err(err),
+        };
+
+        let organization = match self.organization_rep.find_by_id(id).await {
+            Some(organization) => organization,
+            None => return Err(BaseError::new("Organization not found".to_string())),
+        };
+
+        if user.u_type != UserType::Admin && organization.role_of(&user.id).is_none() {
+            return Err(BaseError::new("Forbidden".to_string()));
+        }
+
+        Ok(organization)
+    }
+
+    pub async fn add_member(&self, id: &str, params: AddMemberParams) -> Result<(), BaseError> {
+        match validate(&params) {
+            Ok(_) => (),
+            Err(e) => return Err(e),
+        };
+
+        let (_, organization, role) = match self.get_managed(id).await {
+            Ok(res) => res,
+            Err(err) => return Err(err),
+        };
+
+        let new_role = MemberRole::from_str(&params.role).unwrap();
+        if new_role == MemberRole::Owner && role != MemberRole::Owner {
+            return Err(BaseError::new("Only an owner can add another owner".to_string()));
+        }
+
+        let member = match self.user_rep.find_by_email(&params.email, false).await {
+            Some((member, _)) => member,
+            None => return Err(BaseError::new("User not found".to_string())),
+        };
+
+        if member.u_type != organization.o_type {
+            return Err(BaseError::new(format!("Only {} users can join this organization", organization.o_type)));
+        }
+
+        if organization.role_of(&member.id).is_some() {
+            return Err(BaseError::new("The user is already a member".to_string()));
+        }
+
+        match self.organization_rep.upsert_member(id, &member.id, new_role).await {
+            Ok(_) => Ok(()),
+            Err(e) => Err(BaseError::new(e)),
+        }
+    }
+
+    pub async fn update_member(&self, id: &str, user_id: &str, params: UpdateMemberParams) -> Result<(), BaseError> {
+        match validate(&params) {
+            Ok(_) => (),
+            Err(e) => return Err(e),
+        };
+
+        let (_, organization, role) = match self.get_managed(id).await {
+            Ok(res) => res,
+            Err(err) => return Err(err),
+        };
+
+        if role != MemberRole::Owner {
+            return Err(BaseError::new("Forbidden".to_string()));
+        }
+
+        if organization.role_of(user_id).is_none() {
+            return Err(BaseError::new("Member not found".to_string()));
+        }
+
+        let new_role = MemberRole::from_str(&params.role).unwrap();
+        if !organization.keeps_an_owner(user_id, Some(&new_role)) {
+            return Err(BaseError::new("The organization should have at least one owner".to_string()));
+        }
+
+        match self.organization_rep.upsert_member(id, user_id, new_role).await {
+            Ok(_) => Ok(()),
+            Err(e) => Err(BaseError::new(e)),
+        }
+    }
+
+    pub async fn remove_member(&self, id: &str, user_id: &str) -> Result<(), BaseError> {
+        let user = match self.user_service.get_current_user().await {
+            Ok(user) => user,
+            Err(err) => return Err(err),
+        };
+
+        let organization = match self.organization_rep.find_by_id(id).await {
+            Some(organization) => organization,
+            None => return Err(BaseError::new("Organization not found".to_string())),
+        };
+
+        let member_role = match organization.role_of(user_id) {
+            Some(role) => role,
+            None => return Err(BaseError::new("Member not found".to_string())),
+        };
+
+        let can_remove = user.id == user_id
+            || match organization.role_of(&user.id) {
+                Some(MemberRole::Owner) => true,
+                Some(MemberRole::Manager) => member_role == MemberRole::Member,
+                _ => false,
+            };
+
+        if !can_remove {
+            return Err(BaseError::new("Forbidden".to_string()));
+        }
+
+        if !organization.keeps_an_owner(user_id, None) {
+            return Err(BaseError::new("The organization should have at least one owner".to_string()));
+        }
+
+        match self.organization_rep.remove_member(id, user_id).await {
+            Ok(_) => Ok(()),
+            Err(e) => Err(BaseError::new(e)),
+        }
+    }
+
+    async fn get_managed(&self, id: &str) -> Result<(User, Organization, MemberRole), BaseError> {
+        let user = match self.user_service.get_current_user().await {
+            Ok(user) => user,
+            Err(err) => return Err(err),
+        };
+
+        let organization = match self.organization_rep.find_by_id(id).await {
+            Some(organization) => organization,
+            None => return Err(BaseError::new("Organization not found".to_string())),
+        };
+
+        match organization.role_of(&user.id) {
+            Some(role) if role.can_manage() => Ok((user, organization, role)),
+            _ => Err(BaseError::new("Forbidden".to_string())),
+        }
+    }
+}
@@ -0,0 +1,187 @@
+use std::collections::BTreeMap;
+
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+use validator::{Validate, ValidationError};
+
+use crate::app::{
+    email::Recipient,
+    entities::{
+        preferences::{
+            date_format::DateFormat, notification_category::NotificationCategory,
+            ChannelPreference, Preferences, LOCALES,
+        },
+        user::User,
+    },
+    errors::BaseError,
+    traits::repositories::{preferences::TPreferenceRepositories, user::TUserRepositories},
+    utils::validate_params::validate,
+};
+
+use super::user::UserService;
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChannelParams {
+    pub email: Option<bool>,
+    pub in_app: Option<bool>,
+}
+
+#[derive(Debug, Validate, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdatePreferencesParams {
+    #[validate(custom(function = "validate_locale", message = "Locale is invalid"))]
+    pub locale: Option<String>,
+    #[validate(custom(function = "validate_timezone", message = "Timezone is invalid"))]
+    pub timezone: Option<String>,
+    #[validate(custom(function = "validate_date_format", message = "Date format is invalid"))]
+    pub date_format: Option<String>,
+    #[validate(custom(function = "validate_channels", message = "Notification category is invalid"))]
+    pub channels: Option<BTreeMap<String, ChannelParams>>,
+}
+
+fn validate_locale(value: &str) -> Result<(), ValidationError> {
+    if LOCALES.contains(&value) {
+        return Ok(());
+    }
+    Err(ValidationError::new(""))
+}
+
+fn validate_timezone(value: &str) -> Result<(), ValidationError> {
+    match value.parse::<Tz>() {
+        Ok(_) => Ok(()),
+        Err(_) => Err(ValidationError::new("")),
+    }
+}
+
+fn validate_date_format(value: &str) -> Result<(), ValidationError> {
+    match DateFormat::from_str(value) {
+        Ok(_) => Ok(()),
+        Err(_) => Err(ValidationError::new("")),
+    }
+}
+
+fn validate_channels(value: &BTreeMap<String, ChannelParams>) -> Result<(), ValidationError> {
+    for key in value.keys() {
+        if NotificationCategory::from_str(key).is_err() {
+            return Err(ValidationError::new(""));
+        }
+    }
+    Ok(())
+}
+
+pub async fn recipients_for(
+    preference_rep: &(dyn TPreferenceRepositories + Send + Sync),
+    users: &[User],
+) -> Vec<Recipient> {
+    let mut preferences = preference_rep
+        .find_by_users(users.iter().map(|u| u.id.as_str()).collect())
+        .await;
+
+    users
+        .iter()
+        .map(|user| Recipient {
+            user_id: user.id.clone(),
+            email: user.email.clone(),
+            first_name: user.first_name.clone(),
+            u_type: user.u_type.clone(),
+            preferences: match preferences.iter().position(|(id, _)| id == &user.id) {
+                Some(index) => preferences.swap_remove(index).1,
+                None => Preferences::default(),
+            },
+        })
+        .collect()
+}
+
+pub fn in_app_ids<'r>(recipients: &'r [Recipient], category: &NotificationCategory) -> Vec<&'r str> {
+    recipients
+        .iter()
+        .filter(|r| r.preferences.allows_in_app(category))
+        .map(|r| r.user_id.as_str())
+        .collect()
+}
+
+pub struct PreferenceService<'a> {
+    user_service: UserService<'a>,
+    preference_rep: &'a (dyn TPreferenceRepositories + Send + Sync),
+}
+
+impl<'a> PreferenceService<'a> {
+    pub fn new(
+        user_rep: &'a (dyn TUserRepositories + Send + Sync),
+        preference_rep: &'a (dyn TPreferenceRepositories + Send + Sync),
+        token: &'a str,
+    ) -> Self {
+        Self {
+            preference_rep,
+            user_service: UserService::new(user_rep, token),
+        }
+    }
+
+    pub async fn get_for_current_user(&self) -> Result<Preferences, BaseError> {
+        let user = match self.user_service.get_current_user().await {
+            Ok(user) => user,
+            Err(err) => return Err(err),
+        };
+
+        Ok(self
+            .preference_rep
+            .find_by_user(&user.id)
+            .await
+            .unwrap_or(Preferences::default()))
+    }
+
+    pub async fn update_for_current_user(&self, params: UpdatePreferencesParams) -> Result<Preferences, BaseError> {
+        match validate(&params) {
+            Ok(_) => (),
+            Err(e) => return Err(e),
+        };
+
+        let user = match self.user_service.get_current_user().await {
+            Ok(user) => user,
+            Err(err) => return Err(err),
+        };
+
+        let mut preferences = self
+            .preference_rep
+            .find_by_user(&user.id)
+            .await
+            .unwrap_or(Preferences::default());
+
+        if let Some(locale) = params.locale {
+            if params.date_format.is_none() {
+                preferences.date_format = DateFormat::for_locale(&locale);
+            }
+            preferences.locale = locale;
+        }
+
+        if let Some(timezone) = params.timezone {
+            preferences.timezone = timezone;
+        }
+
+        if let Some(date_format) = params.date_format {
+            preferences.date_format = DateFormat::from_str(&date_format).unwrap();
+        }
+
+        for (category, channel) in params.channels.unwrap_or_default() {
+            let current = preferences
+                .channels
+                .get(&category)
+                .cloned()
+                .unwrap_or(ChannelPreference::default());
+
+            preferences.channels.insert(
+                category,
+                ChannelPreference {
+                    email: channel.email.unwrap_or(current.email),
+                    in_app: channel.in_app.unwrap_or(current.in_app),
+                },
+            );
+        }
+
+        match self.preference_rep.upsert(&user.id, &preferences).await {
+            Ok(_) => Ok(preferences),
+            Err(e) => Err(BaseError::new(e)),
+        }
+    }
+}
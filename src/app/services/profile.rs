@@ -0,0 +1,119 @@
+use serde::Deserialize;
+use validator::Validate;
+
+use crate::app::{
+    entities::{
+        location::UserLocation,
+        project::status::ProjectStatus,
+        user::{user_profile::UserProfile, user_type::UserType, User},
+    },
+    errors::BaseError,
+    traits::repositories::{
+        project::TProjectRepositories, review::TReviewRepositories, user::TUserRepositories,
+    },
+    utils::validate_params::validate,
+};
+
+use super::user::UserService;
+
+#[derive(Debug, Validate, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateLocationParams {
+    #[validate(length(min = 2, max = 180, message = "The city length should be between 2 and 180 symbols"))]
+    city: Option<String>,
+    #[validate(length(min = 1, max = 20, message = "The zip code length should be between 1 and 20 symbols"))]
+    zip_code: Option<String>,
+}
+
+pub struct ProfileService<'a> {
+    user_rep: &'a (dyn TUserRepositories + Send + Sync),
+    project_rep: &'a (dyn TProjectRepositories + Send + Sync),
+    review_rep: &'a (dyn TReviewRepositories + Send + Sync),
+    user_service: UserService<'a>,
+}
+
+impl<'a> ProfileService<'a> {
+    pub fn new(
+        user_rep: &'a (dyn TUserRepositories + Send + Sync),
+        project_rep: &'a (dyn TProjectRepositories + Send + Sync),
+        review_rep: &'a (dyn TReviewRepositories + Send + Sync),
+        token: &'a str,
+    ) -> Self {
+        Self {
+            user_rep,
+            project_rep,
+            review_rep,
+            user_service: UserService::new(user_rep, token),
+        }
+    }
+
+    pub async fn get_by_id(&self, id: &str) -> Result<UserProfile, BaseError> {
+        let viewer = match self.user_service.get_current_user().await {
+            Ok(user) => user,
+            Err(err) => return Err(err),
+        };
+
+        let user = match self.user_rep.find_by_id(id, false).await {
+            Some(user) => user,
+            None => return Err(BaseError::new("User not found".to_string())),
+        };
+
+        let mut profile = UserProfile::from_user(&user);
+
+        match user.u_type {
+            UserType::Vendor => {
+                profile.company = user.company.clone();
+                profile.is_compliant = Some(user.is_compliant);
+                profile.rating = Some(self.review_rep.summary(&user.id).await);
+                profile.location = self.user_rep.find_location(&user.id).await;
+            }
+            UserType::Client => {
+                profile.completed_projects = Some(
+                    self.project_rep
+                        .count_by_creator(&user.id, ProjectStatus::Complete)
+                        .await,
+                );
+            }
+            UserType::Admin => (),
+        }
+
+        if self.can_view_private(&viewer, &user).await {
+            profile.email = Some(user.email.clone());
+            profile.phone = user.phone.clone();
+        }
+
+        Ok(profile)
+    }
+
+    pub async fn update_location(&self, params: UpdateLocationParams) -> Result<UserLocation, BaseError> {
+        match validate(&params) {
+            Ok(_) => (),
+            Err(e) => return Err(e),
+        };
+
+        let user = match self.user_service.get_current_user().await {
+            Ok(user) => user,
+            Err(err) => return Err(err),
+        };
+
+        if user.u_type != UserType::Vendor {
+            return Err(BaseError::new("Forbidden".to_string()));
+        }
+
+        match self.user_rep.update_location(&user.id, params.city.as_deref(), params.zip_code.as_deref()).await {
+            Ok(_) => (),
+            Err(e) => return Err(BaseError::new(e)),
+        };
+
+        match self.user_rep.find_location(&user.id).await {
+            Some(location) => Ok(location),
+            None => Err(BaseError::new("User not found".to_string())),
+        }
+    }
+
+    async fn can_view_private(&self, viewer: &User, user: &User) -> bool {
+        viewer.u_type == UserType::Admin
+            || viewer.id == user.id
+            || self.project_rep.is_counterparty(&viewer.id, &user.id).await
+    }
+}
@@ -1,14 +1,17 @@
 use chrono::{DateTime, Utc};
-use serde::Deserialize;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::json;
 use validator::{Validate, ValidationError};
 
 use crate::app::{
-  entities::{ project::{ appropriate_status::AppropriateStatus, budget_range::BudgetRange, square_range::SquareRange, commercial_work::CommercialWork, architectural_service::ArchitecturalServices, Project, status::ProjectStatus}, user::user_type::UserType},
+  entities::{ attachment::Attachment, location::GeoPoint, page::Page, preferences::notification_category::NotificationCategory, project::{ draft::ProjectDraft, scope::ProjectScope, transition::{Actor, Transition}, status_change::StatusChange, field_change::{FieldChange, FieldDiff}, template::ProjectTemplate, appropriate_status::AppropriateStatus, budget_range::BudgetRange, square_range::SquareRange, commercial_work::CommercialWork, architectural_service::ArchitecturalServices, Project, status::ProjectStatus}, user::{user_type::UserType, User}},
   errors::BaseError,
-  traits::repositories::{notification::TNotificationRepositories, user::TUserRepositories, project::TProjectRepositories}, utils::validate_params::validate, email::project::ProjectEvents,
+  export::project::{csv_header, csv_row, summary_pdf},
+  storage::LocalStorage,
+  traits::{storage::TStorage, repositories::{attachment::TAttachmentRepositories, notification::TNotificationRepositories, organization::TOrganizationRepositories, user::TUserRepositories, project::TProjectRepositories, preferences::TPreferenceRepositories}}, utils::validate_params::validate, email::project::ProjectEvents,
 };
 
-use super::user::UserService;
+use super::{attachment::with_urls, preferences::{in_app_ids, recipients_for}, user::UserService};
 
 #[derive(Debug, Validate, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -35,6 +38,183 @@ pub struct CreateParams {
     #[validate(custom(function = "validate_architectural_services", message = "Architectural Services is invalid"))]
     pub architectural_services: Vec<String>,
     pub completion_date: DateTime<Utc>,
+    pub organization_id: Option<String>,
+}
+
+#[derive(Debug, Validate, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateParams {
+    #[validate(length(min = 2, message = "The name length should be min 2 symbols"))]
+    pub name: Option<String>,
+    #[validate(length(min = 2, message = "The city length should be min 2 symbols"))]
+    pub city: Option<String>,
+    #[serde(default, deserialize_with = "nullable")]
+    pub street: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
+    pub zip_code: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
+    pub floor: Option<Option<String>>,
+    #[serde(default, deserialize_with = "nullable")]
+    pub description: Option<Option<String>>,
+    pub building_type: Option<String>,
+    pub is_save_carbon: Option<bool>,
+    #[validate(custom(function = "validate_appropriate_status", message = "Appropriate Status is invalid"))]
+    pub appropriate_status: Option<String>,
+    pub has_financing_secured: Option<bool>,
+    #[validate(custom(function = "validate_budget_range", message = "Budget range is invalid"))]
+    pub budget_range: Option<String>,
+    #[validate(custom(function = "validate_square_range", message = "Square Range is invalid"))]
+    pub square_range: Option<String>,
+    #[validate(custom(function = "validate_commercial_work", message = "Commercial Work is invalid"))]
+    pub commercial_work: Option<String>,
+    #[validate(custom(function = "validate_architectural_services", message = "Architectural Services is invalid"))]
+    pub architectural_services: Option<Vec<String>>,
+    pub completion_date: Option<DateTime<Utc>>,
+}
+
+// Tells an absent field (None) apart from an explicit null (Some(None)) so that optional fields can be cleared
+fn nullable<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+  D: Deserializer<'de>,
+  T: Deserialize<'de>,
+{
+  Option::<T>::deserialize(deserializer).map(Some)
+}
+
+// Groups the changed fields that vendors bidding on or watching a project should hear about
+fn material_changes(fields: &[String]) -> Vec<&'static str> {
+  let mut changes = vec![];
+
+  for field in fields {
+    let change = match field.as_str() {
+        "city" | "street" | "zipCode" => "location",
+        "budgetRange" => "budget range",
+        "squareRange" => "square range",
+        "commercialWork" => "commercial work",
+        "buildingType" => "building type",
+        "architecturalServices" => "architectural services",
+        "completionDate" => "completion date",
+        _ => continue,
+    };
+    if !changes.contains(&change) {
+      changes.push(change);
+    }
+  }
+
+  changes
+}
+
+#[derive(Debug, Validate, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateDraftParams {
+    #[serde(flatten)]
+    #[validate]
+    pub project: UpdateParams,
+    pub organization_id: Option<String>,
+}
+
+fn required<T>(value: Option<T>, field: &str) -> Result<T, String> {
+  match value {
+      Some(value) => Ok(value),
+      None => Err(format!("The {field} is required")),
+  }
+}
+
+impl TryFrom<ProjectDraft> for CreateParams {
+  type Error = String;
+
+  fn try_from(draft: ProjectDraft) -> Result<Self, Self::Error> {
+    Ok(CreateParams {
+      name: draft.name,
+      city: required(draft.city, "city")?,
+      street: draft.street,
+      zip_code: draft.zip_code,
+      floor: draft.floor,
+      description: draft.description,
+      building_type: required(draft.building_type, "building type")?,
+      is_save_carbon: required(draft.is_save_carbon, "save carbon flag")?,
+      appropriate_status: required(draft.appropriate_status, "appropriate status")?.as_str().to_string(),
+      has_financing_secured: required(draft.has_financing_secured, "financing secured flag")?,
+      budget_range: required(draft.budget_range, "budget range")?.as_str().to_string(),
+      square_range: required(draft.square_range, "square range")?.as_str().to_string(),
+      commercial_work: required(draft.commercial_work, "commercial work")?.as_str().to_string(),
+      architectural_services: required(draft.architectural_services, "architectural services")?
+        .iter()
+        .map(|s| s.as_str().to_string())
+        .collect(),
+      completion_date: required(draft.completion_date, "completion date")?,
+      organization_id: draft.organization_id,
+    })
+  }
+}
+
+#[derive(Debug, Validate, Deserialize)]
+pub struct TransitionParams {
+    #[validate(custom(function = "validate_status", message = "Project Status is invalid"))]
+    pub status: String,
+    #[validate(length(min = 2, max = 1000, message = "The reason length should be 2 to 1000 symbols"))]
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateOrganizationParams {
+    pub organization_id: Option<String>,
+}
+
+#[derive(Debug, Validate, Deserialize)]
+pub struct SaveTemplateParams {
+    #[validate(length(min = 2, max = 64, message = "The name length should be between 2 and 64 symbols"))]
+    pub name: Option<String>,
+}
+
+impl UpdateParams {
+  fn or_project(self, project: &Project) -> UpdateParams {
+    UpdateParams {
+      name: Some(self.name.unwrap_or_else(|| project.name.clone())),
+      city: Some(self.city.unwrap_or_else(|| project.city.clone())),
+      street: Some(self.street.unwrap_or_else(|| project.street.clone())),
+      zip_code: Some(self.zip_code.unwrap_or_else(|| project.zip_code.clone())),
+      floor: Some(self.floor.unwrap_or_else(|| project.floor.clone())),
+      description: Some(self.description.unwrap_or_else(|| project.description.clone())),
+      building_type: Some(self.building_type.unwrap_or_else(|| project.building_type.clone())),
+      is_save_carbon: Some(self.is_save_carbon.unwrap_or(project.is_save_carbon)),
+      appropriate_status: Some(self.appropriate_status.unwrap_or_else(|| project.appropriate_status.as_str().to_string())),
+      has_financing_secured: Some(self.has_financing_secured.unwrap_or(project.has_financing_secured)),
+      budget_range: Some(self.budget_range.unwrap_or_else(|| project.budget_range.as_str().to_string())),
+      square_range: Some(self.square_range.unwrap_or_else(|| project.square_range.as_str().to_string())),
+      commercial_work: Some(self.commercial_work.unwrap_or_else(|| project.commercial_work.as_str().to_string())),
+      architectural_services: Some(self.architectural_services.unwrap_or_else(|| {
+        project.architectural_services.iter().map(|s| s.as_str().to_string()).collect()
+      })),
+      completion_date: Some(self.completion_date.unwrap_or(project.completion_date)),
+    }
+  }
+}
+
+impl CreateParams {
+  fn from_template(template: ProjectTemplate, overrides: UpdateParams) -> Result<Self, String> {
+    Ok(CreateParams {
+      name: overrides.name.unwrap_or(template.name),
+      city: required(overrides.city, "city")?,
+      street: overrides.street.flatten(),
+      zip_code: overrides.zip_code.flatten(),
+      floor: overrides.floor.flatten(),
+      description: overrides.description.unwrap_or(template.description),
+      building_type: overrides.building_type.unwrap_or(template.building_type),
+      is_save_carbon: overrides.is_save_carbon.unwrap_or(template.is_save_carbon),
+      appropriate_status: overrides.appropriate_status.unwrap_or_else(|| template.appropriate_status.as_str().to_string()),
+      has_financing_secured: overrides.has_financing_secured.unwrap_or(template.has_financing_secured),
+      budget_range: overrides.budget_range.unwrap_or_else(|| template.budget_range.as_str().to_string()),
+      square_range: overrides.square_range.unwrap_or_else(|| template.square_range.as_str().to_string()),
+      commercial_work: overrides.commercial_work.unwrap_or_else(|| template.commercial_work.as_str().to_string()),
+      architectural_services: overrides.architectural_services.unwrap_or_else(|| {
+        template.architectural_services.iter().map(|s| s.as_str().to_string()).collect()
+      }),
+      completion_date: required(overrides.completion_date, "completion date")?,
+      organization_id: template.organization_id,
+    })
+  }
 }
 
 
@@ -77,14 +257,73 @@ fn validate_architectural_services(value: &Vec<String>) -> Result<(), Validation
 }
 
 
-#[derive(Debug, Validate, Deserialize)]
+#[derive(Debug, Validate, Deserialize, Serialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct GetProjectByQueryParams {
-  limit: Option<i16>,
-  skip: Option<i16>,
+  #[validate(range(min = 1, max = 100, message = "Limit should be between 1 and 100"))]
+  pub limit: Option<i64>,
+  #[validate(range(min = 0, message = "Skip should not be negative"))]
+  pub skip: Option<i64>,
   #[validate(custom(function = "validate_status", message = "Project Status is invalid"))]
-  status: Option<String>,
-  name: Option<String>
+  pub status: Option<String>,
+  pub name: Option<String>,
+  pub city: Option<String>,
+  #[validate(custom(function = "validate_budget_range", message = "Budget range is invalid"))]
+  pub budget_range: Option<String>,
+  #[validate(custom(function = "validate_square_range", message = "Square Range is invalid"))]
+  pub square_range: Option<String>,
+  #[validate(custom(function = "validate_commercial_work", message = "Commercial Work is invalid"))]
+  pub commercial_work: Option<String>,
+  pub is_save_carbon: Option<bool>,
+  pub completion_from: Option<DateTime<Utc>>,
+  pub completion_to: Option<DateTime<Utc>>,
+  #[validate(custom(function = "validate_sort", message = "Sort is invalid"))]
+  pub sort: Option<String>,
+  #[validate(custom(function = "validate_order", message = "Order is invalid"))]
+  pub order: Option<String>,
+  pub archived: Option<bool>,
+  #[validate(range(min = -90.0, max = 90.0, message = "Latitude should be between -90 and 90"))]
+  pub latitude: Option<f64>,
+  #[validate(range(min = -180.0, max = 180.0, message = "Longitude should be between -180 and 180"))]
+  pub longitude: Option<f64>,
+  #[validate(range(min = 1.0, max = 1000.0, message = "Radius should be between 1 and 1000 km"))]
+  pub radius: Option<f64>,
+  #[validate(range(min = -90.0, max = 90.0, message = "Latitude should be between -90 and 90"))]
+  pub min_latitude: Option<f64>,
+  #[validate(range(min = -180.0, max = 180.0, message = "Longitude should be between -180 and 180"))]
+  pub min_longitude: Option<f64>,
+  #[validate(range(min = -90.0, max = 90.0, message = "Latitude should be between -90 and 90"))]
+  pub max_latitude: Option<f64>,
+  #[validate(range(min = -180.0, max = 180.0, message = "Longitude should be between -180 and 180"))]
+  pub max_longitude: Option<f64>,
+}
+
+const EXPORT_PAGE_SIZE: i64 = 100;
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct ExportParams {
+  pub format: String,
+}
+
+impl ExportParams {
+  fn expect(&self, format: &str) -> Result<(), BaseError> {
+    if self.format == format {
+      Ok(())
+    } else {
+      Err(BaseError::new(format!("Only {format} export is supported")))
+    }
+  }
+}
+
+impl GetProjectByQueryParams {
+  pub fn has_bounds(&self) -> Result<bool, String> {
+    match (self.min_latitude, self.min_longitude, self.max_latitude, self.max_longitude) {
+      (None, None, None, None) => Ok(false),
+      (Some(min_lat), Some(min_lng), Some(max_lat), Some(max_lng)) if min_lat <= max_lat && min_lng <= max_lng => Ok(true),
+      (Some(_), Some(_), Some(_), Some(_)) => Err("The bounding box is invalid".to_string()),
+      _ => Err("The bounding box requires min and max latitude and longitude".to_string()),
+    }
+  }
 }
 
 fn validate_status(value: &str) -> Result<(), ValidationError> {
@@ -94,12 +333,126 @@ fn validate_status(value: &str) -> Result<(), ValidationError> {
   }
 }
 
+fn validate_sort(value: &str) -> Result<(), ValidationError> {
+  match value {
+      "createdAt" | "completionDate" | "name" | "distance" => Ok(()),
+      _ => Err(ValidationError::new("")),
+  }
+}
+
+fn validate_order(value: &str) -> Result<(), ValidationError> {
+  match value {
+      "asc" | "desc" => Ok(()),
+      _ => Err(ValidationError::new("")),
+  }
+}
+
+pub async fn can_manage_project(
+  organization_rep: &(dyn TOrganizationRepositories + Send + Sync),
+  user: &User,
+  project: &Project,
+) -> bool {
+  can_manage_owned(organization_rep, user, &project.creator.id, project.organization_id.as_deref()).await
+}
+
+async fn can_manage_owned(
+  organization_rep: &(dyn TOrganizationRepositories + Send + Sync),
+  user: &User,
+  creator_id: &str,
+  organization_id: Option<&str>,
+) -> bool {
+  if user.u_type == UserType::Admin || creator_id == user.id {
+    return true;
+  }
+
+  match organization_id {
+    Some(organization_id) => match organization_rep.find_role(organization_id, &user.id).await {
+      Some(role) => role.can_manage(),
+      None => false,
+    },
+    None => false,
+  }
+}
+
+pub async fn notify_status_change(
+  user_rep: &(dyn TUserRepositories + Send + Sync),
+  notification_rep: &(dyn TNotificationRepositories + Send + Sync),
+  preference_rep: &(dyn TPreferenceRepositories + Send + Sync),
+  project: &Project,
+  to: &ProjectStatus,
+  reason: Option<&str>,
+  actor: Option<&User>,
+) {
+  let mut users = user_rep.find(vec![UserType::Admin]).await;
+  users.push(project.creator.clone());
+  if let Some(vendor_id) = &project.vendor_id {
+    if let Some(vendor) = user_rep.find_by_id(vendor_id, false).await {
+      users.push(vendor);
+    }
+  }
+  users.sort_by(|a, b| a.id.cmp(&b.id));
+  users.dedup_by(|a, b| a.id == b.id);
+  if let Some(actor) = actor {
+    users.retain(|u| u.id != actor.id);
+  }
+
+  let recipients = recipients_for(preference_rep, &users).await;
+  let receiver_ids = in_app_ids(&recipients, &NotificationCategory::Projects);
+  let sender_id = actor.map(|a| a.id.as_str()).unwrap_or(project.creator.id.as_str());
+
+  if !receiver_ids.is_empty() {
+    let _ = notification_rep.insert(
+      format!("moved the project {} from {} to {}", project.name, project.status.as_str(), to.as_str()).as_str(),
+      format!("/projects/{}", project.id).as_str(),
+      false,
+      false,
+      sender_id,
+      receiver_ids
+    ).await;
+  }
+
+  if !recipients.is_empty() {
+    let _ = ProjectEvents::default().on_status_changed(project, to, reason, recipients).await;
+  }
+}
+
+pub async fn notify_watchers(
+  project_rep: &(dyn TProjectRepositories + Send + Sync),
+  notification_rep: &(dyn TNotificationRepositories + Send + Sync),
+  preference_rep: &(dyn TPreferenceRepositories + Send + Sync),
+  project: &Project,
+  content: &str,
+  actor_id: Option<&str>,
+) {
+  let mut users = project_rep.find_watchers(&project.id).await;
+  users.retain(|u| Some(u.id.as_str()) != actor_id && Some(u.id.as_str()) != project.vendor_id.as_deref());
+  if users.is_empty() {
+    return;
+  }
+
+  let recipients = recipients_for(preference_rep, &users).await;
+  let receiver_ids = in_app_ids(&recipients, &NotificationCategory::Projects);
+
+  if !receiver_ids.is_empty() {
+    let _ = notification_rep.insert(
+      content,
+      format!("/projects/{}", project.id).as_str(),
+      false,
+      false,
+      actor_id.unwrap_or(project.creator.id.as_str()),
+      receiver_ids
+    ).await;
+  }
+}
 
 pub struct ProjectService<'a> {
   user_rep: &'a (dyn TUserRepositories + Send + Sync),
   user_service: UserService<'a>,
   notification_rep: &'a (dyn TNotificationRepositories + Send + Sync),
   project_rep:  &'a (dyn TProjectRepositories + Send + Sync),
+  preference_rep: &'a (dyn TPreferenceRepositories + Send + Sync),
+  organization_rep: &'a (dyn TOrganizationRepositories + Send + Sync),
+  attachment_rep: &'a (dyn TAttachmentRepositories + Send + Sync),
 }
 
 impl<'a> ProjectService<'a> {
@@ -107,12 +460,18 @@ impl<'a> ProjectService<'a> {
       user_rep: &'a (dyn TUserRepositories + Send + Sync),
       notification_rep: &'a (dyn TNotificationRepositories + Send + Sync),
       project_rep:  &'a (dyn TProjectRepositories + Send + Sync),
+      preference_rep: &'a (dyn TPreferenceRepositories + Send + Sync),
+      organization_rep: &'a (dyn TOrganizationRepositories + Send + Sync),
+      attachment_rep: &'a (dyn TAttachmentRepositories + Send + Sync),
       token: &'a str
   ) -> Self {
       Self {
           user_rep,
           project_rep,
           notification_rep,
+          preference_rep,
+          organization_rep,
+          attachment_rep,
           user_service: UserService::new(user_rep, token),
       }
   }
@@ -132,58 +491,962 @@ impl<'a> ProjectService<'a> {
         return  Err(BaseError::new("Forbidden".to_owned()))
       }
 
+      if let Some(organization_id) = &params.organization_id {
+        if !self.can_manage_organization(&user, organization_id).await {
+          return  Err(BaseError::new("Forbidden".to_owned()))
+        }
+      }
+
       let project_id = match self.project_rep.insert(&user.id, ProjectStatus::Active, &params).await {
           Ok(id) => id,
           Err(e) => return Err(BaseError::new(e.to_string())),
       };
 
-      let admins = self.user_rep.find(vec![UserType::Admin], None, None, None).await; 
+      self.notify_created(user, &project_id, &params).await;
+
+      Ok(project_id)
+  }
+
+  pub async fn create_draft(&self, params: CreateDraftParams) -> Result<String, BaseError> {
+      match validate(&params) {
+        Ok(_) => (),
+        Err(e) => return Err(e),
+      };
+
+      let user = match self.user_service.get_current_user().await {
+          Ok(user) => user,
+          Err(err) => return Err(err),
+      };
+
+      if user.u_type != UserType::Client {
+        return  Err(BaseError::new("Forbidden".to_owned()))
+      }
+
+      if params.project.name.is_none() {
+        return Err(BaseError::new("The name is required".to_string()));
+      }
+
+      if let Some(organization_id) = &params.organization_id {
+        if !self.can_manage_organization(&user, organization_id).await {
+          return  Err(BaseError::new("Forbidden".to_owned()))
+        }
+      }
+
+      match self.project_rep.insert_draft(&user.id, &params).await {
+          Ok(id) => Ok(id),
+          Err(e) => Err(BaseError::new(e)),
+      }
+  }
+
+  pub async fn get_drafts(&self) -> Result<Vec<ProjectDraft>, BaseError> {
+    let user = match self.user_service.get_current_user().await {
+        Ok(user) => user,
+        Err(err) => return Err(err),
+    };
+
+    Ok(self.project_rep.find_drafts(&user.id).await)
+  }
+
+  pub async fn get_draft(&self, id: &str) -> Result<ProjectDraft, BaseError> {
+    let user = match self.user_service.get_current_user().await {
+        Ok(user) => user,
+        Err(err) => return Err(err),
+    };
+
+    let draft = match self.project_rep.find_draft_by_id(id).await {
+        Some(draft) => draft,
+        None => return Err(BaseError::new("Not Found".to_string())),
+    };
+
+    if !can_manage_owned(self.organization_rep, &user, &draft.creator.id, draft.organization_id.as_deref()).await {
+      return Err(BaseError::new("Not Found".to_string()));
+    }
+
+    Ok(draft)
+  }
+
+  pub async fn update_draft(&self, id: &str, params: UpdateParams) -> Result<ProjectDraft, BaseError> {
+    match validate(&params) {
+      Ok(_) => (),
+      Err(e) => return Err(e),
+    };
+
+    match self.get_draft(id).await {
+        Ok(_) => (),
+        Err(err) => return Err(err),
+    };
+
+    match self.project_rep.update(id, &params, None).await {
+        Ok(_) => (),
+        Err(e) => return Err(BaseError::new(e)),
+    };
+
+    match self.project_rep.find_draft_by_id(id).await {
+        Some(draft) => Ok(draft),
+        None => Err(BaseError::new("Not Found".to_string())),
+    }
+  }
+
+  pub async fn submit(&self, id: &str) -> Result<(), BaseError> {
+    let draft = match self.get_draft(id).await {
+        Ok(draft) => draft,
+        Err(err) => return Err(err),
+    };
+
+    let params = match CreateParams::try_from(draft) {
+        Ok(params) => params,
+        Err(e) => return Err(BaseError::new(e)),
+    };
+
+    match validate(&params) {
+      Ok(_) => (),
+      Err(e) => return Err(e),
+    };
 
-      println!(">>>>>>>>>{:?}", admins);
-      if admins.len() > 0 {
+    let user = match self.user_service.get_current_user().await {
+        Ok(user) => user,
+        Err(err) => return Err(err),
+    };
+
+    match self.project_rep.transition(id, &ProjectStatus::Draft, &ProjectStatus::Active, Some(&user.id), None).await {
+        Ok(true) => (),
+        Ok(false) => return Err(BaseError::new("The draft has already been submitted".to_string())),
+        Err(e) => return Err(BaseError::new(e)),
+    };
+
+    self.notify_created(user, id, &params).await;
+
+    Ok(())
+  }
+
+  async fn notify_created(&self, user: User, project_id: &str, params: &CreateParams) {
+      let admins = self.user_rep.find(vec![UserType::Admin]).await; 
+      let recipients = recipients_for(self.preference_rep, &admins).await;
+      let admin_ids = in_app_ids(&recipients, &NotificationCategory::Projects);
+
+      if !admin_ids.is_empty() {
         let _ = self.notification_rep.insert(
           format!("create a project {}", params.name).as_str(), 
           format!("/projects/{project_id}").as_str(), 
           false, 
           false, 
           &user.id, 
-          admins.iter().map( |a| a.id.as_str()).collect()
+          admin_ids
         ).await;
+      }
 
+      if !recipients.is_empty() {
         let _ = ProjectEvents::default().on_create_project(
           user, 
           &params.name, 
-          &project_id,
-          admins.iter().map( |u| u.email.as_str()).collect()).await; 
+          project_id,
+          &params.completion_date,
+          recipients).await; 
       }
-
-      Ok(project_id)
   }
 
 
   pub async fn get_by_id(&self, id: &str) -> Result<Project, BaseError> {
-    match self.user_service.get_current_user().await {
+    let user = match self.user_service.get_current_user().await {
+        Ok(user) => user,
+        Err(err) => return Err(err),
+    };
+
+    let scope = ProjectScope::for_user(&user);
+    match self.project_rep.find_visible_by_id(id, &scope).await {
+        Some(mut project) => {
+          if !scope.sees_contacts(project.vendor_id.as_deref()) {
+            project.redact_contacts();
+          }
+          project.attachments = with_urls(self.attachment_rep.find_by_project(id).await);
+          Ok(project)
+        },
+        None => Err(BaseError::new("Not Found".to_string())),
+    }
+  }
+
+  pub async fn get(&self, params: &GetProjectByQueryParams) -> Result<Page<Project>, BaseError> {
+    match validate(params) {
+      Ok(_) => (),
+      Err(e) => return Err(e),
+    };
+
+    if let (Some(from), Some(to)) = (params.completion_from, params.completion_to) {
+      if from > to {
+        return Err(BaseError::new("The completion date range is invalid".to_string()));
+      }
+    }
+
+    let user = match self.user_service.get_current_user().await {
+        Ok(user) => user,
+        Err(err) => return Err(err),
+    };
+
+    if let Err(e) = params.has_bounds() {
+      return Err(BaseError::new(e));
+    }
+
+    let origin = match (params.latitude, params.longitude) {
+      (Some(latitude), Some(longitude)) => Some(GeoPoint { latitude, longitude }),
+      (None, None) if params.radius.is_some() || params.sort.as_deref() == Some("distance") => {
+        match self.user_rep.find_location(&user.id).await.and_then(|l| l.point()) {
+          Some(point) => Some(point),
+          None => return Err(BaseError::new("Set your location or pass latitude and longitude to search by distance".to_string())),
+        }
+      },
+      (None, None) => None,
+      _ => return Err(BaseError::new("Both latitude and longitude are required".to_string())),
+    };
+
+    let mut params = params.clone();
+    params.limit = Some(params.limit.unwrap_or(20));
+
+    let scope = ProjectScope::for_user(&user);
+    let mut page = self.project_rep.find_by_query(&params, &scope, origin.as_ref()).await;
+    for project in page.items.iter_mut() {
+      if !scope.sees_contacts(project.vendor_id.as_deref()) {
+        project.redact_contacts();
+      }
+    }
+
+    Ok(page)
+  }
+
+  pub async fn update(&self, id: &str, params: UpdateParams) -> Result<Project, BaseError> {
+    match validate(&params) {
+      Ok(_) => (),
+      Err(e) => return Err(e),
+    };
+
+    let user = match self.user_service.get_current_user().await {
         Ok(user) => user,
         Err(err) => return Err(err),
     };
-    
+
+    let project = match self.project_rep.find_by_id(id).await {
+        Some(project) => project,
+        None => return Err(BaseError::new("Not Found".to_string())),
+    };
+
+    if !can_manage_project(self.organization_rep, &user, &project).await {
+      return Err(BaseError::new("Forbidden".to_string()));
+    }
+
+    if project.status.is_terminal() {
+      return Err(BaseError::new(format!("{} projects can not be edited", project.status.as_str())));
+    }
+
+    let fields = match self.project_rep.update(id, &params, Some(&user.id)).await {
+        Ok(fields) => fields,
+        Err(e) => return Err(BaseError::new(e)),
+    };
+    let changes = material_changes(&fields);
+
+    if changes.is_empty() && !fields.is_empty() {
+      notify_watchers(
+        self.project_rep,
+        self.notification_rep,
+        self.preference_rep,
+        &project,
+        format!("updated the project {} you watch", project.name).as_str(),
+        Some(&user.id),
+      ).await;
+    }
+
+    if !changes.is_empty() {
+      let mut users = self.user_rep.find(vec![UserType::Admin]).await;
+      if let Some(vendor_id) = &project.vendor_id {
+        if let Some(vendor) = self.user_rep.find_by_id(vendor_id, false).await {
+          users.push(vendor);
+        }
+      }
+      users.extend(self.project_rep.find_interested_vendors(id).await);
+      users.sort_by(|a, b| a.id.cmp(&b.id));
+      users.dedup_by(|a, b| a.id == b.id);
+      users.retain(|u| u.id != user.id);
+
+      let recipients = recipients_for(self.preference_rep, &users).await;
+      let receiver_ids = in_app_ids(&recipients, &NotificationCategory::Projects);
+
+      if !receiver_ids.is_empty() {
+        let _ = self.notification_rep.insert(
+          format!("updated the project {}: {}", project.name, changes.join(", ")).as_str(),
+          format!("/projects/{id}").as_str(),
+          false,
+          false,
+          &user.id,
+          receiver_ids
+        ).await;
+      }
+
+      if !recipients.is_empty() {
+        let _ = ProjectEvents::default().on_update_project(
+          &user,
+          &project.name,
+          id,
+          &changes,
+          recipients).await;
+      }
+    }
+
     match self.project_rep.find_by_id(id).await {
         Some(project) => Ok(project),
         None => Err(BaseError::new("Not Found".to_string())),
     }
   }
 
-  pub async fn get(&self, params: &GetProjectByQueryParams) -> Result<Vec<Project>, BaseError> {
+  pub async fn transition(&self, id: &str, params: TransitionParams) -> Result<(), BaseError> {
+    match validate(&params) {
+      Ok(_) => (),
+      Err(e) => return Err(e),
+    };
+
+    let user = match self.user_service.get_current_user().await {
+        Ok(user) => user,
+        Err(err) => return Err(err),
+    };
+
+    let project = match self.project_rep.find_by_id(id).await {
+        Some(project) => project,
+        None => return Err(BaseError::new("Not Found".to_string())),
+    };
+
+    let to = ProjectStatus::from_str(&params.status).unwrap();
+    let transition = match Transition::find(&project.status, &to) {
+        Some(transition) => transition,
+        None => return Err(BaseError::new(format!("The project can not move from {} to {}", project.status.as_str(), to.as_str()))),
+    };
+
+    let mut actors = vec![];
+    if user.u_type == UserType::Admin {
+      actors.push(Actor::Admin);
+    }
+    if can_manage_project(self.organization_rep, &user, &project).await {
+      actors.push(Actor::Owner);
+    }
+    if project.vendor_id.as_deref() == Some(user.id.as_str()) {
+      actors.push(Actor::Vendor);
+    }
+
+    if !transition.allows(&actors) {
+      return Err(BaseError::new("Forbidden".to_string()));
+    }
+
+    if transition.requires_reason && params.reason.is_none() {
+      return Err(BaseError::new("The reason is required".to_string()));
+    }
+
+    if transition.requires_vendor && project.vendor_id.is_none() {
+      return Err(BaseError::new("The project has no awarded vendor".to_string()));
+    }
+
+    match self.project_rep.transition(id, &project.status, &to, Some(&user.id), params.reason.as_deref()).await {
+        Ok(true) => (),
+        Ok(false) => return Err(BaseError::new("The project status has changed, reload and try again".to_string())),
+        Err(e) => return Err(BaseError::new(e)),
+    };
+
+    notify_status_change(
+      self.user_rep,
+      self.notification_rep,
+      self.preference_rep,
+      &project,
+      &to,
+      params.reason.as_deref(),
+      Some(&user),
+    ).await;
+
+    notify_watchers(
+      self.project_rep,
+      self.notification_rep,
+      self.preference_rep,
+      &project,
+      format!("moved the project {} you watch from {} to {}", project.name, project.status.as_str(), to.as_str()).as_str(),
+      Some(&user.id),
+    ).await;
+
+    Ok(())
+  }
+
+  pub async fn watch(&self, id: &str) -> Result<(), BaseError> {
+    let user = match self.user_service.get_current_user().await {
+        Ok(user) => user,
+        Err(err) => return Err(err),
+    };
+
+    if user.u_type != UserType::Vendor {
+      return Err(BaseError::new("Forbidden".to_string()));
+    }
+
+    let scope = ProjectScope::for_user(&user);
+    if self.project_rep.find_visible_by_id(id, &scope).await.is_none() {
+      return Err(BaseError::new("Not Found".to_string()));
+    }
+
+    match self.project_rep.watch(id, &user.id).await {
+        Ok(_) => Ok(()),
+        Err(e) => Err(BaseError::new(e)),
+    }
+  }
+
+  pub async fn unwatch(&self, id: &str) -> Result<(), BaseError> {
+    let user = match self.user_service.get_current_user().await {
+        Ok(user) => user,
+        Err(err) => return Err(err),
+    };
+
+    match self.project_rep.unwatch(id, &user.id).await {
+        Ok(true) => Ok(()),
+        Ok(false) => Err(BaseError::new("Not Found".to_string())),
+        Err(e) => Err(BaseError::new(e)),
+    }
+  }
+
+  pub async fn get_watched(&self) -> Result<Vec<Project>, BaseError> {
+    let user = match self.user_service.get_current_user().await {
+        Ok(user) => user,
+        Err(err) => return Err(err),
+    };
+
+    if user.u_type != UserType::Vendor {
+      return Err(BaseError::new("Forbidden".to_string()));
+    }
+
+    let scope = ProjectScope::for_user(&user);
+    let mut projects = self.project_rep.find_watched(&user.id, &scope).await;
+    for project in projects.iter_mut() {
+      if !scope.sees_contacts(project.vendor_id.as_deref()) {
+        project.redact_contacts();
+      }
+    }
+
+    Ok(projects)
+  }
+
+  pub async fn delete(&self, id: &str) -> Result<(), BaseError> {
+    let user = match self.user_service.get_current_user().await {
+        Ok(user) => user,
+        Err(err) => return Err(err),
+    };
+
+    if let Some(draft) = self.project_rep.find_draft_by_id(id).await {
+      if !can_manage_owned(self.organization_rep, &user, &draft.creator.id, draft.organization_id.as_deref()).await {
+        return Err(BaseError::new("Not Found".to_string()));
+      }
+
+      return match self.project_rep.soft_delete(id, &user.id).await {
+          Ok(_) => Ok(()),
+          Err(e) => Err(BaseError::new(e)),
+      };
+    }
+
+    let project = match self.project_rep.find_by_id(id).await {
+        Some(project) => project,
+        None => return Err(BaseError::new("Not Found".to_string())),
+    };
+
+    if !can_manage_project(self.organization_rep, &user, &project).await {
+      return Err(BaseError::new("Forbidden".to_string()));
+    }
+
+    if matches!(project.status, ProjectStatus::Ongoing | ProjectStatus::Delivered | ProjectStatus::Overdue) {
+      return Err(BaseError::new(format!("{} projects can't be deleted", project.status.as_str())));
+    }
+
+    match self.project_rep.soft_delete(id, &user.id).await {
+        Ok(_) => Ok(()),
+        Err(e) => Err(BaseError::new(e)),
+    }
+  }
+
+  pub async fn archive(&self, id: &str, is_archived: bool) -> Result<(), BaseError> {
+    let user = match self.user_service.get_current_user().await {
+        Ok(user) => user,
+        Err(err) => return Err(err),
+    };
+
+    let project = match self.project_rep.find_by_id(id).await {
+        Some(project) => project,
+        None => return Err(BaseError::new("Not Found".to_string())),
+    };
+
+    if !can_manage_project(self.organization_rep, &user, &project).await {
+      return Err(BaseError::new("Forbidden".to_string()));
+    }
+
+    if !project.status.is_terminal() {
+      return Err(BaseError::new("Only completed or withdrawn projects can be archived".to_string()));
+    }
+
+    match self.project_rep.set_archived(id, is_archived, &user.id).await {
+        Ok(true) => Ok(()),
+        Ok(false) if is_archived => Err(BaseError::new("The project is already archived".to_string())),
+        Ok(false) => Err(BaseError::new("The project is not archived".to_string())),
+        Err(e) => Err(BaseError::new(e)),
+    }
+  }
+
+  pub async fn duplicate(&self, id: &str, overrides: UpdateParams) -> Result<String, BaseError> {
+    match validate(&overrides) {
+      Ok(_) => (),
+      Err(e) => return Err(e),
+    };
+
+    let user = match self.user_service.get_current_user().await {
+        Ok(user) => user,
+        Err(err) => return Err(err),
+    };
+
+    if user.u_type != UserType::Client {
+      return Err(BaseError::new("Forbidden".to_string()));
+    }
+
+    let project = match self.project_rep.find_by_id(id).await {
+        Some(project) => project,
+        None => return Err(BaseError::new("Not Found".to_string())),
+    };
+
+    if !can_manage_project(self.organization_rep, &user, &project).await {
+      return Err(BaseError::new("Forbidden".to_string()));
+    }
+
+    let organization_id = match &project.organization_id {
+      Some(organization_id) if self.can_manage_organization(&user, organization_id).await => Some(organization_id.clone()),
+      _ => None,
+    };
+
+    let params = CreateDraftParams {
+      project: overrides.or_project(&project),
+      organization_id,
+    };
+
+    match self.project_rep.insert_draft(&user.id, &params).await {
+        Ok(id) => Ok(id),
+        Err(e) => Err(BaseError::new(e)),
+    }
+  }
+
+  pub async fn save_template(&self, id: &str, params: SaveTemplateParams) -> Result<String, BaseError> {
     match validate(&params) {
       Ok(_) => (),
       Err(e) => return Err(e),
     };
 
-    match self.user_service.get_current_user().await {
+    let user = match self.user_service.get_current_user().await {
+        Ok(user) => user,
+        Err(err) => return Err(err),
+    };
+
+    if user.u_type != UserType::Client {
+      return Err(BaseError::new("Forbidden".to_string()));
+    }
+
+    let project = match self.project_rep.find_by_id(id).await {
+        Some(project) => project,
+        None => return Err(BaseError::new("Not Found".to_string())),
+    };
+
+    if !can_manage_project(self.organization_rep, &user, &project).await {
+      return Err(BaseError::new("Forbidden".to_string()));
+    }
+
+    let name = params.name.unwrap_or(project.name);
+    match self.project_rep.insert_template(id, &user.id, &name).await {
+        Ok(id) => Ok(id),
+        Err(e) => Err(BaseError::new(e)),
+    }
+  }
+
+  pub async fn get_templates(&self) -> Result<Vec<ProjectTemplate>, BaseError> {
+    let user = match self.user_service.get_current_user().await {
+        Ok(user) => user,
+        Err(err) => return Err(err),
+    };
+
+    if user.u_type != UserType::Client {
+      return Err(BaseError::new("Forbidden".to_string()));
+    }
+
+    Ok(self.project_rep.find_templates(&user.id).await)
+  }
+
+  pub async fn delete_template(&self, id: &str) -> Result<(), BaseError> {
+    let template = match self.get_template(id).await {
+        Ok(template) => template,
+        Err(err) => return Err(err),
+    };
+
+    match self.project_rep.delete_template(&template.id).await {
+        Ok(true) => Ok(()),
+        Ok(false) => Err(BaseError::new("Not Found".to_string())),
+        Err(e) => Err(BaseError::new(e)),
+    }
+  }
+
+  pub async fn create_from_template(&self, id: &str, overrides: UpdateParams) -> Result<String, BaseError> {
+    match validate(&overrides) {
+      Ok(_) => (),
+      Err(e) => return Err(e),
+    };
+
+    let template = match self.get_template(id).await {
+        Ok(template) => template,
+        Err(err) => return Err(err),
+    };
+
+    let params = match CreateParams::from_template(template, overrides) {
+        Ok(params) => params,
+        Err(e) => return Err(BaseError::new(e)),
+    };
+
+    self.create(params).await
+  }
+
+  async fn get_template(&self, id: &str) -> Result<ProjectTemplate, BaseError> {
+    let user = match self.user_service.get_current_user().await {
+        Ok(user) => user,
+        Err(err) => return Err(err),
+    };
+
+    let template = match self.project_rep.find_template_by_id(id).await {
+        Some(template) => template,
+        None => return Err(BaseError::new("Not Found".to_string())),
+    };
+
+    if !can_manage_owned(self.organization_rep, &user, &template.creator_id, template.organization_id.as_deref()).await {
+      return Err(BaseError::new("Forbidden".to_string()));
+    }
+
+    Ok(template)
+  }
+
+  pub async fn export_pdf(&self, id: &str, export: &ExportParams) -> Result<(Project, Vec<u8>), BaseError> {
+    match export.expect("pdf") {
+      Ok(_) => (),
+      Err(e) => return Err(e),
+    };
+
+    let project = match self.get_by_id(id).await {
+        Ok(project) => project,
+        Err(err) => return Err(err),
+    };
+
+    let history = self.project_rep.find_status_history(id).await;
+    let data = summary_pdf(&project, &history, &project.attachments);
+
+    Ok((project, data))
+  }
+
+  pub async fn export_csv(&self, params: &GetProjectByQueryParams, export: &ExportParams, skip: i64) -> Result<(String, Option<i64>), BaseError> {
+    match export.expect("csv") {
+      Ok(_) => (),
+      Err(e) => return Err(e),
+    };
+
+    let mut params = params.clone();
+    params.limit = Some(EXPORT_PAGE_SIZE);
+    params.skip = Some(skip);
+
+    let page = match self.get(&params).await {
+        Ok(page) => page,
+        Err(err) => return Err(err),
+    };
+
+    let mut data = if skip == 0 { csv_header() } else { String::new() };
+    for project in page.items.iter() {
+      data.push_str(&csv_row(project));
+    }
+
+    let next = skip + page.items.len() as i64;
+    if page.items.is_empty() || next >= page.total {
+      return Ok((data, None));
+    }
+
+    Ok((data, Some(next)))
+  }
+
+  pub async fn get_status_history(&self, id: &str) -> Result<Vec<StatusChange>, BaseError> {
+    match self.get_by_id(id).await {
+        Ok(_) => Ok(self.project_rep.find_status_history(id).await),
+        Err(err) => Err(err),
+    }
+  }
+
+  pub async fn update_organization(&self, id: &str, params: UpdateOrganizationParams) -> Result<(), BaseError> {
+    let user = match self.user_service.get_current_user().await {
+        Ok(user) => user,
+        Err(err) => return Err(err),
+    };
+
+    let project = match self.project_rep.find_by_id(id).await {
+        Some(project) => project,
+        None => return Err(BaseError::new("Not Found".to_string())),
+    };
+
+    if !can_manage_project(self.organization_rep, &user, &project).await {
+      return Err(BaseError::new("Forbidden".to_string()));
+    }
+
+    if let Some(organization_id) = &params.organization_id {
+      if !self.can_manage_organization(&user, organization_id).await {
+        return Err(BaseError::new("Forbidden".to_string()));
+      }
+    }
+
+    match self.project_rep.update_organization(id, params.organization_id.as_deref()).await {
+        Ok(_) => (),
+        Err(e) => return Err(BaseError::new(e)),
+    };
+
+    if project.organization_id == params.organization_id {
+      return Ok(());
+    }
+
+    let diff = FieldDiff::new("organizationId", json!(project.organization_id), json!(params.organization_id));
+    match self.project_rep.insert_changes(id, Some(&user.id), &[diff]).await {
+        Ok(_) => Ok(()),
+        Err(e) => Err(BaseError::new(e)),
+    }
+  }
+
+  pub async fn get_history(&self, id: &str) -> Result<Vec<FieldChange>, BaseError> {
+    let user = match self.user_service.get_current_user().await {
         Ok(user) => user,
         Err(err) => return Err(err),
     };
 
-    Ok(self.project_rep.find_by_query(&params).await)
+    let project = match self.project_rep.find_by_id(id).await {
+        Some(project) => project,
+        None => return Err(BaseError::new("Not Found".to_string())),
+    };
+
+    let is_vendor = project.vendor_id.as_deref() == Some(user.id.as_str());
+    if user.u_type != UserType::Admin && !is_vendor && !can_manage_project(self.organization_rep, &user, &project).await {
+      return Err(BaseError::new("Forbidden".to_string()));
+    }
+
+    Ok(self.project_rep.find_changes(id).await)
+  }
+
+  async fn can_manage_organization(&self, user: &User, organization_id: &str) -> bool {
+    match self.organization_rep.find_role(organization_id, &user.id).await {
+      Some(role) => role.can_manage(),
+      None => false,
+    }
+  }
+}
+
+pub struct ProjectMonitor<'a> {
+  user_rep: &'a (dyn TUserRepositories + Send + Sync),
+  notification_rep: &'a (dyn TNotificationRepositories + Send + Sync),
+  project_rep:  &'a (dyn TProjectRepositories + Send + Sync),
+  preference_rep: &'a (dyn TPreferenceRepositories + Send + Sync),
+  storage: Box<dyn TStorage + Send + Sync>,
+}
+
+impl<'a> ProjectMonitor<'a> {
+  pub fn new(
+      user_rep: &'a (dyn TUserRepositories + Send + Sync),
+      notification_rep: &'a (dyn TNotificationRepositories + Send + Sync),
+      project_rep:  &'a (dyn TProjectRepositories + Send + Sync),
+      preference_rep: &'a (dyn TPreferenceRepositories + Send + Sync),
+  ) -> Self {
+      Self {
+          user_rep,
+          notification_rep,
+          project_rep,
+          preference_rep,
+          storage: Box::new(LocalStorage::default()),
+      }
+  }
+
+  pub async fn mark_overdue(&self) -> Result<(), BaseError> {
+    for project in self.project_rep.find_overdue().await {
+      match self.project_rep.transition(&project.id, &ProjectStatus::Ongoing, &ProjectStatus::Overdue, None, None).await {
+          Ok(true) => (),
+          Ok(false) => continue,
+          Err(e) => return Err(BaseError::new(e)),
+      };
+
+      notify_status_change(
+        self.user_rep,
+        self.notification_rep,
+        self.preference_rep,
+        &project,
+        &ProjectStatus::Overdue,
+        None,
+        None,
+      ).await;
+
+      notify_watchers(
+        self.project_rep,
+        self.notification_rep,
+        self.preference_rep,
+        &project,
+        format!("moved the project {} you watch from {} to {}", project.name, project.status.as_str(), ProjectStatus::Overdue.as_str()).as_str(),
+        None,
+      ).await;
+    }
+
+    Ok(())
+  }
+
+  // Returns the ids of purged projects whose files could not be removed
+  pub async fn purge_deleted(&self, retention_days: i32) -> Result<Vec<String>, BaseError> {
+    let ids = match self.project_rep.purge_deleted(retention_days).await {
+        Ok(ids) => ids,
+        Err(e) => return Err(BaseError::new(e)),
+    };
+
+    let mut failed = vec![];
+    for id in ids {
+      if self.storage.remove_prefix(&Attachment::project_prefix(&id)).await.is_err() {
+        failed.push(id);
+      }
+    }
+
+    Ok(failed)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn fields(names: &[&str]) -> Vec<String> {
+    names.iter().map(|name| name.to_string()).collect()
+  }
+
+  fn template() -> ProjectTemplate {
+    ProjectTemplate {
+      id: "template".to_string(),
+      name: "Store fit-out".to_string(),
+      description: Some("Standard store layout".to_string()),
+      building_type: "Retail".to_string(),
+      is_save_carbon: true,
+      appropriate_status: AppropriateStatus::ReadyToHire,
+      has_financing_secured: true,
+      budget_range: BudgetRange::From100kTo500k,
+      square_range: SquareRange::Under2th,
+      commercial_work: CommercialWork::Renovation,
+      architectural_services: vec![ArchitecturalServices::Space],
+      creator_id: "client".to_string(),
+      organization_id: Some("organization".to_string()),
+      created_at: Utc::now(),
+    }
+  }
+
+  fn overrides(value: serde_json::Value) -> UpdateParams {
+    serde_json::from_value(value).unwrap()
+  }
+
+  fn draft() -> ProjectDraft {
+    ProjectDraft {
+      id: "draft".to_string(),
+      name: "Store fit-out".to_string(),
+      city: Some("Berlin".to_string()),
+      street: None,
+      zip_code: None,
+      floor: None,
+      description: None,
+      building_type: Some("Retail".to_string()),
+      is_save_carbon: Some(false),
+      appropriate_status: Some(AppropriateStatus::Researching),
+      has_financing_secured: Some(true),
+      budget_range: Some(BudgetRange::Under100k),
+      square_range: Some(SquareRange::Under2th),
+      commercial_work: Some(CommercialWork::Renovation),
+      architectural_services: Some(vec![]),
+      completion_date: Some(Utc::now() + chrono::Duration::days(90)),
+      created_at: Utc::now(),
+      updated_at: Utc::now(),
+      creator: User {
+        id: "client".to_string(),
+        email: String::new(),
+        first_name: "Test".to_string(),
+        last_name: "Client".to_string(),
+        company: None,
+        phone: None,
+        u_type: UserType::Client,
+        password_alg: String::new(),
+        password_hash: String::new(),
+        is_compliant: false,
+        created_at: Utc::now(),
+        tokens: vec![],
+      },
+      organization_id: None,
+    }
+  }
+
+  #[test]
+  fn material_changes_ignores_cosmetic_fields() {
+    assert!(material_changes(&fields(&["name", "description", "floor", "isSaveCarbon"])).is_empty());
+  }
+
+  #[test]
+  fn material_changes_groups_location_fields() {
+    assert_eq!(material_changes(&fields(&["city", "street", "zipCode"])), vec!["location"]);
+  }
+
+  #[test]
+  fn material_changes_keeps_field_order() {
+    assert_eq!(
+      material_changes(&fields(&["budgetRange", "name", "completionDate", "city"])),
+      vec!["budget range", "completion date", "location"],
+    );
+  }
+
+  #[test]
+  fn from_template_requires_a_location_and_completion_date() {
+    let err = CreateParams::from_template(template(), overrides(serde_json::json!({ "completionDate": Utc::now() }))).unwrap_err();
+    assert_eq!(err, "The city is required");
+
+    let err = CreateParams::from_template(template(), overrides(serde_json::json!({ "city": "Munich" }))).unwrap_err();
+    assert_eq!(err, "The completion date is required");
+  }
+
+  #[test]
+  fn from_template_applies_overrides_over_template_fields() {
+    let params = CreateParams::from_template(
+      template(),
+      overrides(serde_json::json!({
+        "name": "Munich store",
+        "city": "Munich",
+        "zipCode": "80331",
+        "budgetRange": "Under $100k",
+        "completionDate": Utc::now(),
+      })),
+    )
+    .unwrap();
+
+    assert_eq!(params.name, "Munich store");
+    assert_eq!((params.city.as_str(), params.zip_code.as_deref()), ("Munich", Some("80331")));
+    assert_eq!(params.budget_range, "Under $100k");
+    assert_eq!(params.building_type, "Retail");
+    assert_eq!(params.description.as_deref(), Some("Standard store layout"));
+    assert_eq!(params.commercial_work, CommercialWork::Renovation.as_str());
+    assert_eq!(params.architectural_services, vec![ArchitecturalServices::Space.as_str().to_string()]);
+    assert_eq!(params.organization_id.as_deref(), Some("organization"));
+  }
+
+  #[test]
+  fn complete_drafts_convert_to_valid_create_params() {
+    let params = CreateParams::try_from(draft()).unwrap();
+
+    assert!(validate(&params).is_ok());
+    assert_eq!(params.appropriate_status, AppropriateStatus::Researching.as_str());
+    assert_eq!(params.budget_range, BudgetRange::Under100k.as_str());
+  }
+
+  #[test]
+  fn incomplete_drafts_report_the_missing_field() {
+    let mut incomplete = draft();
+    incomplete.city = None;
+    incomplete.budget_range = None;
+    assert_eq!(CreateParams::try_from(incomplete).unwrap_err(), "The city is required");
+
+    let mut incomplete = draft();
+    incomplete.completion_date = None;
+    assert_eq!(CreateParams::try_from(incomplete).unwrap_err(), "The completion date is required");
   }
 }
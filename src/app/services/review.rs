@@ -0,0 +1,196 @@
+use serde::Deserialize;
+use validator::Validate;
+
+use crate::app::{
+    entities::{
+        preferences::notification_category::NotificationCategory,
+        project::status::ProjectStatus,
+        review::Review,
+        user::{user_type::UserType, User},
+    },
+    errors::BaseError,
+    traits::repositories::{
+        notification::TNotificationRepositories,
+        organization::TOrganizationRepositories, preferences::TPreferenceRepositories,
+        project::TProjectRepositories, review::TReviewRepositories, user::TUserRepositories,
+    },
+    utils::validate_params::validate,
+};
+
+use super::{
+    preferences::{in_app_ids, recipients_for},
+    project::can_manage_project,
+    user::UserService,
+};
+
+#[derive(Debug, Validate, Deserialize)]
+pub struct CreateReviewParams {
+    #[validate(range(min = 1, max = 5, message = "Quality should be between 1 and 5"))]
+    pub quality: i16,
+    #[validate(range(min = 1, max = 5, message = "Communication should be between 1 and 5"))]
+    pub communication: i16,
+    #[validate(range(min = 1, max = 5, message = "Timeliness should be between 1 and 5"))]
+    pub timeliness: i16,
+    #[validate(range(min = 1, max = 5, message = "Value should be between 1 and 5"))]
+    pub value: i16,
+    #[validate(length(min = 10, max = 4000, message = "The review length should be 10 to 4000 symbols"))]
+    pub text: String,
+}
+
+#[derive(Debug, Validate, Deserialize)]
+pub struct ReplyParams {
+    #[validate(length(min = 2, max = 4000, message = "The reply length should be 2 to 4000 symbols"))]
+    pub text: String,
+}
+
+pub struct ReviewService<'a> {
+    user_rep: &'a (dyn TUserRepositories + Send + Sync),
+    notification_rep: &'a (dyn TNotificationRepositories + Send + Sync),
+    project_rep: &'a (dyn TProjectRepositories + Send + Sync),
+    organization_rep: &'a (dyn TOrganizationRepositories + Send + Sync),
+    preference_rep: &'a (dyn TPreferenceRepositories + Send + Sync),
+    review_rep: &'a (dyn TReviewRepositories + Send + Sync),
+    user_service: UserService<'a>,
+}
+
+impl<'a> ReviewService<'a> {
+    pub fn new(
+        user_rep: &'a (dyn TUserRepositories + Send + Sync),
+        notification_rep: &'a (dyn TNotificationRepositories + Send + Sync),
+        project_rep: &'a (dyn TProjectRepositories + Send + Sync),
+        organization_rep: &'a (dyn TOrganizationRepositories + Send + Sync),
+        preference_rep: &'a (dyn TPreferenceRepositories + Send + Sync),
+        review_rep: &'a (dyn TReviewRepositories + Send + Sync),
+        token: &'a str,
+    ) -> Self {
+        Self {
+            user_rep,
+            notification_rep,
+            project_rep,
+            organization_rep,
+            preference_rep,
+            review_rep,
+            user_service: UserService::new(user_rep, token),
+        }
+    }
+
+    pub async fn create(&self, project_id: &str, params: CreateReviewParams) -> Result<i32, BaseError> {
+        match validate(&params) {
+            Ok(_) => (),
+            Err(e) => return Err(e),
+        };
+
+        let user = match self.user_service.get_current_user().await {
+            Ok(user) => user,
+            Err(err) => return Err(err),
+        };
+
+        if user.u_type != UserType::Client {
+            return Err(BaseError::new("Forbidden".to_string()));
+        }
+
+        let project = match self.project_rep.find_by_id(project_id).await {
+            Some(project) => project,
+            None => return Err(BaseError::new("Not Found".to_string())),
+        };
+
+        if !can_manage_project(self.organization_rep, &user, &project).await {
+            return Err(BaseError::new("Forbidden".to_string()));
+        }
+
+        if project.status != ProjectStatus::Complete {
+            return Err(BaseError::new("Only completed projects can be reviewed".to_string()));
+        }
+
+        let vendor_id = match &project.vendor_id {
+            Some(vendor_id) => vendor_id,
+            None => return Err(BaseError::new("The project has no awarded vendor".to_string())),
+        };
+
+        let id = match self.review_rep.insert(project_id, &user.id, vendor_id, &params).await {
+            Ok(id) => id,
+            Err(e) => return Err(BaseError::new(e)),
+        };
+
+        if let Some(vendor) = self.user_rep.find_by_id(vendor_id, false).await {
+            let recipients = recipients_for(self.preference_rep, &[vendor]).await;
+            let vendor_ids = in_app_ids(&recipients, &NotificationCategory::Projects);
+
+            if !vendor_ids.is_empty() {
+                let _ = self.notification_rep.insert(
+                    format!("left a review for the project {}", project.name).as_str(),
+                    format!("/projects/{project_id}").as_str(),
+                    false,
+                    false,
+                    &user.id,
+                    vendor_ids,
+                ).await;
+            }
+        }
+
+        Ok(id)
+    }
+
+    pub async fn get_by_project(&self, project_id: &str) -> Result<Review, BaseError> {
+        let user = match self.user_service.get_current_user().await {
+            Ok(user) => user,
+            Err(err) => return Err(err),
+        };
+
+        match self.review_rep.find_by_project(project_id).await {
+            Some(mut review) if !review.is_hidden || Self::can_see_hidden(&user, &review) => {
+                if user.u_type != UserType::Admin {
+                    review.redact_moderation();
+                }
+                Ok(review)
+            }
+            _ => Err(BaseError::new("Not Found".to_string())),
+        }
+    }
+
+    pub async fn get_by_vendor(&self, vendor_id: &str) -> Result<Vec<Review>, BaseError> {
+        let user = match self.user_service.get_current_user().await {
+            Ok(user) => user,
+            Err(err) => return Err(err),
+        };
+
+        let is_admin = user.u_type == UserType::Admin;
+        let mut reviews = self.review_rep.find_by_vendor(vendor_id, is_admin).await;
+        if !is_admin {
+            reviews.iter_mut().for_each(Review::redact_moderation);
+        }
+
+        Ok(reviews)
+    }
+
+    pub async fn reply(&self, id: i32, params: ReplyParams) -> Result<(), BaseError> {
+        match validate(&params) {
+            Ok(_) => (),
+            Err(e) => return Err(e),
+        };
+
+        let user = match self.user_service.get_current_user().await {
+            Ok(user) => user,
+            Err(err) => return Err(err),
+        };
+
+        let review = match self.review_rep.find_by_id(id).await {
+            Some(review) => review,
+            None => return Err(BaseError::new("Not Found".to_string())),
+        };
+
+        if review.vendor.id != user.id {
+            return Err(BaseError::new("Forbidden".to_string()));
+        }
+
+        match self.review_rep.set_reply(id, &params.text).await {
+            Ok(true) => Ok(()),
+            Ok(false) => Err(BaseError::new("The review already has a reply".to_string())),
+            Err(e) => Err(BaseError::new(e)),
+        }
+    }
+
+    fn can_see_hidden(user: &User, review: &Review) -> bool {
+        user.u_type == UserType::Admin || user.id == review.author.id || user.id == review.vendor.id
+    }
+}
@@ -0,0 +1,396 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use validator::{Validate, ValidationError};
+
+use crate::app::{
+    email::project::ProjectEvents,
+    entities::{
+        location::GeoPoint,
+        preferences::notification_category::NotificationCategory,
+        project::{status::ProjectStatus, Project},
+        saved_search::{frequency::AlertFrequency, SavedSearch, SearchMatch},
+        user::{user_type::UserType, User},
+    },
+    errors::BaseError,
+    traits::repositories::{
+        notification::TNotificationRepositories, preferences::TPreferenceRepositories,
+        project::TProjectRepositories, saved_search::TSavedSearchRepositories,
+        user::TUserRepositories,
+    },
+    utils::validate_params::validate,
+};
+
+use super::{
+    preferences::{in_app_ids, recipients_for},
+    project::GetProjectByQueryParams,
+    user::UserService,
+};
+
+const MAX_SAVED_SEARCHES: i64 = 20;
+
+#[derive(Debug, Validate, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateSavedSearchParams {
+    #[validate(length(min = 1, max = 64, message = "The name length should be between 1 and 64 symbols"))]
+    pub name: String,
+    #[validate]
+    pub query: GetProjectByQueryParams,
+    #[validate(custom(function = "validate_frequency", message = "Alert Frequency is invalid"))]
+    pub frequency: String,
+}
+
+#[derive(Debug, Validate, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateSavedSearchParams {
+    #[validate(length(min = 1, max = 64, message = "The name length should be between 1 and 64 symbols"))]
+    pub name: Option<String>,
+    #[validate]
+    pub query: Option<GetProjectByQueryParams>,
+    #[validate(custom(function = "validate_frequency", message = "Alert Frequency is invalid"))]
+    pub frequency: Option<String>,
+}
+
+fn validate_frequency(value: &str) -> Result<(), ValidationError> {
+    match AlertFrequency::from_str(value) {
+        Ok(_) => Ok(()),
+        Err(_) => Err(ValidationError::new("")),
+    }
+}
+
+fn query_origin(query: &GetProjectByQueryParams) -> Option<GeoPoint> {
+    query
+        .latitude
+        .zip(query.longitude)
+        .map(|(latitude, longitude)| GeoPoint { latitude, longitude })
+}
+
+fn matches(query: &GetProjectByQueryParams, project: &Project, origin: Option<GeoPoint>) -> bool {
+    let text_eq = |filter: &Option<String>, value: &str| match filter {
+        Some(f) => f.trim().to_lowercase() == value.to_lowercase(),
+        None => true,
+    };
+
+    if query.status.as_deref().is_some_and(|s| s != ProjectStatus::Active.as_str()) {
+        return false;
+    }
+
+    if query.name.as_ref().is_some_and(|n| !project.name.to_lowercase().contains(&n.trim().to_lowercase())) {
+        return false;
+    }
+
+    if !text_eq(&query.city, &project.city)
+        || !text_eq(&query.budget_range, project.budget_range.as_str())
+        || !text_eq(&query.square_range, project.square_range.as_str())
+        || !text_eq(&query.commercial_work, project.commercial_work.as_str())
+    {
+        return false;
+    }
+
+    if query.is_save_carbon.is_some_and(|v| v != project.is_save_carbon)
+        || query.completion_from.is_some_and(|d| project.completion_date < d)
+        || query.completion_to.is_some_and(|d| project.completion_date > d)
+    {
+        return false;
+    }
+
+    let location = project
+        .latitude
+        .zip(project.longitude)
+        .map(|(latitude, longitude)| GeoPoint { latitude, longitude });
+
+    if let Some(radius) = query.radius {
+        match (origin, location) {
+            (Some(origin), Some(location)) if origin.distance_km(&location) <= radius => (),
+            _ => return false,
+        }
+    }
+
+    if let (Some(min_lat), Some(min_lng), Some(max_lat), Some(max_lng)) =
+        (query.min_latitude, query.min_longitude, query.max_latitude, query.max_longitude)
+    {
+        match location {
+            Some(l) if (min_lat..=max_lat).contains(&l.latitude) && (min_lng..=max_lng).contains(&l.longitude) => (),
+            _ => return false,
+        }
+    }
+
+    true
+}
+
+pub struct SavedSearchService<'a> {
+    user_rep: &'a (dyn TUserRepositories + Send + Sync),
+    saved_search_rep: &'a (dyn TSavedSearchRepositories + Send + Sync),
+    user_service: UserService<'a>,
+}
+
+impl<'a> SavedSearchService<'a> {
+    pub fn new(
+        user_rep: &'a (dyn TUserRepositories + Send + Sync),
+        saved_search_rep: &'a (dyn TSavedSearchRepositories + Send + Sync),
+        token: &'a str,
+    ) -> Self {
+        Self {
+            user_rep,
+            saved_search_rep,
+            user_service: UserService::new(user_rep, token),
+        }
+    }
+
+    pub async fn create(&self, params: CreateSavedSearchParams) -> Result<SavedSearch, BaseError> {
+        match validate(&params) {
+            Ok(_) => (),
+            Err(e) => return Err(e),
+        };
+
+        let user = match self.get_current_vendor().await {
+            Ok(user) => user,
+            Err(err) => return Err(err),
+        };
+
+        match self.check_query(&user, &params.query).await {
+            Ok(_) => (),
+            Err(err) => return Err(err),
+        };
+
+        if self.saved_search_rep.count_by_user(&user.id).await >= MAX_SAVED_SEARCHES {
+            return Err(BaseError::new(format!("You can save max {MAX_SAVED_SEARCHES} searches")));
+        }
+
+        let id = match self.saved_search_rep.insert(&user.id, &params).await {
+            Ok(id) => id,
+            Err(e) => return Err(BaseError::new(e)),
+        };
+
+        match self.saved_search_rep.find_by_id(&id).await {
+            Some(search) => Ok(search),
+            None => Err(BaseError::new("Not Found".to_string())),
+        }
+    }
+
+    pub async fn get_all(&self) -> Result<Vec<SavedSearch>, BaseError> {
+        let user = match self.get_current_vendor().await {
+            Ok(user) => user,
+            Err(err) => return Err(err),
+        };
+
+        Ok(self.saved_search_rep.find_by_user(&user.id).await)
+    }
+
+    pub async fn update(&self, id: &str, params: UpdateSavedSearchParams) -> Result<SavedSearch, BaseError> {
+        match validate(&params) {
+            Ok(_) => (),
+            Err(e) => return Err(e),
+        };
+
+        let user = match self.get_own(id).await {
+            Ok((user, _)) => user,
+            Err(err) => return Err(err),
+        };
+
+        if let Some(query) = &params.query {
+            match self.check_query(&user, query).await {
+                Ok(_) => (),
+                Err(err) => return Err(err),
+            };
+        }
+
+        match self.saved_search_rep.update(id, &params).await {
+            Ok(_) => (),
+            Err(e) => return Err(BaseError::new(e)),
+        };
+
+        match self.saved_search_rep.find_by_id(id).await {
+            Some(search) => Ok(search),
+            None => Err(BaseError::new("Not Found".to_string())),
+        }
+    }
+
+    pub async fn delete(&self, id: &str) -> Result<(), BaseError> {
+        match self.get_own(id).await {
+            Ok(_) => (),
+            Err(err) => return Err(err),
+        };
+
+        match self.saved_search_rep.delete(id).await {
+            Ok(_) => Ok(()),
+            Err(e) => Err(BaseError::new(e)),
+        }
+    }
+
+    async fn check_query(&self, user: &User, query: &GetProjectByQueryParams) -> Result<(), BaseError> {
+        if let Err(e) = query.has_bounds() {
+            return Err(BaseError::new(e));
+        }
+
+        match (query.latitude, query.longitude) {
+            (Some(_), Some(_)) => Ok(()),
+            (None, None) if query.radius.is_some() => {
+                match self.user_rep.find_location(&user.id).await.and_then(|l| l.point()) {
+                    Some(_) => Ok(()),
+                    None => Err(BaseError::new("Set your location or pass latitude and longitude to search by distance".to_string())),
+                }
+            }
+            (None, None) => Ok(()),
+            _ => Err(BaseError::new("Both latitude and longitude are required".to_string())),
+        }
+    }
+
+    async fn get_own(&self, id: &str) -> Result<(User, SavedSearch), BaseError> {
+        let user = match self.get_current_vendor().await {
+            Ok(user) => user,
+            Err(err) => return Err(err),
+        };
+
+        match self.saved_search_rep.find_by_id(id).await {
+            Some(search) if search.user_id == user.id => Ok((user, search)),
+            _ => Err(BaseError::new("Not Found".to_string())),
+        }
+    }
+
+    async fn get_current_vendor(&self) -> Result<User, BaseError> {
+        let user = match self.user_service.get_current_user().await {
+            Ok(user) => user,
+            Err(err) => return Err(err),
+        };
+
+        if user.u_type != UserType::Vendor {
+            return Err(BaseError::new("Forbidden".to_string()));
+        }
+
+        Ok(user)
+    }
+}
+
+pub struct SavedSearchMonitor<'a> {
+    user_rep: &'a (dyn TUserRepositories + Send + Sync),
+    notification_rep: &'a (dyn TNotificationRepositories + Send + Sync),
+    project_rep: &'a (dyn TProjectRepositories + Send + Sync),
+    saved_search_rep: &'a (dyn TSavedSearchRepositories + Send + Sync),
+    preference_rep: &'a (dyn TPreferenceRepositories + Send + Sync),
+}
+
+impl<'a> SavedSearchMonitor<'a> {
+    pub fn new(
+        user_rep: &'a (dyn TUserRepositories + Send + Sync),
+        notification_rep: &'a (dyn TNotificationRepositories + Send + Sync),
+        project_rep: &'a (dyn TProjectRepositories + Send + Sync),
+        saved_search_rep: &'a (dyn TSavedSearchRepositories + Send + Sync),
+        preference_rep: &'a (dyn TPreferenceRepositories + Send + Sync),
+    ) -> Self {
+        Self {
+            user_rep,
+            notification_rep,
+            project_rep,
+            saved_search_rep,
+            preference_rep,
+        }
+    }
+
+    pub async fn alert_new_projects(&self) -> Result<(), BaseError> {
+        let projects = self.project_rep.find_unalerted().await;
+        if projects.is_empty() {
+            return Ok(());
+        }
+
+        let searches: Vec<(SavedSearch, GetProjectByQueryParams)> = self
+            .saved_search_rep
+            .find_alerting()
+            .await
+            .into_iter()
+            .filter_map(|s| serde_json::from_value(s.query.clone()).ok().map(|q| (s, q)))
+            .collect();
+
+        let mut origins: HashMap<String, Option<GeoPoint>> = HashMap::new();
+        for (search, query) in searches.iter() {
+            if query.radius.is_some() && query_origin(query).is_none() && !origins.contains_key(&search.user_id) {
+                let origin = self.user_rep.find_location(&search.user_id).await.and_then(|l| l.point());
+                origins.insert(search.user_id.clone(), origin);
+            }
+        }
+
+        for project in projects {
+            let matched: Vec<&SavedSearch> = searches
+                .iter()
+                .filter(|(search, query)| {
+                    let origin = query_origin(query).or_else(|| origins.get(&search.user_id).copied().flatten());
+                    matches(query, &project, origin)
+                })
+                .map(|(search, _)| search)
+                .collect();
+
+            if !matched.is_empty() {
+                let ids: Vec<&str> = matched.iter().map(|s| s.id.as_str()).collect();
+                let new_ids = match self.saved_search_rep.insert_matches(&project.id, &ids).await {
+                    Ok(ids) => ids,
+                    Err(e) => return Err(BaseError::new(e)),
+                };
+
+                let instant: Vec<&SavedSearch> = matched
+                    .into_iter()
+                    .filter(|s| s.frequency.is_instant() && new_ids.contains(&s.id))
+                    .collect();
+                self.notify_instant(&project, instant).await;
+            }
+
+            match self.project_rep.mark_alerted(&project.id).await {
+                Ok(_) => (),
+                Err(e) => return Err(BaseError::new(e)),
+            };
+        }
+
+        Ok(())
+    }
+
+    pub async fn send_digests(&self) -> Result<(), BaseError> {
+        let pending = match self.saved_search_rep.claim_pending_digest().await {
+            Ok(pending) => pending,
+            Err(e) => return Err(BaseError::new(e)),
+        };
+
+        let mut by_user: Vec<(User, Vec<&SearchMatch>)> = vec![];
+        for item in pending.iter() {
+            match by_user.last_mut() {
+                Some((user, items)) if user.id == item.user.id => items.push(item),
+                _ => by_user.push((item.user.clone(), vec![item])),
+            }
+        }
+
+        let users: Vec<User> = by_user.iter().map(|(user, _)| user.clone()).collect();
+        let recipients = recipients_for(self.preference_rep, &users).await;
+        let events = ProjectEvents::default();
+
+        for (recipient, (_, items)) in recipients.into_iter().zip(by_user.iter()) {
+            let _ = events.on_saved_search_digest(recipient, items).await;
+        }
+
+        Ok(())
+    }
+
+    async fn notify_instant(&self, project: &Project, searches: Vec<&SavedSearch>) {
+        for search in searches {
+            let user = match self.user_rep.find_by_id(&search.user_id, false).await {
+                Some(user) => user,
+                None => continue,
+            };
+
+            let recipients = recipients_for(self.preference_rep, std::slice::from_ref(&user)).await;
+            let receiver_ids = in_app_ids(&recipients, &NotificationCategory::Projects);
+            if receiver_ids.is_empty() {
+                continue;
+            }
+
+            let _ = self
+                .notification_rep
+                .insert(
+                    format!("posted a project {} matching your search {}", project.name, search.name).as_str(),
+                    format!("/projects/{}", project.id).as_str(),
+                    false,
+                    false,
+                    &project.creator.id,
+                    receiver_ids,
+                )
+                .await;
+        }
+    }
+}
@@ -2,7 +2,7 @@ use serde::Deserialize;
 use validator::{Validate, ValidationError};
 
 use crate::app::{
-    entities::user::{User, user_type::UserType},
+    entities::{page::Page, user::{User, user_type::UserType}},
     errors::BaseError,
     traits::repositories::user::TUserRepositories, utils::{jwt::{ClaimType, JWT}, validate_params::validate},
 };
@@ -11,10 +11,16 @@ use crate::app::{
 pub struct GetAllParams {
     #[validate(custom(function = "validate_user_role", message = "User type is invalid"))]
     #[serde(rename = "type")]
-    u_type: Option<String>, 
-    limit: Option<i64>, 
-    skip: Option<i64>,
-    search: Option<String>
+    pub u_type: Option<String>, 
+    #[validate(range(min = 1, max = 100, message = "Limit should be between 1 and 100"))]
+    pub limit: Option<i64>, 
+    #[validate(range(min = 0, message = "Skip should not be negative"))]
+    pub skip: Option<i64>,
+    pub search: Option<String>,
+    #[validate(custom(function = "validate_sort", message = "Sort is invalid"))]
+    pub sort: Option<String>,
+    #[validate(custom(function = "validate_order", message = "Order is invalid"))]
+    pub order: Option<String>,
 }
 
 fn validate_user_role(role: &str) -> Result<(), ValidationError> {
@@ -24,6 +30,20 @@ fn validate_user_role(role: &str) -> Result<(), ValidationError> {
     Err(ValidationError::new(""))
 }
 
+fn validate_sort(value: &str) -> Result<(), ValidationError> {
+    match value {
+        "createdAt" | "name" | "rating" | "reviews" => Ok(()),
+        _ => Err(ValidationError::new("")),
+    }
+}
+
+fn validate_order(value: &str) -> Result<(), ValidationError> {
+    match value {
+        "asc" | "desc" => Ok(()),
+        _ => Err(ValidationError::new("")),
+    }
+}
+
 pub struct UserService<'a> {
     user_rep: &'a (dyn TUserRepositories + Send + Sync),
     token: &'a str
@@ -52,7 +72,7 @@ impl<'a> UserService<'a> {
         }
     }
 
-    pub async fn get_all(&self, params: GetAllParams) -> Result<Vec<User>, BaseError>{
+    pub async fn get_all(&self, mut params: GetAllParams) -> Result<Page<User>, BaseError>{
         match validate(&params) {
             Ok(_) => (),
             Err(e) => return Err(e),
@@ -67,14 +87,13 @@ impl<'a> UserService<'a> {
             return Err(BaseError::new("Forbidden".to_string()));
         }
 
-        let types = match params.u_type {
-            Some(value) => vec![UserType::from_str(&value)],
+        let types = match &params.u_type {
+            Some(value) => vec![UserType::from_str(value)],
             None => vec![UserType::Client, UserType::Vendor, UserType::Admin]
         };
-        
-        let users = self.user_rep.find(types, params.search.as_deref(), params.limit, params.skip).await;
-        
-        Ok(users)
+        params.limit = Some(params.limit.unwrap_or(20));
+
+        Ok(self.user_rep.search(types, &params).await)
     }
 
     fn id_from_token(&self, token: &str) -> Result<String, BaseError> {
@@ -0,0 +1,48 @@
+use async_trait::async_trait;
+use std::path::PathBuf;
+
+use super::traits::storage::TStorage;
+
+pub struct LocalStorage {
+    root: PathBuf,
+}
+
+impl LocalStorage {
+    pub fn default() -> Self {
+        let root = std::env::var("STORAGE_PATH").unwrap_or("uploads".to_string());
+        Self { root: PathBuf::from(root) }
+    }
+}
+
+#[async_trait]
+impl TStorage for LocalStorage {
+    async fn put(&self, key: &str, data: &[u8]) -> Result<(), String> {
+        let path = self.root.join(key);
+
+        if let Some(parent) = path.parent() {
+            if let Err(err) = tokio::fs::create_dir_all(parent).await {
+                return Err(err.to_string());
+            }
+        }
+
+        match tokio::fs::write(path, data).await {
+            Ok(_) => Ok(()),
+            Err(err) => Err(err.to_string()),
+        }
+    }
+
+    async fn get(&self, key: &str) -> Result<Vec<u8>, String> {
+        match tokio::fs::read(self.root.join(key)).await {
+            Ok(data) => Ok(data),
+            Err(err) => Err(err.to_string()),
+        }
+    }
+
+    async fn remove_prefix(&self, prefix: &str) -> Result<(), String> {
+        match tokio::fs::remove_dir_all(self.root.join(prefix)).await {
+            Ok(_) => Ok(()),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(err) => Err(err.to_string()),
+        }
+    }
+}
@@ -1,2 +1,3 @@
 pub mod repositories;
-pub mod send_email;
\ No newline at end of file
+pub mod send_email;
+pub mod storage;
\ No newline at end of file
@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+
+use crate::app::{entities::attachment::Attachment, services::attachment::UploadParams};
+
+#[async_trait]
+pub trait TAttachmentRepositories {
+    async fn insert(
+        &self,
+        project_id: &str,
+        bid_id: Option<&str>,
+        file_id: Option<&str>,
+        uploaded_by: &str,
+        data: &UploadParams,
+    ) -> Result<Attachment, String>;
+    async fn find_by_id(&self, id: &str) -> Option<Attachment>;
+    async fn find_by_project(&self, project_id: &str) -> Vec<Attachment>;
+    async fn find_versions(&self, project_id: &str, file_id: &str) -> Vec<Attachment>;
+    async fn delete(&self, id: &str) -> Result<bool, String>;
+}
@@ -0,0 +1,16 @@
+use async_trait::async_trait;
+use serde_json::Value;
+
+use crate::app::entities::audit::{audit_action::AuditAction, AuditEntry};
+
+#[async_trait]
+pub trait TAuditRepositories {
+    async fn insert(
+        &self,
+        admin_id: &str,
+        target_user_id: &str,
+        action: AuditAction,
+        details: Value,
+    ) -> Result<i32, String>;
+    async fn find_by_target(&self, target_user_id: &str) -> Vec<AuditEntry>;
+}
@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+
+use crate::app::{
+    entities::bid::{status::BidStatus, Bid},
+    services::bid::{CreateBidParams, UpdateBidParams},
+};
+
+#[async_trait]
+pub trait TBidRepositories {
+    async fn insert(&self, project_id: &str, vendor_id: &str, data: &CreateBidParams) -> Result<String, String>;
+    async fn find_by_id(&self, id: &str) -> Option<Bid>;
+    async fn find_by_project(&self, project_id: &str) -> Vec<Bid>;
+    async fn find_by_vendor(&self, vendor_id: &str) -> Vec<Bid>;
+    async fn update(&self, id: &str, data: &UpdateBidParams) -> Result<bool, String>;
+    async fn set_status(&self, id: &str, from: &BidStatus, to: &BidStatus) -> Result<bool, String>;
+    async fn award(&self, id: &str, project_id: &str, vendor_id: &str, actor_id: &str, note: Option<&str>) -> Result<bool, String>;
+}
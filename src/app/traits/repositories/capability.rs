@@ -0,0 +1,16 @@
+use async_trait::async_trait;
+
+use crate::app::{
+    entities::{
+        capability::{VendorCandidate, VendorCapabilities},
+        location::GeoPoint,
+    },
+    services::matching::UpdateCapabilitiesParams,
+};
+
+#[async_trait]
+pub trait TCapabilityRepositories {
+    async fn upsert(&self, user_id: &str, data: &UpdateCapabilitiesParams) -> Result<(), String>;
+    async fn find_by_user(&self, user_id: &str) -> Option<VendorCapabilities>;
+    async fn find_candidates(&self, origin: Option<&GeoPoint>) -> Vec<VendorCandidate>;
+}
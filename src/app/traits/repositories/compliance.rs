@@ -0,0 +1,16 @@
+use async_trait::async_trait;
+
+use crate::app::{
+    entities::compliance::{document_type::DocumentType, ComplianceDocument},
+    services::compliance::UpsertDocumentParams,
+};
+
+#[async_trait]
+pub trait TComplianceRepositories {
+    async fn upsert(&self, user_id: &str, data: &UpsertDocumentParams) -> Result<i32, String>;
+    async fn find_by_user(&self, user_id: &str) -> Vec<ComplianceDocument>;
+    async fn find_for_reminder(&self, days: i32) -> Vec<ComplianceDocument>;
+    async fn set_reminded(&self, id: i32, days: i32) -> Result<bool, String>;
+    async fn refresh_compliance(&self, user_id: &str, required: Vec<DocumentType>) -> Result<bool, String>;
+    async fn set_expired_non_compliant(&self) -> Result<Vec<String>, String>;
+}
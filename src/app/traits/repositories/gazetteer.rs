@@ -0,0 +1,9 @@
+use async_trait::async_trait;
+
+use crate::app::entities::location::GazetteerEntry;
+
+#[async_trait]
+pub trait TGazetteerRepositories {
+    async fn upsert(&self, entries: &[GazetteerEntry]) -> Result<u64, String>;
+    async fn count(&self) -> i64;
+}
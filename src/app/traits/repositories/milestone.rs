@@ -0,0 +1,21 @@
+use async_trait::async_trait;
+
+use crate::app::{
+    entities::milestone::Milestone,
+    services::milestone::{CreateMilestoneParams, UpdateMilestoneParams, UpdateTaskParams},
+};
+
+#[async_trait]
+pub trait TMilestoneRepositories {
+    async fn insert(&self, project_id: &str, data: &CreateMilestoneParams) -> Result<String, String>;
+    async fn find_by_id(&self, id: &str) -> Option<Milestone>;
+    async fn find_by_project(&self, project_id: &str) -> Vec<Milestone>;
+    async fn update(&self, id: &str, data: &UpdateMilestoneParams) -> Result<bool, String>;
+    async fn complete(&self, id: &str) -> Result<bool, String>;
+    async fn reorder(&self, project_id: &str, ids: &[String]) -> Result<u64, String>;
+    async fn insert_task(&self, milestone_id: &str, title: &str) -> Result<i32, String>;
+    async fn update_task(&self, milestone_id: &str, task_id: i32, data: &UpdateTaskParams) -> Result<bool, String>;
+    async fn reorder_tasks(&self, milestone_id: &str, ids: &[i32]) -> Result<u64, String>;
+    async fn find_slipped(&self) -> Vec<Milestone>;
+    async fn mark_slip_notified(&self, id: &str) -> Result<bool, String>;
+}
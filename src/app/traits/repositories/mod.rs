@@ -1,3 +1,14 @@
+pub mod attachment;
+pub mod audit;
+pub mod bid;
+pub mod capability;
+pub mod compliance;
+pub mod gazetteer;
+pub mod milestone;
 pub mod notification;
+pub mod organization;
+pub mod preferences;
+pub mod review;
+pub mod saved_search;
 pub mod user;
 pub mod project;
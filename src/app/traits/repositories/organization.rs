@@ -0,0 +1,16 @@
+use async_trait::async_trait;
+
+use crate::app::entities::{
+    organization::{member_role::MemberRole, Organization},
+    user::user_type::UserType,
+};
+
+#[async_trait]
+pub trait TOrganizationRepositories {
+    async fn insert(&self, name: &str, o_type: UserType, owner_id: &str) -> Result<String, String>;
+    async fn find_by_id(&self, id: &str) -> Option<Organization>;
+    async fn find_by_user(&self, user_id: &str) -> Vec<Organization>;
+    async fn find_role(&self, id: &str, user_id: &str) -> Option<MemberRole>;
+    async fn upsert_member(&self, id: &str, user_id: &str, role: MemberRole) -> Result<bool, String>;
+    async fn remove_member(&self, id: &str, user_id: &str) -> Result<bool, String>;
+}
@@ -0,0 +1,10 @@
+use async_trait::async_trait;
+
+use crate::app::entities::preferences::Preferences;
+
+#[async_trait]
+pub trait TPreferenceRepositories {
+    async fn find_by_user(&self, user_id: &str) -> Option<Preferences>;
+    async fn find_by_users(&self, user_ids: Vec<&str>) -> Vec<(String, Preferences)>;
+    async fn upsert(&self, user_id: &str, preferences: &Preferences) -> Result<bool, String>;
+}
@@ -1,10 +1,43 @@
 use async_trait::async_trait;
 
-use crate::app::{services::project::{CreateParams, GetProjectByQueryParams}, entities::project::{Project, status::ProjectStatus}};
+use crate::app::{services::project::{CreateDraftParams, CreateParams, GetProjectByQueryParams, UpdateParams}, entities::{page::Page, project::{Project, status::ProjectStatus, status_change::StatusChange, field_change::{FieldChange, FieldDiff}, draft::ProjectDraft, scope::ProjectScope, template::ProjectTemplate}, location::GeoPoint, user::User}};
 
 #[async_trait]
 pub trait TProjectRepositories {
     async fn insert(&self, user_id: &str, status: ProjectStatus, data: &CreateParams) -> Result<String, String>;
+    async fn insert_draft(&self, user_id: &str, data: &CreateDraftParams) -> Result<String, String>;
+    async fn find_draft_by_id(&self, id: &str) -> Option<ProjectDraft>;
+    async fn find_drafts(&self, user_id: &str) -> Vec<ProjectDraft>;
     async fn find_by_id(&self, id: &str) -> Option<Project>;
-    async fn find_by_query(&self, query: &GetProjectByQueryParams) -> Vec<Project>;
+    async fn find_visible_by_id(&self, id: &str, scope: &ProjectScope) -> Option<Project>;
+    async fn find_by_query(&self, query: &GetProjectByQueryParams, scope: &ProjectScope, origin: Option<&GeoPoint>) -> Page<Project>;
+    async fn update(&self, id: &str, data: &UpdateParams, actor_id: Option<&str>) -> Result<Vec<String>, String>;
+    async fn transition(&self, id: &str, from: &ProjectStatus, to: &ProjectStatus, actor_id: Option<&str>, reason: Option<&str>) -> Result<bool, String>;
+    async fn find_overdue(&self) -> Vec<Project>;
+    async fn find_status_history(&self, id: &str) -> Vec<StatusChange>;
+    async fn insert_changes(&self, id: &str, actor_id: Option<&str>, changes: &[FieldDiff]) -> Result<(), String>;
+    async fn find_changes(&self, id: &str) -> Vec<FieldChange>;
+    async fn find_open_for_vendor(&self, scope: &ProjectScope, origin: Option<&GeoPoint>) -> Vec<Project>;
+    async fn find_unalerted(&self) -> Vec<Project>;
+    async fn mark_alerted(&self, id: &str) -> Result<bool, String>;
+    async fn watch(&self, id: &str, user_id: &str) -> Result<bool, String>;
+    async fn unwatch(&self, id: &str, user_id: &str) -> Result<bool, String>;
+    async fn find_watched(&self, user_id: &str, scope: &ProjectScope) -> Vec<Project>;
+    async fn find_watchers(&self, id: &str) -> Vec<User>;
+    async fn find_interested_vendors(&self, id: &str) -> Vec<User>;
+    async fn insert_template(&self, id: &str, user_id: &str, name: &str) -> Result<String, String>;
+    async fn find_template_by_id(&self, id: &str) -> Option<ProjectTemplate>;
+    async fn find_templates(&self, user_id: &str) -> Vec<ProjectTemplate>;
+    async fn delete_template(&self, id: &str) -> Result<bool, String>;
+    async fn locate_missing(&self) -> Result<u64, String>;
+    async fn update_organization(&self, id: &str, organization_id: Option<&str>) -> Result<bool, String>;
+    async fn count_by_creator(&self, user_id: &str, status: ProjectStatus) -> i64;
+    async fn is_counterparty(&self, user_id: &str, other_id: &str) -> bool;
+    async fn owns_any(&self, user_id: &str) -> bool;
+    async fn soft_delete(&self, id: &str, actor_id: &str) -> Result<bool, String>;
+    async fn restore(&self, id: &str, admin_id: &str) -> Result<bool, String>;
+    async fn set_archived(&self, id: &str, is_archived: bool, actor_id: &str) -> Result<bool, String>;
+    async fn find_deleted(&self, limit: i64, skip: i64) -> Page<Project>;
+    async fn find_deleted_drafts(&self, limit: i64, skip: i64) -> Page<ProjectDraft>;
+    async fn purge_deleted(&self, days: i32) -> Result<Vec<String>, String>;
 }
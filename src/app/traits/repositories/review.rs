@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+
+use crate::app::{
+    entities::{audit::AuditRecord, review::{RatingSummary, Review}},
+    services::review::CreateReviewParams,
+};
+
+#[async_trait]
+pub trait TReviewRepositories {
+    async fn insert(&self, project_id: &str, author_id: &str, vendor_id: &str, data: &CreateReviewParams) -> Result<i32, String>;
+    async fn find_by_id(&self, id: i32) -> Option<Review>;
+    async fn find_by_project(&self, project_id: &str) -> Option<Review>;
+    async fn find_by_vendor(&self, vendor_id: &str, with_hidden: bool) -> Vec<Review>;
+    async fn summary(&self, vendor_id: &str) -> RatingSummary;
+    async fn set_reply(&self, id: i32, reply: &str) -> Result<bool, String>;
+    async fn set_hidden(&self, id: i32, is_hidden: bool, note: Option<&str>, audit: &AuditRecord) -> Result<bool, String>;
+}
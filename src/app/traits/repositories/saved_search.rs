@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+
+use crate::app::{
+    entities::saved_search::{SavedSearch, SearchMatch},
+    services::saved_search::{CreateSavedSearchParams, UpdateSavedSearchParams},
+};
+
+#[async_trait]
+pub trait TSavedSearchRepositories {
+    async fn insert(&self, user_id: &str, data: &CreateSavedSearchParams) -> Result<String, String>;
+    async fn find_by_id(&self, id: &str) -> Option<SavedSearch>;
+    async fn find_by_user(&self, user_id: &str) -> Vec<SavedSearch>;
+    async fn count_by_user(&self, user_id: &str) -> i64;
+    async fn update(&self, id: &str, data: &UpdateSavedSearchParams) -> Result<bool, String>;
+    async fn delete(&self, id: &str) -> Result<bool, String>;
+    async fn find_alerting(&self) -> Vec<SavedSearch>;
+    async fn insert_matches(&self, project_id: &str, search_ids: &[&str]) -> Result<Vec<String>, String>;
+    async fn claim_pending_digest(&self) -> Result<Vec<SearchMatch>, String>;
+}
@@ -1,23 +1,21 @@
-use crate::app::entities::user::{user_email::UserEmail, user_type::UserType, User};
+use crate::app::{
+    entities::{
+        audit::AuditRecord,
+        location::UserLocation,
+        page::Page,
+        user::{user_email::UserEmail, user_session::UserSession, user_type::UserType, NewUser, User},
+    },
+    services::user::GetAllParams,
+};
 use async_trait::async_trait;
 #[async_trait]
 pub trait TUserRepositories {
-    async fn insert(
-        &self,
-        first_name: &str,
-        last_name: &str,
-        phone: Option<&str>,
-        email: &str,
-        p_hash: &str,
-        p_alg: &str,
-        u_type: &str,
-        is_verify: bool,
-        is_primary: bool,
-    ) -> Result<String, String>;
+    async fn insert(&self, data: &NewUser<'_>) -> Result<String, String>;
 
     async fn find_by_email(&self, email: &str, with_tokens: bool) -> Option<(User, UserEmail)>;
     async fn find_by_id(&self, id: &str, with_tokens: bool) -> Option<User>;
-    async fn find(&self, types: Vec<UserType>, search: Option<&str>, limit: Option<i64>, skip: Option<i64>) -> Vec<User>;
+    async fn find(&self, types: Vec<UserType>) -> Vec<User>;
+    async fn search(&self, types: Vec<UserType>, params: &GetAllParams) -> Page<User>;
 
     async fn update_password(&self, user_id: &str, alg: &str, hash: &str) -> Result<bool, String>;
 
@@ -31,4 +29,13 @@ pub trait TUserRepositories {
     async fn remove_user_tokens(&self, user_id: &str, tokens: Vec<&str>) -> Result<(), String>;
 
     async fn update_verify_email(&self, email: &str, is_verify: bool) -> Result<bool, String>;
+    async fn force_verify_email(&self, user_id: &str, email: &str, audit: &AuditRecord) -> Result<bool, String>;
+
+    async fn find_emails(&self, user_id: &str) -> Vec<UserEmail>;
+    async fn find_sessions(&self, user_id: &str) -> Vec<UserSession>;
+    async fn update_type(&self, user_id: &str, u_type: &str, audit: &AuditRecord) -> Result<bool, String>;
+    async fn update_location(&self, user_id: &str, city: Option<&str>, zip_code: Option<&str>) -> Result<bool, String>;
+    async fn find_location(&self, user_id: &str) -> Option<UserLocation>;
+    async fn locate_missing(&self) -> Result<u64, String>;
+    async fn delete(&self, user_id: &str, audit: &AuditRecord) -> Result<bool, String>;
 }
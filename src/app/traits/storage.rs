@@ -0,0 +1,8 @@
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait TStorage {
+    async fn put(&self, key: &str, data: &[u8]) -> Result<(), String>;
+    async fn get(&self, key: &str) -> Result<Vec<u8>, String>;
+    async fn remove_prefix(&self, prefix: &str) -> Result<(), String>;
+}
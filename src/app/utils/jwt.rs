@@ -11,6 +11,7 @@ pub enum ClaimType {
     Login,
     VerifyEmail,
     ForgotPassword,
+    Download,
 }
 
 impl fmt::Display for ClaimType {
@@ -20,6 +21,7 @@ impl fmt::Display for ClaimType {
             ClaimType::Login => write!(f, "Login"),
             ClaimType::VerifyEmail => write!(f, "VerifyEmail"),
             ClaimType::ForgotPassword => write!(f, "ForgotPassword"),
+            ClaimType::Download => write!(f, "Download"),
         }
     }
 }
@@ -76,6 +78,20 @@ impl JWT {
         self.create(&claims)
     }
 
+    pub fn download(&self, attachment_id: &str) -> Result<String, String> {
+        let claims = Claims {
+            sub: attachment_id.to_string(),
+            user_type: None,
+            claim_type: ClaimType::Download.to_string(),
+            exp: Utc::now()
+                .checked_add_signed(chrono::Duration::minutes(15))
+                .expect("valid timestamp")
+                .timestamp() as usize,
+            iat: SystemTime::now(),
+        };
+        self.create(&claims)
+    }
+
     pub fn parse(&self, token: &str, claim_type: Option<ClaimType>) -> Result<Claims, String> {
         let token_message = decode::<Claims>(
             &token,
@@ -0,0 +1,180 @@
+use crate::app::{
+    entities::attachment::{category::AttachmentCategory, Attachment},
+    services::attachment::UploadParams,
+    traits::repositories::attachment::TAttachmentRepositories,
+};
+use async_trait::async_trait;
+use std::{sync::Arc, time::SystemTime};
+use tokio_postgres::{Client, Row};
+
+impl Attachment {
+    pub(super) fn from_row(row: &Row) -> Self {
+        Attachment {
+            id: row.get::<&str, String>("id"),
+            file_id: row.get::<&str, String>("file_id"),
+            project_id: row.get::<&str, String>("project_id"),
+            bid_id: row.get::<&str, Option<String>>("bid_id"),
+            category: AttachmentCategory::from_str(row.get::<&str, &str>("category")).unwrap(),
+            name: row.get::<&str, String>("name"),
+            content_type: row.get::<&str, String>("content_type"),
+            size: row.get::<&str, i64>("size"),
+            version: row.get::<&str, i32>("version"),
+            uploaded_by: row.get::<&str, Option<String>>("uploaded_by"),
+            created_at: row.get::<&str, SystemTime>("created_at").into(),
+            url: None,
+        }
+    }
+}
+
+pub struct AttachmentRepository {
+    client: Arc<Client>,
+}
+
+impl AttachmentRepository {
+    pub fn new(client: Arc<Client>) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl TAttachmentRepositories for AttachmentRepository {
+    async fn insert(
+        &self,
+        project_id: &str,
+        bid_id: Option<&str>,
+        file_id: Option<&str>,
+        uploaded_by: &str,
+        data: &UploadParams,
+    ) -> Result<Attachment, String> {
+        let statement = "
+            WITH new_id AS (SELECT uuid_generate_v4()::VARCHAR AS id)
+            INSERT INTO project_attachments (id, file_id, project_id, bid_id, category, name, content_type, size, version, uploaded_by)
+                SELECT id, COALESCE($2, id), $1, $8, $3, $4, $5, $6,
+                    COALESCE((SELECT MAX(version) FROM project_attachments WHERE file_id = $2), 0) + 1,
+                    $7
+                FROM new_id
+            RETURNING *";
+
+        let res = self
+            .client
+            .query_one(
+                statement,
+                &[
+                    &project_id,
+                    &file_id,
+                    &data.category,
+                    &data.name,
+                    &data.content_type,
+                    &(data.data.len() as i64),
+                    &uploaded_by,
+                    &bid_id,
+                ],
+            )
+            .await;
+
+        match res {
+            Ok(row) => Ok(Attachment::from_row(&row)),
+            Err(err) => match err.as_db_error() {
+                Some(err) => Err(err.message().to_string()),
+                None => Err(err.to_string()),
+            },
+        }
+    }
+
+    async fn find_by_id(&self, id: &str) -> Option<Attachment> {
+        let res = self
+            .client
+            .query_one("SELECT * FROM project_attachments WHERE id = $1;", &[&id])
+            .await;
+
+        match res {
+            Ok(row) => Some(Attachment::from_row(&row)),
+            Err(_) => None,
+        }
+    }
+
+    async fn find_by_project(&self, project_id: &str) -> Vec<Attachment> {
+        let statement = "
+            SELECT * FROM (
+                SELECT DISTINCT ON (file_id) * FROM project_attachments
+                    WHERE project_id = $1 AND bid_id IS NULL
+                    ORDER BY file_id, version DESC
+            ) AS a ORDER BY a.category, a.created_at;";
+
+        match self.client.query(statement, &[&project_id]).await {
+            Ok(rows) => rows.iter().map(Attachment::from_row).collect(),
+            Err(_) => vec![],
+        }
+    }
+
+    async fn find_versions(&self, project_id: &str, file_id: &str) -> Vec<Attachment> {
+        let res = self
+            .client
+            .query(
+                "SELECT * FROM project_attachments WHERE project_id = $1 AND file_id = $2 AND bid_id IS NULL ORDER BY version DESC;",
+                &[&project_id, &file_id],
+            )
+            .await;
+
+        match res {
+            Ok(rows) => rows.iter().map(Attachment::from_row).collect(),
+            Err(_) => vec![],
+        }
+    }
+
+    async fn delete(&self, id: &str) -> Result<bool, String> {
+        let res = self
+            .client
+            .execute("DELETE FROM project_attachments WHERE id = $1;", &[&id])
+            .await;
+
+        match res {
+            Ok(row) => Ok(row != 0),
+            Err(err) => match err.as_db_error() {
+                Some(err) => Err(err.message().to_string()),
+                None => Err(err.to_string()),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_support::{connect, project, user};
+
+    fn upload(category: &str, name: &str) -> UploadParams {
+        UploadParams {
+            category: category.to_string(),
+            name: name.to_string(),
+            content_type: "application/pdf".to_string(),
+            data: b"%PDF-1.4".to_vec(),
+        }
+    }
+
+    #[tokio::test]
+    async fn replacing_a_file_adds_a_version_and_lists_only_the_latest() {
+        let client = match connect().await {
+            Some(client) => client,
+            None => return,
+        };
+        let owner = user(&client, "Client", false).await;
+        let project_id = project(&client, &owner, "Active").await;
+        let repo = AttachmentRepository::new(client.clone());
+
+        let drawing = repo.insert(&project_id, None, None, &owner, &upload("Drawing", "floor-plan.pdf")).await.unwrap();
+        assert_eq!((drawing.file_id.as_str(), drawing.version), (drawing.id.as_str(), 1));
+        repo.insert(&project_id, None, None, &owner, &upload("Permit", "permit.pdf")).await.unwrap();
+
+        let revised = repo
+            .insert(&project_id, None, Some(&drawing.file_id), &owner, &upload("Drawing", "floor-plan-v2.pdf"))
+            .await
+            .unwrap();
+        assert_eq!((revised.file_id.as_str(), revised.version), (drawing.id.as_str(), 2));
+
+        let names: Vec<String> = repo.find_by_project(&project_id).await.into_iter().map(|a| a.name).collect();
+        assert_eq!(names, vec!["floor-plan-v2.pdf", "permit.pdf"]);
+        let versions: Vec<i32> = repo.find_versions(&project_id, &drawing.file_id).await.into_iter().map(|a| a.version).collect();
+        assert_eq!(versions, vec![2, 1]);
+    }
+}
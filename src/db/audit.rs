@@ -0,0 +1,81 @@
+use crate::app::{
+    entities::audit::{audit_action::AuditAction, AuditEntry},
+    traits::repositories::audit::TAuditRepositories,
+};
+use async_trait::async_trait;
+use serde_json::Value;
+use std::{sync::Arc, time::SystemTime};
+use tokio_postgres::{Client, Row};
+
+impl AuditEntry {
+    fn from_row(row: &Row) -> Self {
+        AuditEntry {
+            id: row.get::<&str, i32>("id"),
+            admin_id: row.get::<&str, Option<String>>("admin_id"),
+            target_user_id: row.get::<&str, String>("target_user_id"),
+            action: AuditAction::from_str(row.get::<&str, &str>("action")).unwrap(),
+            details: row.get::<&str, Value>("details"),
+            created_at: row.get::<&str, SystemTime>("created_at").into(),
+        }
+    }
+}
+
+// Appends the audit row to a mutation CTE so that both are committed or rolled back together
+pub(super) fn audit_insert(source: &str, admin_id: usize) -> String {
+    let (target_user_id, action, details) = (admin_id + 1, admin_id + 2, admin_id + 3);
+    format!("INSERT INTO admin_audit (admin_id, target_user_id, action, details)
+        SELECT ${admin_id}, ${target_user_id}, ${action}, ${details} FROM {source}")
+}
+
+pub struct AuditRepository {
+    client: Arc<Client>,
+}
+
+impl AuditRepository {
+    pub fn new(client: Arc<Client>) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl TAuditRepositories for AuditRepository {
+    async fn insert(
+        &self,
+        admin_id: &str,
+        target_user_id: &str,
+        action: AuditAction,
+        details: Value,
+    ) -> Result<i32, String> {
+        let statement = "
+            INSERT INTO admin_audit (admin_id, target_user_id, action, details)
+                VALUES ($1, $2, $3, $4) RETURNING id";
+
+        let res = self
+            .client
+            .query_one(statement, &[&admin_id, &target_user_id, &action.as_str(), &details])
+            .await;
+
+        match res {
+            Ok(row) => Ok(row.get::<&str, i32>("id")),
+            Err(err) => match err.as_db_error() {
+                Some(err) => Err(err.message().to_string()),
+                None => Err(err.to_string()),
+            },
+        }
+    }
+
+    async fn find_by_target(&self, target_user_id: &str) -> Vec<AuditEntry> {
+        let res = self
+            .client
+            .query(
+                "SELECT * FROM admin_audit WHERE target_user_id = $1 ORDER BY created_at DESC;",
+                &[&target_user_id],
+            )
+            .await;
+
+        match res {
+            Ok(rows) => rows.iter().map(AuditEntry::from_row).collect(),
+            Err(_) => vec![],
+        }
+    }
+}
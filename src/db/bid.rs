@@ -0,0 +1,401 @@
+use crate::app::{
+    entities::{
+        attachment::Attachment,
+        bid::{status::BidStatus, Bid},
+    },
+    services::bid::{CreateBidParams, UpdateBidParams},
+    traits::repositories::bid::TBidRepositories,
+};
+use async_trait::async_trait;
+use std::{sync::Arc, time::SystemTime};
+use tokio_postgres::{Client, Row};
+
+use super::from_row::base_user_from_row;
+
+const BID_FIELDS: &str = "b.*,
+    ve.email AS vendor_email,
+    v.id AS vendor_user_id,
+    v.first_name AS vendor_first_name,
+    v.last_name AS vendor_last_name,
+    v.company AS vendor_company,
+    v.created_at AS vendor_created_at,
+    v.type AS vendor_type,
+    v.is_compliant AS vendor_is_compliant";
+
+const BID_JOINS: &str = "FROM bids AS b
+    JOIN users AS v ON v.id = b.vendor_id
+    JOIN user_emails AS ve ON ve.user_id = v.id AND ve.is_primary = true";
+
+impl Bid {
+    fn from_row(row: &Row) -> Self {
+        Bid {
+            id: row.get::<&str, String>("id"),
+            project_id: row.get::<&str, String>("project_id"),
+            organization_id: row.get::<&str, Option<String>>("organization_id"),
+            price: row.get::<&str, i64>("price"),
+            duration_days: row.get::<&str, i32>("duration_days"),
+            start_date: row.get::<&str, SystemTime>("start_date").into(),
+            message: row.get::<&str, String>("message"),
+            status: BidStatus::from_str(row.get::<&str, &str>("status")).unwrap(),
+            created_at: row.get::<&str, SystemTime>("created_at").into(),
+            updated_at: row.get::<&str, SystemTime>("updated_at").into(),
+            awarded_at: row
+                .get::<&str, Option<SystemTime>>("awarded_at")
+                .map(|date| date.into()),
+            awarded_by: row.get::<&str, Option<String>>("awarded_by"),
+            award_note: row.get::<&str, Option<String>>("award_note"),
+            vendor: base_user_from_row(row, "vendor"),
+            attachments: vec![],
+        }
+    }
+}
+
+pub struct BidRepository {
+    client: Arc<Client>,
+}
+
+impl BidRepository {
+    pub fn new(client: Arc<Client>) -> Self {
+        Self { client }
+    }
+
+    async fn with_attachments(&self, mut bids: Vec<Bid>) -> Vec<Bid> {
+        let ids: Vec<&str> = bids.iter().map(|bid| bid.id.as_str()).collect();
+        if ids.is_empty() {
+            return bids;
+        }
+
+        let res = self
+            .client
+            .query(
+                "SELECT * FROM project_attachments WHERE bid_id = ANY($1) ORDER BY created_at;",
+                &[&ids],
+            )
+            .await;
+
+        let attachments: Vec<Attachment> = match res {
+            Ok(rows) => rows.iter().map(Attachment::from_row).collect(),
+            Err(_) => vec![],
+        };
+
+        for bid in bids.iter_mut() {
+            bid.attachments = attachments
+                .iter()
+                .filter(|attachment| attachment.bid_id.as_deref() == Some(bid.id.as_str()))
+                .cloned()
+                .collect();
+        }
+
+        bids
+    }
+}
+
+#[async_trait]
+impl TBidRepositories for BidRepository {
+    async fn insert(&self, project_id: &str, vendor_id: &str, data: &CreateBidParams) -> Result<String, String> {
+        let statement = "
+            INSERT INTO bids (project_id, vendor_id, organization_id, price, duration_days, start_date, message)
+                VALUES ($1, $2, $3, $4, $5, $6, $7)
+            RETURNING id";
+
+        let res = self
+            .client
+            .query_one(
+                statement,
+                &[
+                    &project_id,
+                    &vendor_id,
+                    &data.organization_id,
+                    &data.price,
+                    &data.duration_days,
+                    &data.start_date.naive_utc(),
+                    &data.message,
+                ],
+            )
+            .await;
+
+        match res {
+            Ok(row) => Ok(row.get::<&str, String>("id")),
+            Err(err) => match err.as_db_error() {
+                Some(err) if err.constraint() == Some("idx_bids_open") => {
+                    Err("You have already placed a bid on this project".to_string())
+                }
+                Some(err) if err.constraint() == Some("idx_bids_open_organization") => {
+                    Err("Your organization has already placed a bid on this project".to_string())
+                }
+                Some(err) => Err(err.message().to_string()),
+                None => Err(err.to_string()),
+            },
+        }
+    }
+
+    async fn find_by_id(&self, id: &str) -> Option<Bid> {
+        let statement = format!("SELECT {BID_FIELDS} {BID_JOINS} WHERE b.id = $1;");
+
+        match self.client.query_one(&statement, &[&id]).await {
+            Ok(row) => self.with_attachments(vec![Bid::from_row(&row)]).await.pop(),
+            Err(_) => None,
+        }
+    }
+
+    async fn find_by_project(&self, project_id: &str) -> Vec<Bid> {
+        let statement = format!("
+            SELECT {BID_FIELDS} {BID_JOINS}
+                WHERE b.project_id = $1
+                ORDER BY b.created_at;");
+
+        match self.client.query(&statement, &[&project_id]).await {
+            Ok(rows) => self.with_attachments(rows.iter().map(Bid::from_row).collect()).await,
+            Err(_) => vec![],
+        }
+    }
+
+    async fn find_by_vendor(&self, vendor_id: &str) -> Vec<Bid> {
+        let statement = format!("
+            SELECT {BID_FIELDS} {BID_JOINS}
+                JOIN project AS p ON p.id = b.project_id AND p.deleted_at IS NULL
+                WHERE b.vendor_id = $1
+                    OR b.organization_id IN (
+                        SELECT organization_id FROM organization_members WHERE user_id = $1
+                    )
+                ORDER BY b.created_at DESC;");
+
+        match self.client.query(&statement, &[&vendor_id]).await {
+            Ok(rows) => self.with_attachments(rows.iter().map(Bid::from_row).collect()).await,
+            Err(_) => vec![],
+        }
+    }
+
+    async fn update(&self, id: &str, data: &UpdateBidParams) -> Result<bool, String> {
+        let statement = "
+            UPDATE bids SET
+                price = COALESCE($2, price),
+                duration_days = COALESCE($3, duration_days),
+                start_date = COALESCE($4, start_date),
+                message = COALESCE($5, message),
+                updated_at = NOW()
+            WHERE id = $1 AND status IN ('Submitted', 'Shortlisted');";
+
+        let res = self
+            .client
+            .execute(
+                statement,
+                &[
+                    &id,
+                    &data.price,
+                    &data.duration_days,
+                    &data.start_date.map(|d| d.naive_utc()),
+                    &data.message,
+                ],
+            )
+            .await;
+
+        match res {
+            Ok(row) => Ok(row != 0),
+            Err(err) => match err.as_db_error() {
+                Some(err) => Err(err.message().to_string()),
+                None => Err(err.to_string()),
+            },
+        }
+    }
+
+    async fn set_status(&self, id: &str, from: &BidStatus, to: &BidStatus) -> Result<bool, String> {
+        let res = self
+            .client
+            .execute(
+                "UPDATE bids SET status = $3, updated_at = NOW() WHERE id = $1 AND status = $2;",
+                &[&id, &from.as_str(), &to.as_str()],
+            )
+            .await;
+
+        match res {
+            Ok(row) => Ok(row != 0),
+            Err(err) => match err.as_db_error() {
+                Some(err) => Err(err.message().to_string()),
+                None => Err(err.to_string()),
+            },
+        }
+    }
+
+    async fn award(&self, id: &str, project_id: &str, vendor_id: &str, actor_id: &str, note: Option<&str>) -> Result<bool, String> {
+        let statement = "
+            WITH started AS (
+                UPDATE project SET vendor_id = $3, status = 'Ongoing', updated_at = NOW()
+                    WHERE id = $2 AND status = 'Active' AND deleted_at IS NULL AND EXISTS (
+                        SELECT 1 FROM bids
+                            WHERE id = $1 AND project_id = $2 AND status IN ('Submitted', 'Shortlisted')
+                    )
+                RETURNING id
+            ), awarded AS (
+                UPDATE bids SET status = 'Awarded', awarded_at = NOW(), awarded_by = $4, award_note = $5, updated_at = NOW()
+                    WHERE id = $1 AND EXISTS (SELECT 1 FROM started)
+                RETURNING id
+            ), declined AS (
+                UPDATE bids SET status = 'Declined', updated_at = NOW()
+                    WHERE project_id = $2 AND id <> $1 AND status IN ('Submitted', 'Shortlisted')
+                        AND EXISTS (SELECT 1 FROM started)
+                RETURNING id
+            ), changed AS (
+                INSERT INTO project_changes (project_id, field, old_value, new_value, actor_id)
+                    SELECT id, 'vendorId', 'null', to_jsonb($3::VARCHAR), $4 FROM started
+            )
+            INSERT INTO project_status_history (project_id, from_status, to_status, actor_id, reason)
+                SELECT id, 'Active', 'Ongoing', $4, 'Bid awarded' FROM started;";
+
+        let res = self
+            .client
+            .execute(statement, &[&id, &project_id, &vendor_id, &actor_id, &note])
+            .await;
+
+        match res {
+            Ok(row) => Ok(row != 0),
+            Err(err) => match err.as_db_error() {
+                Some(err) => Err(err.message().to_string()),
+                None => Err(err.to_string()),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, Utc};
+
+    use super::*;
+    use crate::db::test_support::{bid, connect, project, user};
+
+    fn revision() -> UpdateBidParams {
+        UpdateBidParams {
+            price: Some(90000),
+            duration_days: None,
+            start_date: None,
+            message: None,
+        }
+    }
+
+    fn proposal() -> CreateBidParams {
+        CreateBidParams {
+            price: 120000,
+            duration_days: 45,
+            start_date: Utc::now() + Duration::days(14),
+            message: "We can start two weeks from now".to_string(),
+            organization_id: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn award_starts_the_project_and_declines_competing_bids() {
+        let client = match connect().await {
+            Some(client) => client,
+            None => return,
+        };
+        let owner = user(&client, "Client", false).await;
+        let vendor = user(&client, "Vendor", true).await;
+        let competitor = user(&client, "Vendor", true).await;
+        let project_id = project(&client, &owner, "Active").await;
+        let winning = bid(&client, &project_id, &vendor).await;
+        let losing = bid(&client, &project_id, &competitor).await;
+        let repo = BidRepository::new(client.clone());
+
+        assert_eq!(repo.award(&winning, &project_id, &vendor, &owner, Some("Best offer")).await, Ok(true));
+
+        let row = client
+            .query_one("SELECT status, vendor_id FROM project WHERE id = $1;", &[&project_id])
+            .await
+            .unwrap();
+        assert_eq!(row.get::<&str, String>("status"), "Ongoing");
+        assert_eq!(row.get::<&str, Option<String>>("vendor_id"), Some(vendor.clone()));
+        assert_eq!(repo.find_by_id(&winning).await.unwrap().status, BidStatus::Awarded);
+        assert_eq!(repo.find_by_id(&losing).await.unwrap().status, BidStatus::Declined);
+
+        let row = client
+            .query_one(
+                "SELECT
+                    (SELECT COUNT(*) FROM project_status_history WHERE project_id = $1) AS transitions,
+                    (SELECT COUNT(*) FROM project_changes WHERE project_id = $1 AND field = 'vendorId') AS changes;",
+                &[&project_id],
+            )
+            .await
+            .unwrap();
+        assert_eq!(row.get::<&str, i64>("transitions"), 1);
+        assert_eq!(row.get::<&str, i64>("changes"), 1);
+    }
+
+    #[tokio::test]
+    async fn award_is_applied_only_once() {
+        let client = match connect().await {
+            Some(client) => client,
+            None => return,
+        };
+        let owner = user(&client, "Client", false).await;
+        let vendor = user(&client, "Vendor", true).await;
+        let competitor = user(&client, "Vendor", true).await;
+        let project_id = project(&client, &owner, "Active").await;
+        let first = bid(&client, &project_id, &vendor).await;
+        let second = bid(&client, &project_id, &competitor).await;
+        let repo = BidRepository::new(client.clone());
+
+        assert_eq!(repo.award(&first, &project_id, &vendor, &owner, None).await, Ok(true));
+        assert_eq!(repo.award(&second, &project_id, &competitor, &owner, None).await, Ok(false));
+        assert_eq!(repo.find_by_id(&second).await.unwrap().status, BidStatus::Declined);
+    }
+
+    #[tokio::test]
+    async fn bids_can_be_revised_and_withdrawn_only_while_open() {
+        let client = match connect().await {
+            Some(client) => client,
+            None => return,
+        };
+        let owner = user(&client, "Client", false).await;
+        let vendor = user(&client, "Vendor", true).await;
+        let project_id = project(&client, &owner, "Active").await;
+        let id = bid(&client, &project_id, &vendor).await;
+        let repo = BidRepository::new(client.clone());
+
+        assert_eq!(repo.update(&id, &revision()).await, Ok(true));
+        assert_eq!(repo.find_by_id(&id).await.unwrap().price, 90000);
+        assert_eq!(repo.set_status(&id, &BidStatus::Submitted, &BidStatus::Withdrawn).await, Ok(true));
+
+        assert_eq!(repo.update(&id, &revision()).await, Ok(false));
+        assert_eq!(repo.set_status(&id, &BidStatus::Submitted, &BidStatus::Withdrawn).await, Ok(false));
+        assert_eq!(repo.find_by_id(&id).await.unwrap().status, BidStatus::Withdrawn);
+    }
+
+    #[tokio::test]
+    async fn vendors_bid_again_only_after_withdrawing() {
+        let client = match connect().await {
+            Some(client) => client,
+            None => return,
+        };
+        let owner = user(&client, "Client", false).await;
+        let vendor = user(&client, "Vendor", true).await;
+        let project_id = project(&client, &owner, "Active").await;
+        let repo = BidRepository::new(client.clone());
+
+        let first = repo.insert(&project_id, &vendor, &proposal()).await.unwrap();
+        assert_eq!(
+            repo.insert(&project_id, &vendor, &proposal()).await,
+            Err("You have already placed a bid on this project".to_string())
+        );
+
+        assert_eq!(repo.set_status(&first, &BidStatus::Submitted, &BidStatus::Withdrawn).await, Ok(true));
+        assert!(repo.insert(&project_id, &vendor, &proposal()).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn awarded_bids_can_no_longer_be_revised_or_withdrawn() {
+        let client = match connect().await {
+            Some(client) => client,
+            None => return,
+        };
+        let owner = user(&client, "Client", false).await;
+        let vendor = user(&client, "Vendor", true).await;
+        let project_id = project(&client, &owner, "Active").await;
+        let id = bid(&client, &project_id, &vendor).await;
+        let repo = BidRepository::new(client.clone());
+
+        assert_eq!(repo.award(&id, &project_id, &vendor, &owner, None).await, Ok(true));
+        assert_eq!(repo.update(&id, &revision()).await, Ok(false));
+        assert_eq!(repo.set_status(&id, &BidStatus::Submitted, &BidStatus::Withdrawn).await, Ok(false));
+    }
+}
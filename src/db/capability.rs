@@ -0,0 +1,152 @@
+use crate::app::{
+    entities::{
+        capability::{VendorCandidate, VendorCapabilities},
+        location::GeoPoint,
+        project::{
+            architectural_service::ArchitecturalServices, budget_range::BudgetRange,
+            commercial_work::CommercialWork, square_range::SquareRange,
+        },
+    },
+    services::matching::UpdateCapabilitiesParams,
+    traits::repositories::capability::TCapabilityRepositories,
+};
+use async_trait::async_trait;
+use std::{sync::Arc, time::SystemTime};
+use tokio_postgres::{Client, Row};
+
+use super::{from_row::base_user_from_row, gazetteer::distance};
+
+const VENDOR_FIELDS: &str = "ve.email AS vendor_email,
+    v.id AS vendor_user_id,
+    v.first_name AS vendor_first_name,
+    v.last_name AS vendor_last_name,
+    v.company AS vendor_company,
+    v.created_at AS vendor_created_at,
+    v.type AS vendor_type,
+    v.is_compliant AS vendor_is_compliant";
+
+impl VendorCapabilities {
+    fn from_row(row: &Row) -> Self {
+        VendorCapabilities {
+            user_id: row.get::<&str, String>("user_id"),
+            commercial_works: row
+                .get::<&str, Vec<&str>>("commercial_works")
+                .into_iter()
+                .filter_map(|v| CommercialWork::from_str(v).ok())
+                .collect(),
+            architectural_services: row
+                .get::<&str, Vec<&str>>("architectural_services")
+                .into_iter()
+                .filter_map(|v| ArchitecturalServices::from_str(v).ok())
+                .collect(),
+            budget_ranges: row
+                .get::<&str, Vec<&str>>("budget_ranges")
+                .into_iter()
+                .filter_map(|v| BudgetRange::from_str(v).ok())
+                .collect(),
+            square_ranges: row
+                .get::<&str, Vec<&str>>("square_ranges")
+                .into_iter()
+                .filter_map(|v| SquareRange::from_str(v).ok())
+                .collect(),
+            is_save_carbon: row.get::<&str, bool>("is_save_carbon"),
+            service_cities: row.get::<&str, Vec<String>>("service_cities"),
+            service_radius: row.get::<&str, Option<i32>>("service_radius"),
+            updated_at: row.get::<&str, SystemTime>("updated_at").into(),
+        }
+    }
+}
+
+pub struct CapabilityRepository {
+    client: Arc<Client>,
+}
+
+impl CapabilityRepository {
+    pub fn new(client: Arc<Client>) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl TCapabilityRepositories for CapabilityRepository {
+    async fn upsert(&self, user_id: &str, data: &UpdateCapabilitiesParams) -> Result<(), String> {
+        let statement = "
+            INSERT INTO vendor_capabilities (
+                user_id, commercial_works, architectural_services, budget_ranges, square_ranges,
+                is_save_carbon, service_cities, service_radius
+            )
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            ON CONFLICT (user_id) DO UPDATE SET
+                commercial_works = EXCLUDED.commercial_works,
+                architectural_services = EXCLUDED.architectural_services,
+                budget_ranges = EXCLUDED.budget_ranges,
+                square_ranges = EXCLUDED.square_ranges,
+                is_save_carbon = EXCLUDED.is_save_carbon,
+                service_cities = EXCLUDED.service_cities,
+                service_radius = EXCLUDED.service_radius,
+                updated_at = NOW();";
+
+        let res = self
+            .client
+            .execute(
+                statement,
+                &[
+                    &user_id,
+                    &data.commercial_works,
+                    &data.architectural_services,
+                    &data.budget_ranges,
+                    &data.square_ranges,
+                    &data.is_save_carbon,
+                    &data.service_cities,
+                    &data.service_radius,
+                ],
+            )
+            .await;
+
+        match res {
+            Ok(_) => Ok(()),
+            Err(err) => match err.as_db_error() {
+                Some(err) => Err(err.message().to_string()),
+                None => Err(err.to_string()),
+            },
+        }
+    }
+
+    async fn find_by_user(&self, user_id: &str) -> Option<VendorCapabilities> {
+        match self
+            .client
+            .query_one("SELECT * FROM vendor_capabilities WHERE user_id = $1;", &[&user_id])
+            .await
+        {
+            Ok(row) => Some(VendorCapabilities::from_row(&row)),
+            Err(_) => None,
+        }
+    }
+
+    async fn find_candidates(&self, origin: Option<&GeoPoint>) -> Vec<VendorCandidate> {
+        let statement = format!(
+            "SELECT c.*, {VENDOR_FIELDS}, {} AS distance
+                FROM vendor_capabilities AS c
+                    JOIN users AS v ON v.id = c.user_id AND v.type = 'Vendor' AND v.is_compliant = true
+                    JOIN user_emails AS ve ON ve.user_id = v.id AND ve.is_primary = true;",
+            distance("v.latitude", "v.longitude", 1, 2)
+        );
+
+        let res = self
+            .client
+            .query(&statement, &[&origin.map(|o| o.latitude), &origin.map(|o| o.longitude)])
+            .await;
+
+        match res {
+            Ok(rows) => rows
+                .iter()
+                .map(|row| VendorCandidate {
+                    vendor: base_user_from_row(row, "vendor"),
+                    capabilities: VendorCapabilities::from_row(row),
+                    distance: row.get::<&str, Option<f64>>("distance"),
+                })
+                .collect(),
+            Err(_) => vec![],
+        }
+    }
+}
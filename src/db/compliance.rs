@@ -0,0 +1,249 @@
+use crate::app::{
+    entities::compliance::{document_type::DocumentType, ComplianceDocument},
+    services::compliance::UpsertDocumentParams,
+    traits::repositories::compliance::TComplianceRepositories,
+};
+use async_trait::async_trait;
+use std::{sync::Arc, time::SystemTime};
+use tokio_postgres::{Client, Row};
+
+use super::from_row::base_user_from_row;
+
+const VENDOR_FIELDS: &str = "e.email AS vendor_email,
+    u.id AS vendor_user_id,
+    u.first_name AS vendor_first_name,
+    u.last_name AS vendor_last_name,
+    u.company AS vendor_company,
+    u.created_at AS vendor_created_at,
+    u.type AS vendor_type,
+    u.is_compliant AS vendor_is_compliant";
+
+impl ComplianceDocument {
+    fn from_row(row: &Row) -> Self {
+        ComplianceDocument {
+            id: row.get::<&str, i32>("id"),
+            doc_type: DocumentType::from_str(row.get::<&str, &str>("type")).unwrap(),
+            number: row.get::<&str, Option<String>>("number"),
+            issued_at: row.get::<&str, SystemTime>("issued_at").into(),
+            expires_at: row.get::<&str, SystemTime>("expires_at").into(),
+            created_at: row.get::<&str, SystemTime>("created_at").into(),
+            vendor: base_user_from_row(row, "vendor"),
+        }
+    }
+}
+
+pub struct ComplianceRepository {
+    client: Arc<Client>,
+}
+
+impl ComplianceRepository {
+    pub fn new(client: Arc<Client>) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl TComplianceRepositories for ComplianceRepository {
+    async fn upsert(&self, user_id: &str, data: &UpsertDocumentParams) -> Result<i32, String> {
+        let statement = "
+            INSERT INTO compliance_documents (user_id, type, number, issued_at, expires_at)
+                VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (user_id, type) DO UPDATE SET
+                number = EXCLUDED.number,
+                issued_at = EXCLUDED.issued_at,
+                expires_at = EXCLUDED.expires_at,
+                reminded_days = NULL,
+                updated_at = NOW()
+            RETURNING id";
+
+        let res = self
+            .client
+            .query_one(
+                statement,
+                &[
+                    &user_id,
+                    &data.doc_type,
+                    &data.number,
+                    &data.issued_at.naive_utc(),
+                    &data.expires_at.naive_utc(),
+                ],
+            )
+            .await;
+
+        match res {
+            Ok(row) => Ok(row.get::<&str, i32>("id")),
+            Err(err) => match err.as_db_error() {
+                Some(err) => Err(err.message().to_string()),
+                None => Err(err.to_string()),
+            },
+        }
+    }
+
+    async fn find_by_user(&self, user_id: &str) -> Vec<ComplianceDocument> {
+        let statement = format!("
+            SELECT d.*, {VENDOR_FIELDS}
+                FROM compliance_documents AS d
+                    JOIN users AS u ON u.id = d.user_id AND d.user_id = $1
+                    JOIN user_emails AS e ON e.user_id = u.id AND e.is_primary = true
+                ORDER BY d.type;");
+
+        let res = self.client.query(&statement, &[&user_id]).await;
+        match res {
+            Ok(rows) => rows.iter().map(ComplianceDocument::from_row).collect(),
+            Err(_) => vec![],
+        }
+    }
+
+    async fn find_for_reminder(&self, days: i32) -> Vec<ComplianceDocument> {
+        let statement = format!("
+            SELECT d.*, {VENDOR_FIELDS}
+                FROM compliance_documents AS d
+                    JOIN users AS u ON u.id = d.user_id AND u.type = 'Vendor'
+                    JOIN user_emails AS e ON e.user_id = u.id AND e.is_primary = true
+                WHERE d.expires_at > NOW()
+                    AND d.expires_at <= NOW() + make_interval(days => $1)
+                    AND (d.reminded_days IS NULL OR d.reminded_days > $1);");
+
+        let res = self.client.query(&statement, &[&days]).await;
+        match res {
+            Ok(rows) => rows.iter().map(ComplianceDocument::from_row).collect(),
+            Err(_) => vec![],
+        }
+    }
+
+    async fn set_reminded(&self, id: i32, days: i32) -> Result<bool, String> {
+        let statement = "
+            UPDATE compliance_documents SET reminded_days = $2
+                WHERE id = $1 AND (reminded_days IS NULL OR reminded_days > $2);";
+        let res = self.client.execute(statement, &[&id, &days]).await;
+
+        match res {
+            Ok(row) => Ok(row != 0),
+            Err(err) => match err.as_db_error() {
+                Some(err) => Err(err.message().to_string()),
+                None => Err(err.to_string()),
+            },
+        }
+    }
+
+    async fn refresh_compliance(&self, user_id: &str, required: Vec<DocumentType>) -> Result<bool, String> {
+        let required: Vec<&str> = required.iter().map(|t| t.as_str()).collect();
+        let statement = "
+            UPDATE users SET is_compliant = (
+                SELECT COUNT(DISTINCT d.type) = cardinality($2::VARCHAR[])
+                    FROM compliance_documents AS d
+                    WHERE d.user_id = $1 AND d.type = ANY($2) AND d.expires_at > NOW()
+            ) WHERE id = $1 RETURNING is_compliant;";
+
+        let res = self.client.query_one(statement, &[&user_id, &required]).await;
+
+        match res {
+            Ok(row) => Ok(row.get::<&str, bool>("is_compliant")),
+            Err(err) => match err.as_db_error() {
+                Some(err) => Err(err.message().to_string()),
+                None => Err(err.to_string()),
+            },
+        }
+    }
+
+    async fn set_expired_non_compliant(&self) -> Result<Vec<String>, String> {
+        let statement = "
+            UPDATE users AS u SET is_compliant = false
+                WHERE u.is_compliant = true AND u.type = 'Vendor' AND EXISTS (
+                    SELECT 1 FROM compliance_documents AS d
+                        WHERE d.user_id = u.id AND d.expires_at <= NOW()
+                )
+            RETURNING u.id;";
+
+        let res = self.client.query(statement, &[]).await;
+
+        match res {
+            Ok(rows) => Ok(rows.iter().map(|row| row.get::<&str, String>("id")).collect()),
+            Err(err) => match err.as_db_error() {
+                Some(err) => Err(err.message().to_string()),
+                None => Err(err.to_string()),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, Utc};
+
+    use super::*;
+    use crate::db::test_support::{connect, user};
+
+    fn document(doc_type: &str, expires_in_days: i64) -> UpsertDocumentParams {
+        UpsertDocumentParams {
+            doc_type: doc_type.to_string(),
+            number: None,
+            issued_at: Utc::now() - Duration::days(365),
+            expires_at: Utc::now() + Duration::days(expires_in_days),
+        }
+    }
+
+    fn reminded(documents: &[ComplianceDocument], id: i32) -> bool {
+        documents.iter().any(|d| d.id == id)
+    }
+
+    #[tokio::test]
+    async fn reminders_are_claimed_once_per_threshold() {
+        let client = match connect().await {
+            Some(client) => client,
+            None => return,
+        };
+        let vendor = user(&client, "Vendor", true).await;
+        let repo = ComplianceRepository::new(client.clone());
+        let id = repo.upsert(&vendor, &document("License", 5)).await.unwrap();
+
+        assert!(reminded(&repo.find_for_reminder(30).await, id));
+        assert!(reminded(&repo.find_for_reminder(7).await, id));
+        assert!(!reminded(&repo.find_for_reminder(1).await, id));
+
+        assert_eq!(repo.set_reminded(id, 7).await, Ok(true));
+        assert_eq!(repo.set_reminded(id, 7).await, Ok(false));
+        assert_eq!(repo.set_reminded(id, 30).await, Ok(false));
+        assert!(!reminded(&repo.find_for_reminder(30).await, id));
+        assert!(!reminded(&repo.find_for_reminder(7).await, id));
+        assert_eq!(repo.set_reminded(id, 1).await, Ok(true));
+    }
+
+    #[tokio::test]
+    async fn renewing_a_document_resets_its_reminders() {
+        let client = match connect().await {
+            Some(client) => client,
+            None => return,
+        };
+        let vendor = user(&client, "Vendor", true).await;
+        let repo = ComplianceRepository::new(client.clone());
+        let id = repo.upsert(&vendor, &document("Insurance", 5)).await.unwrap();
+
+        assert_eq!(repo.set_reminded(id, 7).await, Ok(true));
+        assert_eq!(repo.upsert(&vendor, &document("Insurance", 20)).await, Ok(id));
+        assert!(reminded(&repo.find_for_reminder(30).await, id));
+    }
+
+    #[tokio::test]
+    async fn expired_documents_make_vendors_non_compliant() {
+        let client = match connect().await {
+            Some(client) => client,
+            None => return,
+        };
+        let vendor = user(&client, "Vendor", false).await;
+        let repo = ComplianceRepository::new(client.clone());
+        repo.upsert(&vendor, &document("License", 30)).await.unwrap();
+
+        assert_eq!(repo.refresh_compliance(&vendor, DocumentType::required()).await, Ok(false));
+
+        repo.upsert(&vendor, &document("Insurance", 30)).await.unwrap();
+        assert_eq!(repo.refresh_compliance(&vendor, DocumentType::required()).await, Ok(true));
+
+        client
+            .execute("UPDATE compliance_documents SET expires_at = NOW() - INTERVAL '1 day' WHERE user_id = $1 AND type = 'License';", &[&vendor])
+            .await
+            .unwrap();
+        assert!(repo.set_expired_non_compliant().await.unwrap().contains(&vendor));
+        assert!(!repo.set_expired_non_compliant().await.unwrap().contains(&vendor));
+    }
+}
@@ -1,6 +1,6 @@
 use std::time::SystemTime;
 use tokio_postgres::Row;
-use crate::app::entities::user::{user_type::UserType, User};
+use crate::app::entities::user::{user_profile::UserProfile, user_type::UserType, User};
 
 pub fn base_user_from_row(row: &Row, key: &str) -> User {
     User {
@@ -8,15 +8,40 @@ pub fn base_user_from_row(row: &Row, key: &str) -> User {
         email: row.get::<&str, String>(format!("{key}_email").as_str()),
         first_name: row.get::<&str, String>(format!("{key}_first_name").as_str()),
         last_name: row.get::<&str, String>(format!("{key}_last_name").as_str()),
+        company: row.get::<&str, Option<String>>(format!("{key}_company").as_str()),
+        phone: None,
         password_alg: String::from(""),
         password_hash: String::from(""),
         u_type: UserType::from_str(
             row.get::<&str, String>(format!("{key}_type").as_str())
                 .as_str(),
         ),
+        is_compliant: row.get::<&str, bool>(format!("{key}_is_compliant").as_str()),
         created_at: row
             .get::<&str, SystemTime>(format!("{key}_created_at").as_str())
             .into(),
         tokens: vec![],
     }
-}
\ No newline at end of file
+}
+
+pub fn profile_from_row(row: &Row, key: &str) -> UserProfile {
+    UserProfile {
+        id: row.get::<&str, String>(format!("{key}_user_id").as_str()),
+        first_name: row.get::<&str, String>(format!("{key}_first_name").as_str()),
+        last_name: row.get::<&str, String>(format!("{key}_last_name").as_str()),
+        u_type: UserType::from_str(
+            row.get::<&str, String>(format!("{key}_type").as_str())
+                .as_str(),
+        ),
+        created_at: row
+            .get::<&str, SystemTime>(format!("{key}_created_at").as_str())
+            .into(),
+        company: row.get::<&str, Option<String>>(format!("{key}_company").as_str()),
+        is_compliant: None,
+        completed_projects: None,
+        rating: None,
+        location: None,
+        email: None,
+        phone: None,
+    }
+}
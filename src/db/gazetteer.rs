@@ -0,0 +1,94 @@
+use crate::app::{
+    entities::location::GazetteerEntry, traits::repositories::gazetteer::TGazetteerRepositories,
+};
+use async_trait::async_trait;
+use std::sync::Arc;
+use tokio_postgres::Client;
+
+pub(super) fn country() -> Option<String> {
+    std::env::var("GAZETTEER_COUNTRY")
+        .ok()
+        .map(|c| c.trim().to_uppercase())
+        .filter(|c| c.len() == 2)
+}
+
+// Takes the first non-empty match of zip + city, zip, then city, within the country when one is set.
+// A match spread across countries or over more than ~50 km is ambiguous and yields no point.
+pub(super) fn locate(zip_code: &str, city: &str, country: &str) -> String {
+    let candidates = |condition: String, rank: i32| format!("
+        SELECT AVG(g.latitude) AS latitude, AVG(g.longitude) AS longitude, {rank} AS rank,
+          COUNT(DISTINCT g.country_code) = 1
+            AND MAX(g.latitude) - MIN(g.latitude) <= 0.5
+            AND MAX(g.longitude) - MIN(g.longitude) <= 0.5 AS is_unique
+          FROM gazetteer AS g
+          WHERE {condition} AND ({country}::VARCHAR IS NULL OR g.country_code = {country}::VARCHAR)");
+
+    format!("(
+      SELECT
+        CASE WHEN c.is_unique THEN c.latitude END,
+        CASE WHEN c.is_unique THEN c.longitude END
+      FROM ({} UNION ALL {} UNION ALL {}) AS c
+        WHERE c.latitude IS NOT NULL
+        ORDER BY c.rank
+        LIMIT 1
+    )",
+        candidates(format!("g.zip_code = {zip_code} AND lower(g.city) = lower({city})"), 0),
+        candidates(format!("g.zip_code = {zip_code}"), 1),
+        candidates(format!("lower(g.city) = lower({city})"), 2),
+    )
+}
+
+pub(super) fn distance(latitude: &str, longitude: &str, origin_latitude: usize, origin_longitude: usize) -> String {
+    format!("(6371 * 2 * ASIN(SQRT(
+      POWER(SIN(RADIANS({latitude} - ${origin_latitude}::FLOAT8) / 2), 2)
+        + COS(RADIANS(${origin_latitude}::FLOAT8)) * COS(RADIANS({latitude}))
+        * POWER(SIN(RADIANS({longitude} - ${origin_longitude}::FLOAT8) / 2), 2)
+    )))")
+}
+
+pub struct GazetteerRepository {
+    client: Arc<Client>,
+}
+
+impl GazetteerRepository {
+    pub fn new(client: Arc<Client>) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl TGazetteerRepositories for GazetteerRepository {
+    async fn upsert(&self, entries: &[GazetteerEntry]) -> Result<u64, String> {
+        let statement = "
+            INSERT INTO gazetteer (country_code, zip_code, city, latitude, longitude)
+                SELECT * FROM UNNEST($1::VARCHAR[], $2::VARCHAR[], $3::VARCHAR[], $4::FLOAT8[], $5::FLOAT8[])
+            ON CONFLICT ON CONSTRAINT uq_gazetteer_place
+                DO UPDATE SET latitude = EXCLUDED.latitude, longitude = EXCLUDED.longitude;";
+
+        let country_codes: Vec<&str> = entries.iter().map(|e| e.country_code.as_str()).collect();
+        let zip_codes: Vec<&str> = entries.iter().map(|e| e.zip_code.as_str()).collect();
+        let cities: Vec<&str> = entries.iter().map(|e| e.city.as_str()).collect();
+        let latitudes: Vec<f64> = entries.iter().map(|e| e.latitude).collect();
+        let longitudes: Vec<f64> = entries.iter().map(|e| e.longitude).collect();
+
+        let res = self
+            .client
+            .execute(statement, &[&country_codes, &zip_codes, &cities, &latitudes, &longitudes])
+            .await;
+
+        match res {
+            Ok(rows) => Ok(rows),
+            Err(err) => match err.as_db_error() {
+                Some(err) => Err(err.message().to_string()),
+                None => Err(err.to_string()),
+            },
+        }
+    }
+
+    async fn count(&self) -> i64 {
+        match self.client.query_one("SELECT COUNT(*) AS total FROM gazetteer;", &[]).await {
+            Ok(row) => row.get::<&str, i64>("total"),
+            Err(_) => 0,
+        }
+    }
+}
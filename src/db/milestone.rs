@@ -0,0 +1,389 @@
+use crate::app::{
+    entities::milestone::{owner::MilestoneOwner, status::MilestoneStatus, Milestone, MilestoneTask},
+    services::milestone::{CreateMilestoneParams, UpdateMilestoneParams, UpdateTaskParams},
+    traits::repositories::milestone::TMilestoneRepositories,
+};
+use async_trait::async_trait;
+use std::{sync::Arc, time::SystemTime};
+use tokio_postgres::{Client, Row};
+
+impl Milestone {
+    fn from_row(row: &Row) -> Self {
+        Milestone {
+            id: row.get::<&str, String>("id"),
+            project_id: row.get::<&str, String>("project_id"),
+            title: row.get::<&str, String>("title"),
+            due_date: row.get::<&str, SystemTime>("due_date").into(),
+            owner: MilestoneOwner::from_str(row.get::<&str, &str>("owner")).unwrap(),
+            status: MilestoneStatus::from_str(row.get::<&str, &str>("status")).unwrap(),
+            position: row.get::<&str, i32>("position"),
+            completed_at: row
+                .get::<&str, Option<SystemTime>>("completed_at")
+                .map(|date| date.into()),
+            created_at: row.get::<&str, SystemTime>("created_at").into(),
+            tasks: vec![],
+        }
+    }
+}
+
+impl MilestoneTask {
+    fn from_row(row: &Row) -> Self {
+        MilestoneTask {
+            id: row.get::<&str, i32>("id"),
+            milestone_id: row.get::<&str, String>("milestone_id"),
+            title: row.get::<&str, String>("title"),
+            position: row.get::<&str, i32>("position"),
+            is_done: row.get::<&str, bool>("is_done"),
+            completed_at: row
+                .get::<&str, Option<SystemTime>>("completed_at")
+                .map(|date| date.into()),
+        }
+    }
+}
+
+pub struct MilestoneRepository {
+    client: Arc<Client>,
+}
+
+impl MilestoneRepository {
+    pub fn new(client: Arc<Client>) -> Self {
+        Self { client }
+    }
+
+    async fn with_tasks(&self, mut milestones: Vec<Milestone>) -> Vec<Milestone> {
+        let ids: Vec<&str> = milestones.iter().map(|milestone| milestone.id.as_str()).collect();
+        if ids.is_empty() {
+            return milestones;
+        }
+
+        let res = self
+            .client
+            .query(
+                "SELECT * FROM milestone_tasks WHERE milestone_id = ANY($1) ORDER BY position, id;",
+                &[&ids],
+            )
+            .await;
+
+        let tasks: Vec<MilestoneTask> = match res {
+            Ok(rows) => rows.iter().map(MilestoneTask::from_row).collect(),
+            Err(_) => vec![],
+        };
+
+        for milestone in milestones.iter_mut() {
+            milestone.tasks = tasks
+                .iter()
+                .filter(|task| task.milestone_id == milestone.id)
+                .cloned()
+                .collect();
+        }
+
+        milestones
+    }
+}
+
+#[async_trait]
+impl TMilestoneRepositories for MilestoneRepository {
+    async fn insert(&self, project_id: &str, data: &CreateMilestoneParams) -> Result<String, String> {
+        let statement = "
+            WITH milestone AS (
+                INSERT INTO milestones (project_id, title, due_date, owner, position)
+                    VALUES ($1, $2, $3, $4, COALESCE((SELECT MAX(position) FROM milestones WHERE project_id = $1::VARCHAR), 0) + 1)
+                RETURNING id
+            ), tasks AS (
+                INSERT INTO milestone_tasks (milestone_id, title, position)
+                    SELECT milestone.id, t.title, t.position
+                        FROM milestone, unnest($5::VARCHAR[]) WITH ORDINALITY AS t(title, position)
+            )
+            SELECT id FROM milestone;";
+
+        let tasks = data.tasks.clone().unwrap_or_default();
+        let res = self
+            .client
+            .query_one(
+                statement,
+                &[
+                    &project_id,
+                    &data.title,
+                    &data.due_date.naive_utc(),
+                    &data.owner,
+                    &tasks,
+                ],
+            )
+            .await;
+
+        match res {
+            Ok(row) => Ok(row.get::<&str, String>("id")),
+            Err(err) => match err.as_db_error() {
+                Some(err) => Err(err.message().to_string()),
+                None => Err(err.to_string()),
+            },
+        }
+    }
+
+    async fn find_by_id(&self, id: &str) -> Option<Milestone> {
+        match self.client.query_one("SELECT * FROM milestones WHERE id = $1;", &[&id]).await {
+            Ok(row) => self.with_tasks(vec![Milestone::from_row(&row)]).await.pop(),
+            Err(_) => None,
+        }
+    }
+
+    async fn find_by_project(&self, project_id: &str) -> Vec<Milestone> {
+        let res = self
+            .client
+            .query(
+                "SELECT * FROM milestones WHERE project_id = $1 ORDER BY position, created_at;",
+                &[&project_id],
+            )
+            .await;
+
+        match res {
+            Ok(rows) => self.with_tasks(rows.iter().map(Milestone::from_row).collect()).await,
+            Err(_) => vec![],
+        }
+    }
+
+    async fn update(&self, id: &str, data: &UpdateMilestoneParams) -> Result<bool, String> {
+        let statement = "
+            UPDATE milestones SET
+                title = COALESCE($2, title),
+                slip_notified_at = CASE WHEN $3::TIMESTAMP IS NULL THEN slip_notified_at ELSE NULL END,
+                due_date = COALESCE($3, due_date),
+                owner = COALESCE($4, owner),
+                status = COALESCE($5, status),
+                updated_at = NOW()
+            WHERE id = $1 AND status <> 'Done';";
+
+        let res = self
+            .client
+            .execute(
+                statement,
+                &[
+                    &id,
+                    &data.title,
+                    &data.due_date.map(|d| d.naive_utc()),
+                    &data.owner,
+                    &data.status,
+                ],
+            )
+            .await;
+
+        match res {
+            Ok(row) => Ok(row != 0),
+            Err(err) => match err.as_db_error() {
+                Some(err) => Err(err.message().to_string()),
+                None => Err(err.to_string()),
+            },
+        }
+    }
+
+    async fn complete(&self, id: &str) -> Result<bool, String> {
+        let statement = "
+            WITH completed AS (
+                UPDATE milestones SET status = 'Done', completed_at = NOW(), updated_at = NOW()
+                    WHERE id = $1 AND status <> 'Done'
+                RETURNING id
+            ), tasks AS (
+                UPDATE milestone_tasks SET is_done = true, completed_at = COALESCE(completed_at, NOW())
+                    WHERE milestone_id IN (SELECT id FROM completed)
+            )
+            SELECT COUNT(*) AS total FROM completed;";
+
+        match self.client.query_one(statement, &[&id]).await {
+            Ok(row) => Ok(row.get::<&str, i64>("total") != 0),
+            Err(err) => match err.as_db_error() {
+                Some(err) => Err(err.message().to_string()),
+                None => Err(err.to_string()),
+            },
+        }
+    }
+
+    async fn reorder(&self, project_id: &str, ids: &[String]) -> Result<u64, String> {
+        let statement = "
+            UPDATE milestones SET position = o.position, updated_at = NOW()
+                FROM unnest($2::VARCHAR[]) WITH ORDINALITY AS o(id, position)
+            WHERE milestones.id = o.id AND milestones.project_id = $1;";
+
+        match self.client.execute(statement, &[&project_id, &ids]).await {
+            Ok(rows) => Ok(rows),
+            Err(err) => match err.as_db_error() {
+                Some(err) => Err(err.message().to_string()),
+                None => Err(err.to_string()),
+            },
+        }
+    }
+
+    async fn insert_task(&self, milestone_id: &str, title: &str) -> Result<i32, String> {
+        let statement = "
+            INSERT INTO milestone_tasks (milestone_id, title, position)
+                VALUES ($1, $2, COALESCE((SELECT MAX(position) FROM milestone_tasks WHERE milestone_id = $1::VARCHAR), 0) + 1)
+            RETURNING id";
+
+        match self.client.query_one(statement, &[&milestone_id, &title]).await {
+            Ok(row) => Ok(row.get::<&str, i32>("id")),
+            Err(err) => match err.as_db_error() {
+                Some(err) => Err(err.message().to_string()),
+                None => Err(err.to_string()),
+            },
+        }
+    }
+
+    async fn update_task(&self, milestone_id: &str, task_id: i32, data: &UpdateTaskParams) -> Result<bool, String> {
+        let statement = "
+            UPDATE milestone_tasks SET
+                title = COALESCE($3, title),
+                is_done = COALESCE($4, is_done),
+                completed_at = CASE
+                    WHEN $4 = true THEN COALESCE(completed_at, NOW())
+                    WHEN $4 = false THEN NULL
+                    ELSE completed_at
+                END
+            WHERE id = $2 AND milestone_id = $1;";
+
+        let res = self
+            .client
+            .execute(statement, &[&milestone_id, &task_id, &data.title, &data.is_done])
+            .await;
+
+        match res {
+            Ok(row) => Ok(row != 0),
+            Err(err) => match err.as_db_error() {
+                Some(err) => Err(err.message().to_string()),
+                None => Err(err.to_string()),
+            },
+        }
+    }
+
+    async fn reorder_tasks(&self, milestone_id: &str, ids: &[i32]) -> Result<u64, String> {
+        let statement = "
+            UPDATE milestone_tasks SET position = o.position
+                FROM unnest($2::INT[]) WITH ORDINALITY AS o(id, position)
+            WHERE milestone_tasks.id = o.id AND milestone_tasks.milestone_id = $1;";
+
+        match self.client.execute(statement, &[&milestone_id, &ids]).await {
+            Ok(rows) => Ok(rows),
+            Err(err) => match err.as_db_error() {
+                Some(err) => Err(err.message().to_string()),
+                None => Err(err.to_string()),
+            },
+        }
+    }
+
+    async fn find_slipped(&self) -> Vec<Milestone> {
+        let statement = "
+            SELECT m.* FROM milestones AS m
+                JOIN project AS p ON p.id = m.project_id
+            WHERE m.status <> 'Done'
+                AND m.due_date < NOW()
+                AND m.slip_notified_at IS NULL
+                AND p.status IN ('Ongoing', 'Overdue')
+                AND p.deleted_at IS NULL;";
+
+        match self.client.query(statement, &[]).await {
+            Ok(rows) => rows.iter().map(Milestone::from_row).collect(),
+            Err(_) => vec![],
+        }
+    }
+
+    async fn mark_slip_notified(&self, id: &str) -> Result<bool, String> {
+        let res = self
+            .client
+            .execute(
+                "UPDATE milestones SET slip_notified_at = NOW() WHERE id = $1 AND slip_notified_at IS NULL;",
+                &[&id],
+            )
+            .await;
+
+        match res {
+            Ok(row) => Ok(row != 0),
+            Err(err) => match err.as_db_error() {
+                Some(err) => Err(err.message().to_string()),
+                None => Err(err.to_string()),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Duration, Utc};
+
+    use super::*;
+    use crate::{
+        app::traits::repositories::project::TProjectRepositories,
+        db::{
+            project::ProjectRepository,
+            test_support::{connect, project, user},
+        },
+    };
+
+    fn milestone(title: &str, tasks: &[&str]) -> CreateMilestoneParams {
+        CreateMilestoneParams {
+            title: title.to_string(),
+            due_date: Utc::now() + Duration::days(30),
+            owner: "Vendor".to_string(),
+            tasks: Some(tasks.iter().map(|t| t.to_string()).collect()),
+        }
+    }
+
+    fn done(is_done: bool) -> UpdateTaskParams {
+        UpdateTaskParams { title: None, is_done: Some(is_done) }
+    }
+
+    #[tokio::test]
+    async fn project_progress_averages_milestones_and_their_tasks() {
+        let client = match connect().await {
+            Some(client) => client,
+            None => return,
+        };
+        let owner = user(&client, "Client", false).await;
+        let project_id = project(&client, &owner, "Ongoing").await;
+        let repo = MilestoneRepository::new(client.clone());
+        let projects = ProjectRepository::new(client.clone());
+        let progress = || async { projects.find_by_id(&project_id).await.unwrap().progress };
+
+        assert_eq!(progress().await, None);
+
+        let design = repo.insert(&project_id, &milestone("Design", &["Sketches", "Permits"])).await.unwrap();
+        let build = repo.insert(&project_id, &milestone("Build", &["Foundation"])).await.unwrap();
+        assert_eq!(progress().await, Some(0.0));
+
+        let sketches = repo.find_by_id(&design).await.unwrap().tasks[0].id;
+        assert_eq!(repo.update_task(&design, sketches, &done(true)).await, Ok(true));
+        assert_eq!(progress().await, Some(25.0));
+
+        assert_eq!(repo.complete(&build).await, Ok(true));
+        assert_eq!(progress().await, Some(75.0));
+        assert!(repo.find_by_id(&build).await.unwrap().tasks.iter().all(|t| t.is_done));
+
+        assert_eq!(repo.update_task(&design, sketches, &done(false)).await, Ok(true));
+        assert_eq!(progress().await, Some(50.0));
+    }
+
+    #[tokio::test]
+    async fn milestones_and_tasks_keep_their_order() {
+        let client = match connect().await {
+            Some(client) => client,
+            None => return,
+        };
+        let owner = user(&client, "Client", false).await;
+        let project_id = project(&client, &owner, "Ongoing").await;
+        let repo = MilestoneRepository::new(client.clone());
+
+        let first = repo.insert(&project_id, &milestone("Design", &["Sketches", "Permits"])).await.unwrap();
+        let second = repo.insert(&project_id, &milestone("Build", &[])).await.unwrap();
+        let titles = |milestones: &[Milestone]| milestones.iter().map(|m| m.title.clone()).collect::<Vec<String>>();
+        assert_eq!(titles(&repo.find_by_project(&project_id).await), vec!["Design", "Build"]);
+
+        assert_eq!(repo.reorder(&project_id, &[second, first.clone()]).await, Ok(2));
+        assert_eq!(titles(&repo.find_by_project(&project_id).await), vec!["Build", "Design"]);
+
+        let ids: Vec<i32> = repo.find_by_id(&first).await.unwrap().tasks.iter().rev().map(|t| t.id).collect();
+        assert_eq!(repo.reorder_tasks(&first, &ids).await, Ok(2));
+        let tasks: Vec<String> = repo.find_by_id(&first).await.unwrap().tasks.into_iter().map(|t| t.title).collect();
+        assert_eq!(tasks, vec!["Permits", "Sketches"]);
+
+        repo.insert_task(&first, "Inspection").await.unwrap();
+        let tasks: Vec<String> = repo.find_by_id(&first).await.unwrap().tasks.into_iter().map(|t| t.title).collect();
+        assert_eq!(tasks, vec!["Permits", "Sketches", "Inspection"]);
+    }
+}
@@ -1,22 +1,58 @@
 use crate::app::traits::repositories::{
-    notification::TNotificationRepositories, user::TUserRepositories, project::TProjectRepositories,
+    attachment::TAttachmentRepositories, audit::TAuditRepositories, bid::TBidRepositories, capability::TCapabilityRepositories,
+    compliance::TComplianceRepositories, gazetteer::TGazetteerRepositories, milestone::TMilestoneRepositories,
+    notification::TNotificationRepositories, organization::TOrganizationRepositories,
+    preferences::TPreferenceRepositories, review::TReviewRepositories, saved_search::TSavedSearchRepositories,
+    user::TUserRepositories, project::TProjectRepositories,
 };
 use std::{fs, sync::Arc};
 use tokio_postgres::NoTls;
 
-use self::{notification::NotificationRepository, user::UserRepository, project::ProjectRepository};
+use self::{
+    attachment::AttachmentRepository, audit::AuditRepository, bid::BidRepository, capability::CapabilityRepository,
+    compliance::ComplianceRepository, gazetteer::GazetteerRepository, milestone::MilestoneRepository,
+    notification::NotificationRepository, organization::OrganizationRepository,
+    preferences::PreferenceRepository, review::ReviewRepository, saved_search::SavedSearchRepository,
+    user::UserRepository, project::ProjectRepository,
+};
 
+mod attachment;
+mod audit;
+mod bid;
+mod capability;
+mod compliance;
+mod gazetteer;
+mod milestone;
 mod notification;
+mod organization;
+mod preferences;
+mod review;
+mod saved_search;
 mod user;
 mod project;
 pub mod from_row;
+#[cfg(test)]
+mod test_support;
 
 pub struct DB {
     pub users: Box<dyn TUserRepositories + Sync + Send>,
     pub notifications: Box<dyn TNotificationRepositories + Sync + Send>,
     pub projects: Box<dyn TProjectRepositories + Sync + Send>,
+    pub compliance: Box<dyn TComplianceRepositories + Sync + Send>,
+    pub audit: Box<dyn TAuditRepositories + Sync + Send>,
+    pub preferences: Box<dyn TPreferenceRepositories + Sync + Send>,
+    pub organizations: Box<dyn TOrganizationRepositories + Sync + Send>,
+    pub reviews: Box<dyn TReviewRepositories + Sync + Send>,
+    pub attachments: Box<dyn TAttachmentRepositories + Sync + Send>,
+    pub bids: Box<dyn TBidRepositories + Sync + Send>,
+    pub milestones: Box<dyn TMilestoneRepositories + Sync + Send>,
+    pub gazetteer: Box<dyn TGazetteerRepositories + Sync + Send>,
+    pub capabilities: Box<dyn TCapabilityRepositories + Sync + Send>,
+    pub saved_searches: Box<dyn TSavedSearchRepositories + Sync + Send>,
 }
 
+const SEARCH_SIMILARITY: f32 = 0.7;
+
 impl DB {
     pub async fn connect() -> Self {
         let url = std::env::var("DATABASE_URL").expect("set DATABASE_URL env variable");
@@ -36,13 +72,28 @@ impl DB {
 
         let migration = fs::read_to_string(path).expect("Not read file");
         client.batch_execute(&migration).await.expect("Not init db");
+        client
+            .batch_execute(&format!("SET pg_trgm.word_similarity_threshold = {SEARCH_SIMILARITY};"))
+            .await
+            .expect("Not init db");
 
         let arc_client = Arc::new(client);
 
         DB {
             users: Box::new(UserRepository::new(arc_client.clone())),
             notifications: Box::new(NotificationRepository::new(arc_client.clone())),
-            projects: Box::new(ProjectRepository::new(arc_client.clone()))
+            projects: Box::new(ProjectRepository::new(arc_client.clone())),
+            compliance: Box::new(ComplianceRepository::new(arc_client.clone())),
+            audit: Box::new(AuditRepository::new(arc_client.clone())),
+            preferences: Box::new(PreferenceRepository::new(arc_client.clone())),
+            organizations: Box::new(OrganizationRepository::new(arc_client.clone())),
+            reviews: Box::new(ReviewRepository::new(arc_client.clone())),
+            attachments: Box::new(AttachmentRepository::new(arc_client.clone())),
+            bids: Box::new(BidRepository::new(arc_client.clone())),
+            milestones: Box::new(MilestoneRepository::new(arc_client.clone())),
+            gazetteer: Box::new(GazetteerRepository::new(arc_client.clone())),
+            capabilities: Box::new(CapabilityRepository::new(arc_client.clone())),
+            saved_searches: Box::new(SavedSearchRepository::new(arc_client.clone())),
         }
     }
 }
@@ -8,15 +8,19 @@ const S_USER_FIELDS: &str = "s_emails.email AS sender_email,
     s.id AS sender_user_id, 
     s.first_name AS sender_first_name, 
     s.last_name AS sender_last_name, 
+    s.company AS sender_company,
     s.created_at AS sender_created_at, 
-    s.type AS sender_type";
+    s.type AS sender_type,
+    s.is_compliant AS sender_is_compliant";
 
 const R_USER_FIELDS: &str = "r_emails.email AS receiver_email, 
     r.id AS receiver_user_id, 
     r.first_name AS receiver_first_name, 
     r.last_name AS receiver_last_name, 
+    r.company AS receiver_company,
     r.created_at AS receiver_created_at,
-    r.type AS receiver_type";
+    r.type AS receiver_type,
+    r.is_compliant AS receiver_is_compliant";
 
 
 impl Notification {
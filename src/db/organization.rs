@@ -0,0 +1,178 @@
+use crate::app::{
+    entities::{
+        organization::{member_role::MemberRole, Organization, OrganizationMember},
+        user::user_type::UserType,
+    },
+    traits::repositories::organization::TOrganizationRepositories,
+};
+use async_trait::async_trait;
+use std::{sync::Arc, time::SystemTime};
+use tokio_postgres::{Client, Row};
+
+use super::from_row::base_user_from_row;
+
+const MEMBER_FIELDS: &str = "m.role AS member_role,
+    m.created_at AS member_joined_at,
+    e.email AS member_email,
+    u.id AS member_user_id,
+    u.first_name AS member_first_name,
+    u.last_name AS member_last_name,
+    u.company AS member_company,
+    u.created_at AS member_created_at,
+    u.type AS member_type,
+    u.is_compliant AS member_is_compliant";
+
+impl Organization {
+    fn from_rows(rows: &[Row]) -> Vec<Self> {
+        let mut organizations: Vec<Organization> = vec![];
+
+        for row in rows {
+            let id = row.get::<&str, String>("id");
+            let member = OrganizationMember {
+                user: base_user_from_row(row, "member"),
+                role: MemberRole::from_str(row.get::<&str, &str>("member_role")).unwrap(),
+                created_at: row.get::<&str, SystemTime>("member_joined_at").into(),
+            };
+
+            match organizations.iter_mut().find(|o| o.id == id) {
+                Some(organization) => organization.members.push(member),
+                None => organizations.push(Organization {
+                    id,
+                    name: row.get::<&str, String>("name"),
+                    o_type: UserType::from_str(row.get::<&str, &str>("type")),
+                    created_at: row.get::<&str, SystemTime>("created_at").into(),
+                    members: vec![member],
+                }),
+            }
+        }
+
+        organizations
+    }
+}
+
+pub struct OrganizationRepository {
+    client: Arc<Client>,
+}
+
+impl OrganizationRepository {
+    pub fn new(client: Arc<Client>) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl TOrganizationRepositories for OrganizationRepository {
+    async fn insert(&self, name: &str, o_type: UserType, owner_id: &str) -> Result<String, String> {
+        let statement = "
+            WITH \"organization\" AS (
+                INSERT INTO organizations (name, type) VALUES ($1, $2) RETURNING id
+            ), owner AS (
+                INSERT INTO organization_members (organization_id, user_id, role)
+                SELECT id, $3, $4 FROM \"organization\"
+            )
+            SELECT id FROM \"organization\";";
+
+        let res = self
+            .client
+            .query_one(
+                statement,
+                &[&name, &o_type.to_string(), &owner_id, &MemberRole::Owner.as_str()],
+            )
+            .await;
+
+        match res {
+            Ok(row) => Ok(row.get::<&str, String>("id")),
+            Err(err) => match err.as_db_error() {
+                Some(err) => Err(err.message().to_string()),
+                None => Err(err.to_string()),
+            },
+        }
+    }
+
+    async fn find_by_id(&self, id: &str) -> Option<Organization> {
+        let statement = format!("
+            SELECT o.*, {MEMBER_FIELDS}
+                FROM organizations AS o
+                    JOIN organization_members AS m ON m.organization_id = o.id AND o.id = $1
+                    JOIN users AS u ON u.id = m.user_id
+                    JOIN user_emails AS e ON e.user_id = u.id AND e.is_primary = true
+                ORDER BY m.created_at;");
+
+        let res = self.client.query(&statement, &[&id]).await;
+
+        match res {
+            Ok(rows) => Organization::from_rows(&rows).into_iter().next(),
+            Err(_) => None,
+        }
+    }
+
+    async fn find_by_user(&self, user_id: &str) -> Vec<Organization> {
+        let statement = format!("
+            SELECT o.*, {MEMBER_FIELDS}
+                FROM organizations AS o
+                    JOIN organization_members AS m ON m.organization_id = o.id
+                    JOIN users AS u ON u.id = m.user_id
+                    JOIN user_emails AS e ON e.user_id = u.id AND e.is_primary = true
+                WHERE o.id IN (SELECT organization_id FROM organization_members WHERE user_id = $1)
+                ORDER BY o.name, m.created_at;");
+
+        let res = self.client.query(&statement, &[&user_id]).await;
+
+        match res {
+            Ok(rows) => Organization::from_rows(&rows),
+            Err(_) => vec![],
+        }
+    }
+
+    async fn find_role(&self, id: &str, user_id: &str) -> Option<MemberRole> {
+        let res = self
+            .client
+            .query_one(
+                "SELECT role FROM organization_members WHERE organization_id = $1 AND user_id = $2;",
+                &[&id, &user_id],
+            )
+            .await;
+
+        match res {
+            Ok(row) => MemberRole::from_str(row.get::<&str, &str>("role")).ok(),
+            Err(_) => None,
+        }
+    }
+
+    async fn upsert_member(&self, id: &str, user_id: &str, role: MemberRole) -> Result<bool, String> {
+        let statement = "
+            INSERT INTO organization_members (organization_id, user_id, role) VALUES ($1, $2, $3)
+            ON CONFLICT (organization_id, user_id) DO UPDATE SET role = EXCLUDED.role;";
+
+        let res = self
+            .client
+            .execute(statement, &[&id, &user_id, &role.as_str()])
+            .await;
+
+        match res {
+            Ok(row) => Ok(row != 0),
+            Err(err) => match err.as_db_error() {
+                Some(err) => Err(err.message().to_string()),
+                None => Err(err.to_string()),
+            },
+        }
+    }
+
+    async fn remove_member(&self, id: &str, user_id: &str) -> Result<bool, String> {
+        let res = self
+            .client
+            .execute(
+                "DELETE FROM organization_members WHERE organization_id = $1 AND user_id = $2;",
+                &[&id, &user_id],
+            )
+            .await;
+
+        match res {
+            Ok(row) => Ok(row != 0),
+            Err(err) => match err.as_db_error() {
+                Some(err) => Err(err.message().to_string()),
+                None => Err(err.to_string()),
+            },
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use crate::app::{
+    entities::preferences::{date_format::DateFormat, ChannelPreference, Preferences},
+    traits::repositories::preferences::TPreferenceRepositories,
+};
+use async_trait::async_trait;
+use serde_json::Value;
+use std::{collections::BTreeMap, sync::Arc};
+use tokio_postgres::{Client, Row};
+
+impl Preferences {
+    fn from_row(row: &Row) -> Self {
+        let locale = row.get::<&str, String>("locale");
+        Preferences {
+            date_format: DateFormat::from_str(row.get::<&str, &str>("date_format"))
+                .unwrap_or(DateFormat::for_locale(&locale)),
+            locale,
+            timezone: row.get::<&str, String>("timezone"),
+            channels: serde_json::from_value::<BTreeMap<String, ChannelPreference>>(
+                row.get::<&str, Value>("channels"),
+            )
+            .unwrap_or_default(),
+        }
+    }
+}
+
+pub struct PreferenceRepository {
+    client: Arc<Client>,
+}
+
+impl PreferenceRepository {
+    pub fn new(client: Arc<Client>) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl TPreferenceRepositories for PreferenceRepository {
+    async fn find_by_user(&self, user_id: &str) -> Option<Preferences> {
+        let res = self
+            .client
+            .query_one("SELECT * FROM user_preferences WHERE user_id = $1;", &[&user_id])
+            .await;
+
+        match res {
+            Ok(row) => Some(Preferences::from_row(&row)),
+            Err(_) => None,
+        }
+    }
+
+    async fn find_by_users(&self, user_ids: Vec<&str>) -> Vec<(String, Preferences)> {
+        let res = self
+            .client
+            .query("SELECT * FROM user_preferences WHERE user_id = ANY($1);", &[&user_ids])
+            .await;
+
+        match res {
+            Ok(rows) => rows
+                .iter()
+                .map(|row| (row.get::<&str, String>("user_id"), Preferences::from_row(row)))
+                .collect(),
+            Err(_) => vec![],
+        }
+    }
+
+    async fn upsert(&self, user_id: &str, preferences: &Preferences) -> Result<bool, String> {
+        let statement = "
+            INSERT INTO user_preferences (user_id, locale, timezone, date_format, channels)
+                VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (user_id) DO UPDATE SET
+                locale = EXCLUDED.locale,
+                timezone = EXCLUDED.timezone,
+                date_format = EXCLUDED.date_format,
+                channels = EXCLUDED.channels,
+                updated_at = NOW();";
+
+        let channels = serde_json::to_value(&preferences.channels).unwrap_or(Value::Null);
+        let res = self
+            .client
+            .execute(
+                statement,
+                &[
+                    &user_id,
+                    &preferences.locale,
+                    &preferences.timezone,
+                    &preferences.date_format.as_str(),
+                    &channels,
+                ],
+            )
+            .await;
+
+        match res {
+            Ok(row) => Ok(row != 0),
+            Err(err) => match err.as_db_error() {
+                Some(err) => Err(err.message().to_string()),
+                None => Err(err.to_string()),
+            },
+        }
+    }
+}
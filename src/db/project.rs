@@ -1,19 +1,52 @@
 use crate::app::{
-  entities::{project::{Project, appropriate_status::AppropriateStatus, budget_range::BudgetRange, square_range::SquareRange, commercial_work::CommercialWork, architectural_service::ArchitecturalServices, status::ProjectStatus}, user::{user_type::UserType, User}},
- services::project::{CreateParams, GetProjectByQueryParams}, traits::repositories::project::TProjectRepositories,
+  entities::{audit::audit_action::AuditAction, page::Page, project::{Project, appropriate_status::AppropriateStatus, budget_range::BudgetRange, square_range::SquareRange, commercial_work::CommercialWork, architectural_service::ArchitecturalServices, status::ProjectStatus, status_change::StatusChange, field_change::{FieldChange, FieldDiff}, draft::ProjectDraft, scope::ProjectScope, template::ProjectTemplate}, location::GeoPoint, user::{user_type::UserType, User}},
+ services::project::{CreateDraftParams, CreateParams, GetProjectByQueryParams, UpdateParams}, traits::repositories::project::TProjectRepositories,
 };
 use async_trait::async_trait;
+use serde_json::Value;
 use std::{sync::Arc, time::SystemTime, ops::Deref};
 use tokio_postgres::{Client, Row};
 
-use super::from_row::base_user_from_row;
+use super::{from_row::base_user_from_row, gazetteer::{country, distance, locate}};
 
 const USER_FIELDS: &str = "e.email AS user_email, 
     u.id AS user_user_id, 
     u.first_name AS user_first_name, 
     u.last_name AS user_last_name, 
+    u.company AS user_company,
     u.created_at AS user_created_at,
-    u.type AS user_type";
+    u.type AS user_type,
+    u.is_compliant AS user_is_compliant";
+
+const PROGRESS_FIELD: &str = "(
+      SELECT ROUND(AVG(
+        CASE WHEN m.status = 'Done' THEN 1
+          ELSE COALESCE((SELECT AVG(t.is_done::INT) FROM milestone_tasks AS t WHERE t.milestone_id = m.id), 0)
+        END
+      ) * 100)::FLOAT8
+        FROM milestones AS m WHERE m.project_id = p.id
+    ) AS progress";
+
+fn visibility(scope: usize, user_id: usize) -> String {
+  format!("(${scope} = 'All'
+    OR (${scope} = 'Member' AND (
+      p.user_id = ${user_id} OR p.organization_id IN (
+        SELECT organization_id FROM organization_members WHERE user_id = ${user_id}
+      )
+    ))
+    OR (${scope} IN ('Vendor', 'ApprovedVendor') AND (
+      (${scope} = 'ApprovedVendor' AND p.status = 'Active')
+      OR p.vendor_id = ${user_id}
+      OR EXISTS (
+        SELECT 1 FROM bids AS b
+          WHERE b.project_id = p.id AND (
+            b.vendor_id = ${user_id} OR b.organization_id IN (
+              SELECT organization_id FROM organization_members WHERE user_id = ${user_id}
+            )
+          )
+      )
+    )))")
+}
 
 impl Project {
   fn from_row(row: &Row) -> Self {
@@ -37,11 +70,94 @@ impl Project {
         created_at: row.get::<&str, SystemTime>("created_at").into(),
         creator: base_user_from_row(row, "user"),
         status: ProjectStatus::from_str(row.get::<&str, &str>("status")).unwrap(),
+        organization_id: row.get::<&str, Option<String>>("organization_id"),
+        vendor_id: row.get::<&str, Option<String>>("vendor_id"),
+        latitude: row.get::<&str, Option<f64>>("latitude"),
+        longitude: row.get::<&str, Option<f64>>("longitude"),
+        distance: row.try_get::<&str, Option<f64>>("distance").ok().flatten(),
+        archived_at: row.get::<&str, Option<SystemTime>>("archived_at").map(|d| d.into()),
+        deleted_at: row.get::<&str, Option<SystemTime>>("deleted_at").map(|d| d.into()),
+        progress: row.get::<&str, Option<f64>>("progress"),
+        attachments: vec![],
     } 
        
   }
 }
 
+impl ProjectDraft {
+  fn from_row(row: &Row) -> Self {
+      ProjectDraft {
+        id: row.get::<&str, String>("id"),
+        name: row.get::<&str, String>("name"),
+        city: row.get::<&str, Option<String>>("city"),
+        street: row.get::<&str, Option<String>>("street"),
+        zip_code: row.get::<&str, Option<String>>("zip_code"),
+        floor: row.get::<&str, Option<String>>("floor"),
+        description: row.get::<&str, Option<String>>("description"),
+        building_type: row.get::<&str, Option<String>>("building_type"),
+        is_save_carbon: row.get::<&str, Option<bool>>("is_save_carbon"),
+        appropriate_status: row.get::<&str, Option<&str>>("appropriate_status").map(|v| AppropriateStatus::from_str(v).unwrap()),
+        has_financing_secured: row.get::<&str, Option<bool>>("has_financing_secured"),
+        budget_range: row.get::<&str, Option<&str>>("budget_range").map(|v| BudgetRange::from_str(v).unwrap()),
+        square_range: row.get::<&str, Option<&str>>("square_range").map(|v| SquareRange::from_str(v).unwrap()),
+        commercial_work: row.get::<&str, Option<&str>>("commercial_work").map(|v| CommercialWork::from_str(v).unwrap()),
+        architectural_services: row.get::<&str, Option<Vec<&str>>>("architectural_services").map(|v| v.into_iter().map(|s| ArchitecturalServices::from_str(s).unwrap()).collect()),
+        completion_date: row.get::<&str, Option<SystemTime>>("completion_date").map(|d| d.into()),
+        created_at: row.get::<&str, SystemTime>("created_at").into(),
+        updated_at: row.get::<&str, Option<SystemTime>>("updated_at").unwrap_or(row.get::<&str, SystemTime>("created_at")).into(),
+        creator: base_user_from_row(row, "user"),
+        organization_id: row.get::<&str, Option<String>>("organization_id"),
+      }
+  }
+}
+
+impl ProjectTemplate {
+  fn from_row(row: &Row) -> Self {
+      ProjectTemplate {
+        id: row.get::<&str, String>("id"),
+        name: row.get::<&str, String>("name"),
+        description: row.get::<&str, Option<String>>("description"),
+        building_type: row.get::<&str, String>("building_type"),
+        is_save_carbon: row.get::<&str, bool>("is_save_carbon"),
+        appropriate_status: AppropriateStatus::from_str(row.get::<&str, &str>("appropriate_status")).unwrap(),
+        has_financing_secured: row.get::<&str, bool>("has_financing_secured"),
+        budget_range: BudgetRange::from_str(row.get::<&str, &str>("budget_range")).unwrap(),
+        square_range: SquareRange::from_str(row.get::<&str, &str>("square_range")).unwrap(),
+        commercial_work: CommercialWork::from_str(row.get::<&str, &str>("commercial_work")).unwrap(),
+        architectural_services: row.get::<&str, Option<Vec<&str>>>("architectural_services").unwrap_or_default().into_iter().map(|s| ArchitecturalServices::from_str(s).unwrap()).collect(),
+        creator_id: row.get::<&str, String>("user_id"),
+        organization_id: row.get::<&str, Option<String>>("organization_id"),
+        created_at: row.get::<&str, SystemTime>("created_at").into(),
+      }
+  }
+}
+
+impl StatusChange {
+  fn from_row(row: &Row) -> Self {
+      StatusChange {
+        id: row.get::<&str, i32>("id"),
+        from: ProjectStatus::from_str(row.get::<&str, &str>("from_status")).unwrap(),
+        to: ProjectStatus::from_str(row.get::<&str, &str>("to_status")).unwrap(),
+        actor_id: row.get::<&str, Option<String>>("actor_id"),
+        reason: row.get::<&str, Option<String>>("reason"),
+        created_at: row.get::<&str, SystemTime>("created_at").into(),
+      }
+  }
+}
+
+impl FieldChange {
+  fn from_row(row: &Row) -> Self {
+      FieldChange {
+        id: row.get::<&str, i32>("id"),
+        field: row.get::<&str, String>("field"),
+        old_value: row.get::<&str, Value>("old_value"),
+        new_value: row.get::<&str, Value>("new_value"),
+        actor_id: row.get::<&str, Option<String>>("actor_id"),
+        created_at: row.get::<&str, SystemTime>("created_at").into(),
+      }
+  }
+}
+
 pub struct ProjectRepository {
   client: Arc<Client>,
 }
@@ -50,6 +166,16 @@ impl ProjectRepository {
   pub fn new(client: Arc<Client>) -> Self {
       Self { client }
   }
+
+  async fn locate(&self, id: &str) {
+    let statement = format!("
+      UPDATE project AS p SET (latitude, longitude) = {}
+        WHERE p.id = $1;", locate("p.zip_code", "p.city", "$2"));
+
+    if let Err(err) = self.client.execute(&statement, &[&id, &country()]).await {
+      eprintln!("project locate error: {}", err);
+    }
+  }
 }
 
 #[async_trait]
@@ -73,9 +199,10 @@ impl TProjectRepositories for ProjectRepository {
             completion_date, 
             square_range,
             user_id,
-            status
+            status,
+            organization_id
           ) 
-              VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17) 
+              VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18) 
             RETURNING id";
 
       let res = self
@@ -99,14 +226,19 @@ impl TProjectRepositories for ProjectRepository {
                   &param.completion_date.naive_utc(),
                   &param.square_range.deref(),
                   &user_id,
-                  &status.as_str()
+                  &status.as_str(),
+                  &param.organization_id
               ],
           )
           .await;
 
 
       match res {
-          Ok(row) => Ok(row.get::<&str, String>("id")),
+          Ok(row) => {
+            let id = row.get::<&str, String>("id");
+            self.locate(&id).await;
+            Ok(id)
+          },
           Err(err) => match err.as_db_error() {
               Some(err) => Err(err.message().to_string()),
               None => Err(err.to_string()),
@@ -114,11 +246,95 @@ impl TProjectRepositories for ProjectRepository {
       }
   }
 
-  async fn find_by_id(&self, id: &str) -> Option<Project> {
+  async fn insert_draft(&self, user_id: &str, param: &CreateDraftParams) -> Result<String, String> {
+    let statement = "
+      INSERT INTO project (
+        name, city, street, zip_code, floor, description, building_type, is_save_carbon,
+        appropriate_status, has_financing_secured, budget_range, square_range, commercial_work,
+        architectural_services, completion_date, user_id, status, organization_id
+      )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18)
+      RETURNING id";
+    let project = &param.project;
+
+    let res = self
+        .client
+        .query_one(
+          statement,
+          &[
+            &project.name,
+            &project.city,
+            &project.street.clone().flatten(),
+            &project.zip_code.clone().flatten(),
+            &project.floor.clone().flatten(),
+            &project.description.clone().flatten(),
+            &project.building_type,
+            &project.is_save_carbon,
+            &project.appropriate_status,
+            &project.has_financing_secured,
+            &project.budget_range,
+            &project.square_range,
+            &project.commercial_work,
+            &project.architectural_services,
+            &project.completion_date.map(|d| d.naive_utc()),
+            &user_id,
+            &ProjectStatus::Draft.as_str(),
+            &param.organization_id,
+          ],
+        )
+        .await;
+
+    match res {
+        Ok(row) => {
+          let id = row.get::<&str, String>("id");
+          self.locate(&id).await;
+          Ok(id)
+        },
+        Err(err) => match err.as_db_error() {
+            Some(err) => Err(err.message().to_string()),
+            None => Err(err.to_string()),
+        },
+    }
+  }
+
+  async fn find_draft_by_id(&self, id: &str) -> Option<ProjectDraft> {
+    let statement = format!("
+      SELECT p.*, {USER_FIELDS} 
+        FROM project AS p
+          JOIN users AS u ON p.user_id = u.id AND p.id = $1 AND p.status = 'Draft' AND p.deleted_at IS NULL
+          JOIN user_emails AS e ON e.user_id = p.user_id AND e.is_primary = true;");
+
+    match self.client.query_one(&statement, &[&id]).await {
+        Ok(row) => Some(ProjectDraft::from_row(&row)),
+        Err(_) => None
+    }
+  }
+
+  async fn find_drafts(&self, user_id: &str) -> Vec<ProjectDraft> {
     let statement = format!("
       SELECT p.*, {USER_FIELDS} 
         FROM project AS p
-          JOIN users AS u ON p.user_id = u.id AND p.id = $1
+          JOIN users AS u ON p.user_id = u.id
+          JOIN user_emails AS e ON e.user_id = p.user_id AND e.is_primary = true
+        WHERE p.status = 'Draft' AND p.deleted_at IS NULL AND (
+          p.user_id = $1 OR p.organization_id IN (
+            SELECT organization_id FROM organization_members
+              WHERE user_id = $1 AND role IN ('Owner', 'Manager')
+          )
+        )
+        ORDER BY p.updated_at DESC;");
+
+    match self.client.query(&statement, &[&user_id]).await {
+        Ok(rows) => rows.iter().map(ProjectDraft::from_row).collect(),
+        Err(_) => vec![]
+    }
+  }
+
+  async fn find_by_id(&self, id: &str) -> Option<Project> {
+    let statement = format!("
+      SELECT p.*, {USER_FIELDS}, {PROGRESS_FIELD} 
+        FROM project AS p
+          JOIN users AS u ON p.user_id = u.id AND p.id = $1 AND p.status <> 'Draft' AND p.deleted_at IS NULL
           JOIN user_emails AS e ON e.user_id = p.user_id AND e.is_primary = true;");
 
     let res = self
@@ -133,23 +349,1136 @@ impl TProjectRepositories for ProjectRepository {
     
   }
 
-  async fn find_by_query(&self, params: &GetProjectByQueryParams) -> Vec<Project> {
-    return vec![];
-    // let user_types: Vec<String> = types.iter().map( | t | t.to_string()).collect();
-    // let value = format!("%{}%", value.unwrap_or("").split_whitespace().collect::<String>()).to_lowercase();
-    // let statement =
-    //     format!("SELECT u.*, e.email as email FROM users AS u 
-    //         JOIN user_emails AS e ON u.id = e.user_id AND u.type = ANY($1) AND lower(CONCAT(u.first_name, u.last_name)) LIKE ($2)
-    //         LIMIT $3 OFFSET $4;");
-
-    // let res = self.client.query(&statement, &[&user_types, &value, &limit, &skip]).await;
-    // match res {
-    //     Ok(rows) => rows
-    //         .into_iter()
-    //         .map(|row| User::from_rows(&vec![row]))
-    //         .collect(),
-    //     Err(_) =>  vec![]
-    // }
-  }
-  
+  async fn find_visible_by_id(&self, id: &str, scope: &ProjectScope) -> Option<Project> {
+    let statement = format!("
+      SELECT p.*, {USER_FIELDS}, {PROGRESS_FIELD} 
+        FROM project AS p
+          JOIN users AS u ON p.user_id = u.id AND p.id = $1 AND p.status <> 'Draft' AND p.deleted_at IS NULL
+          JOIN user_emails AS e ON e.user_id = p.user_id AND e.is_primary = true
+        WHERE {};", visibility(2, 3));
+
+    let res = self
+        .client
+        .query_one(&statement, &[&id, &scope.as_str(), &scope.user_id()])
+        .await;
+
+    match res {
+        Ok(row) => Some(Project::from_row(&row)),
+        Err(_) => None
+    }
+  }
+
+  async fn find_by_query(&self, params: &GetProjectByQueryParams, scope: &ProjectScope, origin: Option<&GeoPoint>) -> Page<Project> {
+    let name = params.name.as_deref().unwrap_or("").trim().to_lowercase();
+    let pattern = format!("%{}%", name.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_"));
+    let city = params.city.as_deref().map(|c| c.trim().to_lowercase());
+    let completion_from = params.completion_from.map(|d| d.naive_utc());
+    let completion_to = params.completion_to.map(|d| d.naive_utc());
+    let order = if params.order.as_deref() == Some("asc") { "ASC" } else { "DESC" };
+    let sort = match params.sort.as_deref() {
+        Some("name") => format!("p.name {order}"),
+        Some("completionDate") => format!("p.completion_date {order}"),
+        Some("distance") => format!("distance {order} NULLS LAST"),
+        _ => format!("p.created_at {order}"),
+    };
+
+    let scope_name = scope.as_str();
+    let user_id = scope.user_id();
+    let archived = params.archived.unwrap_or(false);
+    let origin_latitude = origin.map(|o| o.latitude);
+    let origin_longitude = origin.map(|o| o.longitude);
+    let distance = distance("p.latitude", "p.longitude", 14, 15);
+    let condition = format!("FROM project AS p
+          JOIN users AS u ON p.user_id = u.id
+          JOIN user_emails AS e ON e.user_id = p.user_id AND e.is_primary = true
+        WHERE p.status <> 'Draft'
+          AND p.deleted_at IS NULL
+          AND ($13::BOOLEAN = (p.archived_at IS NOT NULL))
+          AND ($1::VARCHAR IS NULL OR p.status = $1)
+          AND ($2 = '' OR lower(p.name) LIKE $3)
+          AND ($4::VARCHAR IS NULL OR lower(p.city) = $4)
+          AND ($5::VARCHAR IS NULL OR p.budget_range = $5)
+          AND ($6::VARCHAR IS NULL OR p.square_range = $6)
+          AND ($7::VARCHAR IS NULL OR p.commercial_work = $7)
+          AND ($8::BOOLEAN IS NULL OR p.is_save_carbon = $8)
+          AND ($9::TIMESTAMP IS NULL OR p.completion_date >= $9)
+          AND ($10::TIMESTAMP IS NULL OR p.completion_date <= $10)
+          AND ($16::FLOAT8 IS NULL OR (p.latitude IS NOT NULL AND {distance} <= $16))
+          AND ($17::FLOAT8 IS NULL OR (
+            p.latitude BETWEEN $17 AND $19 AND p.longitude BETWEEN $18::FLOAT8 AND $20::FLOAT8
+          ))
+          AND {}", visibility(11, 12));
+
+    let total = match self
+        .client
+        .query_one(
+          &format!("SELECT COUNT(*) AS total {condition};"),
+          &[
+            &params.status,
+            &name,
+            &pattern,
+            &city,
+            &params.budget_range,
+            &params.square_range,
+            &params.commercial_work,
+            &params.is_save_carbon,
+            &completion_from,
+            &completion_to,
+            &scope_name,
+            &user_id,
+            &archived,
+            &origin_latitude,
+            &origin_longitude,
+            &params.radius,
+            &params.min_latitude,
+            &params.min_longitude,
+            &params.max_latitude,
+            &params.max_longitude,
+          ],
+        )
+        .await
+    {
+        Ok(row) => row.get::<&str, i64>("total"),
+        Err(_) => 0,
+    };
+
+    let statement = format!("SELECT p.*, {USER_FIELDS}, {PROGRESS_FIELD}, {distance} AS distance {condition} ORDER BY {sort}, p.id LIMIT $21 OFFSET $22;");
+    let skip = params.skip.unwrap_or(0);
+
+    let res = self
+        .client
+        .query(
+          &statement,
+          &[
+            &params.status,
+            &name,
+            &pattern,
+            &city,
+            &params.budget_range,
+            &params.square_range,
+            &params.commercial_work,
+            &params.is_save_carbon,
+            &completion_from,
+            &completion_to,
+            &scope_name,
+            &user_id,
+            &archived,
+            &origin_latitude,
+            &origin_longitude,
+            &params.radius,
+            &params.min_latitude,
+            &params.min_longitude,
+            &params.max_latitude,
+            &params.max_longitude,
+            &params.limit,
+            &skip,
+          ],
+        )
+        .await;
+
+    let items = match res {
+        Ok(rows) => rows.iter().map(Project::from_row).collect(),
+        Err(_) => vec![],
+    };
+
+    Page { items, total, limit: params.limit, skip }
+  }
+
+  async fn update(&self, id: &str, param: &UpdateParams, actor_id: Option<&str>) -> Result<Vec<String>, String> {
+    let statement = "
+      WITH old AS (
+        SELECT * FROM project WHERE id = $1 AND deleted_at IS NULL FOR UPDATE
+      ), updated AS (
+        UPDATE project SET
+          name = COALESCE($2, name),
+          city = COALESCE($3, city),
+          street = CASE WHEN $17 THEN $4 ELSE street END,
+          zip_code = CASE WHEN $18 THEN $5 ELSE zip_code END,
+          floor = CASE WHEN $19 THEN $6 ELSE floor END,
+          description = CASE WHEN $20 THEN $7 ELSE description END,
+          building_type = COALESCE($8, building_type),
+          is_save_carbon = COALESCE($9, is_save_carbon),
+          appropriate_status = COALESCE($10, appropriate_status),
+          has_financing_secured = COALESCE($11, has_financing_secured),
+          budget_range = COALESCE($12, budget_range),
+          square_range = COALESCE($13, square_range),
+          commercial_work = COALESCE($14, commercial_work),
+          architectural_services = COALESCE($15, architectural_services),
+          completion_date = COALESCE($16, completion_date),
+          updated_at = NOW()
+        WHERE id IN (SELECT id FROM old)
+        RETURNING *
+      ), changed AS (
+        INSERT INTO project_changes (project_id, field, old_value, new_value, actor_id)
+          SELECT n.id, c.field, c.old_value, c.new_value, $21
+            FROM old AS o
+              JOIN updated AS n ON n.id = o.id,
+              LATERAL (VALUES
+                ('name', to_jsonb(o.name), to_jsonb(n.name)),
+                ('city', to_jsonb(o.city), to_jsonb(n.city)),
+                ('street', COALESCE(to_jsonb(o.street), 'null'), COALESCE(to_jsonb(n.street), 'null')),
+                ('zipCode', COALESCE(to_jsonb(o.zip_code), 'null'), COALESCE(to_jsonb(n.zip_code), 'null')),
+                ('floor', COALESCE(to_jsonb(o.floor), 'null'), COALESCE(to_jsonb(n.floor), 'null')),
+                ('description', COALESCE(to_jsonb(o.description), 'null'), COALESCE(to_jsonb(n.description), 'null')),
+                ('buildingType', to_jsonb(o.building_type), to_jsonb(n.building_type)),
+                ('isSaveCarbon', to_jsonb(o.is_save_carbon), to_jsonb(n.is_save_carbon)),
+                ('appropriateStatus', to_jsonb(o.appropriate_status), to_jsonb(n.appropriate_status)),
+                ('hasFinancingSecured', to_jsonb(o.has_financing_secured), to_jsonb(n.has_financing_secured)),
+                ('budgetRange', to_jsonb(o.budget_range), to_jsonb(n.budget_range)),
+                ('squareRange', to_jsonb(o.square_range), to_jsonb(n.square_range)),
+                ('commercialWork', to_jsonb(o.commercial_work), to_jsonb(n.commercial_work)),
+                ('architecturalServices',
+                  to_jsonb(ARRAY(SELECT DISTINCT s FROM unnest(o.architectural_services) AS s ORDER BY s)),
+                  to_jsonb(ARRAY(SELECT DISTINCT s FROM unnest(n.architectural_services) AS s ORDER BY s))),
+                ('completionDate',
+                  to_jsonb(to_char(o.completion_date, 'YYYY-MM-DD\"T\"HH24:MI:SS\"Z\"')),
+                  to_jsonb(to_char(n.completion_date, 'YYYY-MM-DD\"T\"HH24:MI:SS\"Z\"')))
+              ) AS c(field, old_value, new_value)
+            WHERE o.status <> 'Draft' AND c.old_value IS DISTINCT FROM c.new_value
+        RETURNING field
+      )
+      SELECT field FROM changed;";
+
+    let res = self
+        .client
+        .query(
+          statement,
+          &[
+            &id,
+            &param.name,
+            &param.city,
+            &param.street.clone().flatten(),
+            &param.zip_code.clone().flatten(),
+            &param.floor.clone().flatten(),
+            &param.description.clone().flatten(),
+            &param.building_type,
+            &param.is_save_carbon,
+            &param.appropriate_status,
+            &param.has_financing_secured,
+            &param.budget_range,
+            &param.square_range,
+            &param.commercial_work,
+            &param.architectural_services,
+            &param.completion_date.map(|d| d.naive_utc()),
+            &param.street.is_some(),
+            &param.zip_code.is_some(),
+            &param.floor.is_some(),
+            &param.description.is_some(),
+            &actor_id,
+          ],
+        )
+        .await;
+
+    if param.city.is_some() || param.zip_code.is_some() {
+      self.locate(id).await;
+    }
+
+    match res {
+        Ok(rows) => Ok(rows.iter().map(|row| row.get::<&str, String>("field")).collect()),
+        Err(err) => match err.as_db_error() {
+            Some(err) => Err(err.message().to_string()),
+            None => Err(err.to_string()),
+        },
+    }
+  }
+
+  async fn transition(&self, id: &str, from: &ProjectStatus, to: &ProjectStatus, actor_id: Option<&str>, reason: Option<&str>) -> Result<bool, String> {
+    let statement = "
+      WITH updated AS (
+        UPDATE project SET status = $3, updated_at = NOW()
+          WHERE id = $1 AND status = $2 AND deleted_at IS NULL
+        RETURNING id
+      )
+      INSERT INTO project_status_history (project_id, from_status, to_status, actor_id, reason)
+        SELECT id, $2, $3, $4, $5 FROM updated;";
+
+    let res = self
+        .client
+        .execute(statement, &[&id, &from.as_str(), &to.as_str(), &actor_id, &reason])
+        .await;
+
+    match res {
+        Ok(row) => Ok(row != 0),
+        Err(err) => match err.as_db_error() {
+            Some(err) => Err(err.message().to_string()),
+            None => Err(err.to_string()),
+        },
+    }
+  }
+
+  async fn find_overdue(&self) -> Vec<Project> {
+    let statement = format!("
+      SELECT p.*, {USER_FIELDS}, {PROGRESS_FIELD} 
+        FROM project AS p
+          JOIN users AS u ON p.user_id = u.id
+          JOIN user_emails AS e ON e.user_id = p.user_id AND e.is_primary = true
+        WHERE p.status = $1 AND p.completion_date < NOW() AND p.deleted_at IS NULL;");
+
+    let res = self
+        .client
+        .query(&statement, &[&ProjectStatus::Ongoing.as_str()])
+        .await;
+
+    match res {
+        Ok(rows) => rows.iter().map(Project::from_row).collect(),
+        Err(_) => vec![]
+    }
+  }
+
+  async fn find_status_history(&self, id: &str) -> Vec<StatusChange> {
+    let res = self
+        .client
+        .query(
+          "SELECT * FROM project_status_history WHERE project_id = $1 ORDER BY created_at, id;",
+          &[&id],
+        )
+        .await;
+
+    match res {
+        Ok(rows) => rows.iter().map(StatusChange::from_row).collect(),
+        Err(_) => vec![]
+    }
+  }
+
+  async fn insert_changes(&self, id: &str, actor_id: Option<&str>, changes: &[FieldDiff]) -> Result<(), String> {
+    if changes.is_empty() {
+      return Ok(());
+    }
+
+    let fields: Vec<&str> = changes.iter().map(|c| c.field).collect();
+    let old_values: Vec<&Value> = changes.iter().map(|c| &c.old_value).collect();
+    let new_values: Vec<&Value> = changes.iter().map(|c| &c.new_value).collect();
+
+    let statement = "
+      INSERT INTO project_changes (project_id, field, old_value, new_value, actor_id)
+        SELECT $1, c.field, c.old_value, c.new_value, $2
+          FROM UNNEST($3::VARCHAR[], $4::JSONB[], $5::JSONB[]) AS c(field, old_value, new_value);";
+
+    let res = self
+        .client
+        .execute(statement, &[&id, &actor_id, &fields, &old_values, &new_values])
+        .await;
+
+    match res {
+        Ok(_) => Ok(()),
+        Err(err) => match err.as_db_error() {
+            Some(err) => Err(err.message().to_string()),
+            None => Err(err.to_string()),
+        },
+    }
+  }
+
+  async fn find_changes(&self, id: &str) -> Vec<FieldChange> {
+    let res = self
+        .client
+        .query(
+          "SELECT * FROM project_changes WHERE project_id = $1 ORDER BY created_at, id;",
+          &[&id],
+        )
+        .await;
+
+    match res {
+        Ok(rows) => rows.iter().map(FieldChange::from_row).collect(),
+        Err(_) => vec![]
+    }
+  }
+
+  async fn find_open_for_vendor(&self, scope: &ProjectScope, origin: Option<&GeoPoint>) -> Vec<Project> {
+    let statement = format!("
+      SELECT p.*, {USER_FIELDS}, {PROGRESS_FIELD}, {} AS distance
+        FROM project AS p
+          JOIN users AS u ON p.user_id = u.id
+          JOIN user_emails AS e ON e.user_id = p.user_id AND e.is_primary = true
+        WHERE p.status = 'Active' AND p.deleted_at IS NULL AND p.archived_at IS NULL AND {}
+          AND NOT EXISTS (SELECT 1 FROM bids AS b WHERE b.project_id = p.id AND b.vendor_id = $1);",
+      distance("p.latitude", "p.longitude", 2, 3),
+      visibility(4, 1));
+
+    let res = self
+        .client
+        .query(&statement, &[&scope.user_id(), &origin.map(|o| o.latitude), &origin.map(|o| o.longitude), &scope.as_str()])
+        .await;
+
+    match res {
+        Ok(rows) => rows.iter().map(Project::from_row).collect(),
+        Err(_) => vec![]
+    }
+  }
+
+  async fn find_unalerted(&self) -> Vec<Project> {
+    let statement = format!("
+      SELECT p.*, {USER_FIELDS}, {PROGRESS_FIELD}
+        FROM project AS p
+          JOIN users AS u ON p.user_id = u.id
+          JOIN user_emails AS e ON e.user_id = p.user_id AND e.is_primary = true
+        WHERE p.status = 'Active' AND p.alerted_at IS NULL AND p.deleted_at IS NULL
+        ORDER BY p.created_at;");
+
+    match self.client.query(&statement, &[]).await {
+        Ok(rows) => rows.iter().map(Project::from_row).collect(),
+        Err(_) => vec![]
+    }
+  }
+
+  async fn mark_alerted(&self, id: &str) -> Result<bool, String> {
+    let res = self
+        .client
+        .execute("UPDATE project SET alerted_at = NOW() WHERE id = $1 AND alerted_at IS NULL;", &[&id])
+        .await;
+
+    match res {
+        Ok(row) => Ok(row != 0),
+        Err(err) => match err.as_db_error() {
+            Some(err) => Err(err.message().to_string()),
+            None => Err(err.to_string()),
+        },
+    }
+  }
+
+  async fn watch(&self, id: &str, user_id: &str) -> Result<bool, String> {
+    let res = self
+        .client
+        .execute(
+          "INSERT INTO project_watchers (project_id, user_id) VALUES ($1, $2) ON CONFLICT DO NOTHING;",
+          &[&id, &user_id],
+        )
+        .await;
+
+    match res {
+        Ok(row) => Ok(row != 0),
+        Err(err) => match err.as_db_error() {
+            Some(err) => Err(err.message().to_string()),
+            None => Err(err.to_string()),
+        },
+    }
+  }
+
+  async fn unwatch(&self, id: &str, user_id: &str) -> Result<bool, String> {
+    let res = self
+        .client
+        .execute(
+          "DELETE FROM project_watchers WHERE project_id = $1 AND user_id = $2;",
+          &[&id, &user_id],
+        )
+        .await;
+
+    match res {
+        Ok(row) => Ok(row != 0),
+        Err(err) => match err.as_db_error() {
+            Some(err) => Err(err.message().to_string()),
+            None => Err(err.to_string()),
+        },
+    }
+  }
+
+  async fn find_watched(&self, user_id: &str, scope: &ProjectScope) -> Vec<Project> {
+    let statement = format!("
+      SELECT p.*, {USER_FIELDS}, {PROGRESS_FIELD}
+        FROM project_watchers AS w
+          JOIN project AS p ON p.id = w.project_id AND p.deleted_at IS NULL
+          JOIN users AS u ON p.user_id = u.id
+          JOIN user_emails AS e ON e.user_id = p.user_id AND e.is_primary = true
+        WHERE w.user_id = $1 AND {}
+        ORDER BY w.created_at DESC;", visibility(2, 3));
+
+    match self.client.query(&statement, &[&user_id, &scope.as_str(), &scope.user_id()]).await {
+        Ok(rows) => rows.iter().map(Project::from_row).collect(),
+        Err(_) => vec![]
+    }
+  }
+
+  async fn find_watchers(&self, id: &str) -> Vec<User> {
+    let statement = format!("
+      SELECT {USER_FIELDS}
+        FROM project_watchers AS w
+          JOIN users AS u ON u.id = w.user_id
+          JOIN user_emails AS e ON e.user_id = u.id AND e.is_primary = true
+        WHERE w.project_id = $1;");
+
+    match self.client.query(&statement, &[&id]).await {
+        Ok(rows) => rows.iter().map(|row| base_user_from_row(row, "user")).collect(),
+        Err(_) => vec![]
+    }
+  }
+
+  async fn insert_template(&self, id: &str, user_id: &str, name: &str) -> Result<String, String> {
+    let statement = "
+      INSERT INTO project_templates (
+        name, description, building_type, is_save_carbon, appropriate_status, has_financing_secured,
+        budget_range, square_range, commercial_work, architectural_services, user_id, organization_id
+      )
+        SELECT $2, description, building_type, is_save_carbon, appropriate_status, has_financing_secured,
+          budget_range, square_range, commercial_work, architectural_services, $3, organization_id
+          FROM project WHERE id = $1
+      RETURNING id";
+
+    let res = self
+        .client
+        .query_one(statement, &[&id, &name, &user_id])
+        .await;
+
+    match res {
+        Ok(row) => Ok(row.get::<&str, String>("id")),
+        Err(err) => match err.as_db_error() {
+            Some(err) => Err(err.message().to_string()),
+            None => Err(err.to_string()),
+        },
+    }
+  }
+
+  async fn find_template_by_id(&self, id: &str) -> Option<ProjectTemplate> {
+    match self.client.query_one("SELECT * FROM project_templates WHERE id = $1;", &[&id]).await {
+        Ok(row) => Some(ProjectTemplate::from_row(&row)),
+        Err(_) => None
+    }
+  }
+
+  async fn find_templates(&self, user_id: &str) -> Vec<ProjectTemplate> {
+    let statement = "
+      SELECT * FROM project_templates
+        WHERE user_id = $1
+          OR organization_id IN (
+            SELECT organization_id FROM organization_members
+              WHERE user_id = $1 AND role IN ('Owner', 'Manager')
+          )
+        ORDER BY created_at DESC;";
+
+    match self.client.query(statement, &[&user_id]).await {
+        Ok(rows) => rows.iter().map(ProjectTemplate::from_row).collect(),
+        Err(_) => vec![]
+    }
+  }
+
+  async fn delete_template(&self, id: &str) -> Result<bool, String> {
+    let res = self
+        .client
+        .execute("DELETE FROM project_templates WHERE id = $1;", &[&id])
+        .await;
+
+    match res {
+        Ok(row) => Ok(row != 0),
+        Err(err) => match err.as_db_error() {
+            Some(err) => Err(err.message().to_string()),
+            None => Err(err.to_string()),
+        },
+    }
+  }
+
+  async fn find_interested_vendors(&self, id: &str) -> Vec<User> {
+    let statement = format!("
+      SELECT {USER_FIELDS}
+        FROM users AS u
+          JOIN user_emails AS e ON e.user_id = u.id AND e.is_primary = true
+        WHERE u.id IN (
+          SELECT b.vendor_id FROM bids AS b WHERE b.project_id = $1 AND b.status IN ('Submitted', 'Shortlisted')
+          UNION
+          SELECT w.user_id FROM project_watchers AS w WHERE w.project_id = $1
+        );");
+
+    match self.client.query(&statement, &[&id]).await {
+        Ok(rows) => rows.iter().map(|row| base_user_from_row(row, "user")).collect(),
+        Err(_) => vec![]
+    }
+  }
+
+  async fn locate_missing(&self) -> Result<u64, String> {
+    let statement = format!("
+      UPDATE project AS p SET (latitude, longitude) = {}
+        WHERE p.latitude IS NULL AND p.deleted_at IS NULL
+          AND (p.zip_code IS NOT NULL OR p.city IS NOT NULL);", locate("p.zip_code", "p.city", "$1"));
+
+    match self.client.execute(&statement, &[&country()]).await {
+        Ok(rows) => Ok(rows),
+        Err(err) => match err.as_db_error() {
+            Some(err) => Err(err.message().to_string()),
+            None => Err(err.to_string()),
+        },
+    }
+  }
+
+  async fn update_organization(&self, id: &str, organization_id: Option<&str>) -> Result<bool, String> {
+    let res = self
+        .client
+        .execute(
+          "UPDATE project SET organization_id = $2 WHERE id = $1 AND deleted_at IS NULL;",
+          &[&id, &organization_id],
+        )
+        .await;
+
+    match res {
+        Ok(row) => Ok(row != 0),
+        Err(err) => match err.as_db_error() {
+            Some(err) => Err(err.message().to_string()),
+            None => Err(err.to_string()),
+        },
+    }
+  }
+
+  async fn count_by_creator(&self, user_id: &str, status: ProjectStatus) -> i64 {
+    let res = self
+        .client
+        .query_one(
+          "SELECT COUNT(*) AS total FROM project WHERE user_id = $1 AND status = $2 AND deleted_at IS NULL;",
+          &[&user_id, &status.as_str()],
+        )
+        .await;
+
+    match res {
+        Ok(row) => row.get::<&str, i64>("total"),
+        Err(_) => 0
+    }
+  }
+
+  async fn is_counterparty(&self, user_id: &str, other_id: &str) -> bool {
+    let res = self
+        .client
+        .query_one(
+          "SELECT EXISTS (
+            SELECT 1 FROM project
+              WHERE ((user_id = $1 AND vendor_id = $2) OR (user_id = $2 AND vendor_id = $1))
+                AND deleted_at IS NULL
+          ) AS shared;",
+          &[&user_id, &other_id],
+        )
+        .await;
+
+    match res {
+        Ok(row) => row.get::<&str, bool>("shared"),
+        Err(_) => false
+    }
+  }
+
+  async fn owns_any(&self, user_id: &str) -> bool {
+    let res = self
+        .client
+        .query_one("SELECT EXISTS (SELECT 1 FROM project WHERE user_id = $1) AS owns;", &[&user_id])
+        .await;
+
+    match res {
+        Ok(row) => row.get::<&str, bool>("owns"),
+        Err(_) => false
+    }
+  }
+
+  async fn soft_delete(&self, id: &str, actor_id: &str) -> Result<bool, String> {
+    let res = self
+        .client
+        .execute(
+          "WITH deleted AS (
+            UPDATE project SET deleted_at = NOW(), deleted_by = $2 WHERE id = $1 AND deleted_at IS NULL
+            RETURNING id
+          )
+          INSERT INTO project_changes (project_id, field, old_value, new_value, actor_id)
+            SELECT id, 'isDeleted', 'false', 'true', $2 FROM deleted;",
+          &[&id, &actor_id],
+        )
+        .await;
+
+    match res {
+        Ok(row) => Ok(row != 0),
+        Err(err) => match err.as_db_error() {
+            Some(err) => Err(err.message().to_string()),
+            None => Err(err.to_string()),
+        },
+    }
+  }
+
+  async fn restore(&self, id: &str, admin_id: &str) -> Result<bool, String> {
+    let res = self
+        .client
+        .execute(
+          "WITH restored AS (
+            UPDATE project SET deleted_at = NULL, deleted_by = NULL WHERE id = $1 AND deleted_at IS NOT NULL
+            RETURNING id, user_id, name
+          ), changed AS (
+            INSERT INTO project_changes (project_id, field, old_value, new_value, actor_id)
+              SELECT id, 'isDeleted', 'true', 'false', $2 FROM restored
+          )
+          INSERT INTO admin_audit (admin_id, target_user_id, action, details)
+            SELECT $2, user_id, $3, jsonb_build_object('projectId', id, 'name', name) FROM restored;",
+          &[&id, &admin_id, &AuditAction::RestoreProject.as_str()],
+        )
+        .await;
+
+    match res {
+        Ok(row) => Ok(row != 0),
+        Err(err) => match err.as_db_error() {
+            Some(err) => Err(err.message().to_string()),
+            None => Err(err.to_string()),
+        },
+    }
+  }
+
+  async fn set_archived(&self, id: &str, is_archived: bool, actor_id: &str) -> Result<bool, String> {
+    let statement = "
+      WITH archived AS (
+        UPDATE project SET archived_at = CASE WHEN $2 THEN NOW() ELSE NULL END
+          WHERE id = $1 AND deleted_at IS NULL AND (archived_at IS NULL) = $2
+        RETURNING id
+      )
+      INSERT INTO project_changes (project_id, field, old_value, new_value, actor_id)
+        SELECT id, 'isArchived', to_jsonb(NOT $2), to_jsonb($2), $3 FROM archived;";
+
+    match self.client.execute(statement, &[&id, &is_archived, &actor_id]).await {
+        Ok(row) => Ok(row != 0),
+        Err(err) => match err.as_db_error() {
+            Some(err) => Err(err.message().to_string()),
+            None => Err(err.to_string()),
+        },
+    }
+  }
+
+  async fn find_deleted(&self, limit: i64, skip: i64) -> Page<Project> {
+    let total = match self
+        .client
+        .query_one("SELECT COUNT(*) AS total FROM project WHERE deleted_at IS NOT NULL AND status <> 'Draft';", &[])
+        .await
+    {
+        Ok(row) => row.get::<&str, i64>("total"),
+        Err(_) => 0,
+    };
+
+    let statement = format!("
+      SELECT p.*, {USER_FIELDS}, {PROGRESS_FIELD} 
+        FROM project AS p
+          JOIN users AS u ON p.user_id = u.id
+          JOIN user_emails AS e ON e.user_id = p.user_id AND e.is_primary = true
+        WHERE p.deleted_at IS NOT NULL AND p.status <> 'Draft'
+        ORDER BY p.deleted_at DESC, p.id LIMIT $1 OFFSET $2;");
+
+    let items = match self.client.query(&statement, &[&limit, &skip]).await {
+        Ok(rows) => rows.iter().map(Project::from_row).collect(),
+        Err(_) => vec![],
+    };
+
+    Page { items, total, limit: Some(limit), skip }
+  }
+
+  async fn find_deleted_drafts(&self, limit: i64, skip: i64) -> Page<ProjectDraft> {
+    let total = match self
+        .client
+        .query_one("SELECT COUNT(*) AS total FROM project WHERE deleted_at IS NOT NULL AND status = 'Draft';", &[])
+        .await
+    {
+        Ok(row) => row.get::<&str, i64>("total"),
+        Err(_) => 0,
+    };
+
+    let statement = format!("
+      SELECT p.*, {USER_FIELDS} 
+        FROM project AS p
+          JOIN users AS u ON p.user_id = u.id
+          JOIN user_emails AS e ON e.user_id = p.user_id AND e.is_primary = true
+        WHERE p.deleted_at IS NOT NULL AND p.status = 'Draft'
+        ORDER BY p.deleted_at DESC, p.id LIMIT $1 OFFSET $2;");
+
+    let items = match self.client.query(&statement, &[&limit, &skip]).await {
+        Ok(rows) => rows.iter().map(ProjectDraft::from_row).collect(),
+        Err(_) => vec![],
+    };
+
+    Page { items, total, limit: Some(limit), skip }
+  }
+
+  async fn purge_deleted(&self, days: i32) -> Result<Vec<String>, String> {
+    let res = self
+        .client
+        .query(
+          "DELETE FROM project WHERE deleted_at < NOW() - make_interval(days => $1) RETURNING id;",
+          &[&days],
+        )
+        .await;
+
+    match res {
+        Ok(rows) => Ok(rows.iter().map(|row| row.get::<&str, String>("id")).collect()),
+        Err(err) => match err.as_db_error() {
+            Some(err) => Err(err.message().to_string()),
+            None => Err(err.to_string()),
+        },
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{
+    app::{entities::location::GazetteerEntry, traits::repositories::gazetteer::TGazetteerRepositories},
+    db::{gazetteer::GazetteerRepository, test_support::{bid, connect, project, user}},
+  };
+
+  async fn is_visible(repo: &ProjectRepository, id: &str, scope: ProjectScope) -> bool {
+    repo.find_visible_by_id(id, &scope).await.is_some()
+  }
+
+  #[tokio::test]
+  async fn active_projects_are_visible_to_owners_admins_and_approved_vendors() {
+    let client = match connect().await {
+      Some(client) => client,
+      None => return,
+    };
+    let owner = user(&client, "Client", false).await;
+    let stranger = user(&client, "Client", false).await;
+    let approved = user(&client, "Vendor", true).await;
+    let unapproved = user(&client, "Vendor", false).await;
+    let project_id = project(&client, &owner, "Active").await;
+    let repo = ProjectRepository::new(client.clone());
+
+    assert!(is_visible(&repo, &project_id, ProjectScope::All).await);
+    assert!(is_visible(&repo, &project_id, ProjectScope::Member(owner)).await);
+    assert!(!is_visible(&repo, &project_id, ProjectScope::Member(stranger)).await);
+    assert!(is_visible(&repo, &project_id, ProjectScope::ApprovedVendor(approved)).await);
+    assert!(!is_visible(&repo, &project_id, ProjectScope::Vendor(unapproved)).await);
+  }
+
+  #[tokio::test]
+  async fn started_projects_are_visible_only_to_vendors_involved_in_them() {
+    let client = match connect().await {
+      Some(client) => client,
+      None => return,
+    };
+    let owner = user(&client, "Client", false).await;
+    let bidder = user(&client, "Vendor", false).await;
+    let outsider = user(&client, "Vendor", true).await;
+    let project_id = project(&client, &owner, "Ongoing").await;
+    bid(&client, &project_id, &bidder).await;
+    let repo = ProjectRepository::new(client.clone());
+
+    assert!(is_visible(&repo, &project_id, ProjectScope::Vendor(bidder)).await);
+    assert!(!is_visible(&repo, &project_id, ProjectScope::ApprovedVendor(outsider)).await);
+  }
+
+  #[tokio::test]
+  async fn drafts_are_hidden_from_everyone() {
+    let client = match connect().await {
+      Some(client) => client,
+      None => return,
+    };
+    let owner = user(&client, "Client", false).await;
+    let draft = project(&client, &owner, "Draft").await;
+    let repo = ProjectRepository::new(client.clone());
+
+    assert!(!is_visible(&repo, &draft, ProjectScope::All).await);
+    assert!(!is_visible(&repo, &draft, ProjectScope::Member(owner)).await);
+  }
+
+  #[tokio::test]
+  async fn deleted_projects_move_to_the_trash_until_restored() {
+    let client = match connect().await {
+      Some(client) => client,
+      None => return,
+    };
+    let owner = user(&client, "Client", false).await;
+    let admin = user(&client, "Admin", false).await;
+    let project_id = project(&client, &owner, "Active").await;
+    let repo = ProjectRepository::new(client.clone());
+
+    assert_eq!(repo.soft_delete(&project_id, &owner).await, Ok(true));
+    assert_eq!(repo.soft_delete(&project_id, &owner).await, Ok(false));
+    assert!(repo.find_by_id(&project_id).await.is_none());
+    assert!(!is_visible(&repo, &project_id, ProjectScope::All).await);
+    assert!(repo.find_deleted(100, 0).await.items.iter().any(|p| p.id == project_id));
+
+    assert_eq!(repo.restore(&project_id, &admin).await, Ok(true));
+    assert_eq!(repo.restore(&project_id, &admin).await, Ok(false));
+    assert!(is_visible(&repo, &project_id, ProjectScope::Member(owner)).await);
+    assert!(!repo.find_deleted(100, 0).await.items.iter().any(|p| p.id == project_id));
+  }
+
+  #[tokio::test]
+  async fn deleted_drafts_have_their_own_trash() {
+    let client = match connect().await {
+      Some(client) => client,
+      None => return,
+    };
+    let owner = user(&client, "Client", false).await;
+    let admin = user(&client, "Admin", false).await;
+    let draft = project(&client, &owner, "Draft").await;
+    client.execute("UPDATE project SET city = NULL, budget_range = NULL WHERE id = $1;", &[&draft]).await.unwrap();
+    let repo = ProjectRepository::new(client.clone());
+
+    assert_eq!(repo.soft_delete(&draft, &owner).await, Ok(true));
+    assert!(repo.find_draft_by_id(&draft).await.is_none());
+    assert!(repo.find_deleted_drafts(100, 0).await.items.iter().any(|d| d.id == draft));
+    assert!(!repo.find_deleted(100, 0).await.items.iter().any(|p| p.id == draft));
+
+    assert_eq!(repo.restore(&draft, &admin).await, Ok(true));
+    assert!(repo.find_drafts(&owner).await.iter().any(|d| d.id == draft));
+  }
+
+  #[tokio::test]
+  async fn purge_removes_only_projects_deleted_before_the_retention_period() {
+    let client = match connect().await {
+      Some(client) => client,
+      None => return,
+    };
+    let owner = user(&client, "Client", false).await;
+    let expired = project(&client, &owner, "Active").await;
+    let recent = project(&client, &owner, "Active").await;
+    let repo = ProjectRepository::new(client.clone());
+    repo.soft_delete(&expired, &owner).await.unwrap();
+    repo.soft_delete(&recent, &owner).await.unwrap();
+    client.execute("UPDATE project SET deleted_at = NOW() - INTERVAL '31 days' WHERE id = $1;", &[&expired]).await.unwrap();
+
+    let purged = repo.purge_deleted(30).await.unwrap();
+    assert!(purged.contains(&expired));
+    assert!(!purged.contains(&recent));
+    assert!(repo.find_deleted(100, 0).await.items.iter().any(|p| p.id == recent));
+  }
+
+  fn update_params(value: serde_json::Value) -> UpdateParams {
+    serde_json::from_value(value).unwrap()
+  }
+
+  #[tokio::test]
+  async fn updates_record_only_the_fields_that_changed() {
+    let client = match connect().await {
+      Some(client) => client,
+      None => return,
+    };
+    let owner = user(&client, "Client", false).await;
+    let project_id = project(&client, &owner, "Active").await;
+    client.execute("UPDATE project SET description = 'Open plan' WHERE id = $1;", &[&project_id]).await.unwrap();
+    let repo = ProjectRepository::new(client.clone());
+
+    let params = update_params(serde_json::json!({ "name": "New offices", "city": "Berlin", "description": null }));
+    let mut fields = repo.update(&project_id, &params, Some(&owner)).await.unwrap();
+    fields.sort();
+    assert_eq!(fields, vec!["description", "name"]);
+
+    let changes = repo.find_changes(&project_id).await;
+    let name = changes.iter().find(|c| c.field == "name").unwrap();
+    assert_eq!(name.old_value, serde_json::json!("Test project"));
+    assert_eq!(name.new_value, serde_json::json!("New offices"));
+    assert_eq!(name.actor_id, Some(owner.clone()));
+    let description = changes.iter().find(|c| c.field == "description").unwrap();
+    assert_eq!(description.new_value, Value::Null);
+
+    assert_eq!(repo.update(&project_id, &params, Some(&owner)).await, Ok(vec![]));
+    assert_eq!(repo.find_changes(&project_id).await.len(), 2);
+  }
+
+  #[tokio::test]
+  async fn draft_autosaves_are_not_recorded() {
+    let client = match connect().await {
+      Some(client) => client,
+      None => return,
+    };
+    let owner = user(&client, "Client", false).await;
+    let draft = project(&client, &owner, "Draft").await;
+    let repo = ProjectRepository::new(client.clone());
+
+    let params = update_params(serde_json::json!({ "name": "Renamed draft" }));
+    assert_eq!(repo.update(&draft, &params, Some(&owner)).await, Ok(vec![]));
+    assert_eq!(repo.find_draft_by_id(&draft).await.unwrap().name, "Renamed draft");
+    assert!(repo.find_changes(&draft).await.is_empty());
+  }
+
+  #[tokio::test]
+  async fn projects_are_located_only_from_unambiguous_places() {
+    let client = match connect().await {
+      Some(client) => client,
+      None => return,
+    };
+    let owner = user(&client, "Client", false).await;
+    let unique = project(&client, &owner, "Active").await;
+    let ambiguous = project(&client, &owner, "Active").await;
+    let place = |country: &str, city: &str, latitude: f64, longitude: f64| GazetteerEntry {
+      country_code: country.to_string(),
+      zip_code: "00000".to_string(),
+      city: city.to_string(),
+      latitude,
+      longitude,
+    };
+    GazetteerRepository::new(client.clone())
+      .upsert(&[
+        place("DE", &unique, 52.52, 13.405),
+        place("DE", &ambiguous, 52.52, 13.405),
+        place("FR", &ambiguous, 48.857, 2.352),
+      ])
+      .await
+      .unwrap();
+    client
+      .execute("UPDATE project SET city = id, zip_code = NULL WHERE id = ANY($1);", &[&vec![&unique, &ambiguous]])
+      .await
+      .unwrap();
+    let repo = ProjectRepository::new(client.clone());
+
+    repo.locate(&unique).await;
+    repo.locate(&ambiguous).await;
+
+    let located = repo.find_by_id(&unique).await.unwrap();
+    assert_eq!((located.latitude, located.longitude), (Some(52.52), Some(13.405)));
+    let located = repo.find_by_id(&ambiguous).await.unwrap();
+    assert_eq!((located.latitude, located.longitude), (None, None));
+  }
+
+  #[tokio::test]
+  async fn transition_applies_only_from_the_expected_status() {
+    let client = match connect().await {
+      Some(client) => client,
+      None => return,
+    };
+    let owner = user(&client, "Client", false).await;
+    let project_id = project(&client, &owner, "Ongoing").await;
+    let repo = ProjectRepository::new(client.clone());
+
+    assert_eq!(repo.transition(&project_id, &ProjectStatus::Ongoing, &ProjectStatus::Overdue, None, None).await, Ok(true));
+    assert_eq!(repo.transition(&project_id, &ProjectStatus::Ongoing, &ProjectStatus::Overdue, None, None).await, Ok(false));
+    assert_eq!(repo.find_status_history(&project_id).await.len(), 1);
+  }
+
+  #[tokio::test]
+  async fn only_ongoing_projects_past_their_completion_date_are_overdue() {
+    let client = match connect().await {
+      Some(client) => client,
+      None => return,
+    };
+    let owner = user(&client, "Client", false).await;
+    let late = project(&client, &owner, "Ongoing").await;
+    let on_time = project(&client, &owner, "Ongoing").await;
+    let on_hold = project(&client, &owner, "OnHold").await;
+    client
+      .execute("UPDATE project SET completion_date = NOW() - INTERVAL '1 day' WHERE id = ANY($1);", &[&vec![&late, &on_hold]])
+      .await
+      .unwrap();
+    let repo = ProjectRepository::new(client.clone());
+
+    let overdue: Vec<String> = repo.find_overdue().await.into_iter().map(|p| p.id).collect();
+    assert!(overdue.contains(&late));
+    assert!(!overdue.contains(&on_time));
+    assert!(!overdue.contains(&on_hold));
+  }
+
+  #[tokio::test]
+  async fn watchers_are_recorded_once_and_see_only_visible_projects() {
+    let client = match connect().await {
+      Some(client) => client,
+      None => return,
+    };
+    let owner = user(&client, "Client", false).await;
+    let vendor = user(&client, "Vendor", true).await;
+    let active = project(&client, &owner, "Active").await;
+    let ongoing = project(&client, &owner, "Ongoing").await;
+    let repo = ProjectRepository::new(client.clone());
+
+    assert_eq!(repo.watch(&active, &vendor).await, Ok(true));
+    assert_eq!(repo.watch(&active, &vendor).await, Ok(false));
+    assert_eq!(repo.watch(&ongoing, &vendor).await, Ok(true));
+
+    let watched: Vec<String> = repo
+      .find_watched(&vendor, &ProjectScope::ApprovedVendor(vendor.clone()))
+      .await
+      .into_iter()
+      .map(|p| p.id)
+      .collect();
+    assert_eq!(watched, vec![active.clone()]);
+    assert!(repo.find_watchers(&active).await.iter().any(|u| u.id == vendor));
+    assert!(repo.find_interested_vendors(&active).await.iter().any(|u| u.id == vendor));
+
+    assert_eq!(repo.unwatch(&active, &vendor).await, Ok(true));
+    assert_eq!(repo.unwatch(&active, &vendor).await, Ok(false));
+    assert!(repo.find_watchers(&active).await.is_empty());
+  }
+
+  #[tokio::test]
+  async fn templates_copy_the_project_without_its_location() {
+    let client = match connect().await {
+      Some(client) => client,
+      None => return,
+    };
+    let owner = user(&client, "Client", false).await;
+    let stranger = user(&client, "Client", false).await;
+    let project_id = project(&client, &owner, "Active").await;
+    let repo = ProjectRepository::new(client.clone());
+
+    let template_id = repo.insert_template(&project_id, &owner, "Store fit-out").await.unwrap();
+
+    let template = repo.find_template_by_id(&template_id).await.unwrap();
+    let source = repo.find_by_id(&project_id).await.unwrap();
+    assert_eq!(template.name, "Store fit-out");
+    assert_eq!(template.creator_id, owner);
+    assert_eq!(template.building_type, source.building_type);
+    assert_eq!(template.commercial_work, source.commercial_work);
+    assert!(repo.find_templates(&owner).await.iter().any(|t| t.id == template_id));
+    assert!(!repo.find_templates(&stranger).await.iter().any(|t| t.id == template_id));
+
+    assert_eq!(repo.delete_template(&template_id).await, Ok(true));
+    assert!(repo.find_template_by_id(&template_id).await.is_none());
+  }
+
+  #[tokio::test]
+  async fn only_the_awarded_vendor_is_a_counterparty_of_the_client() {
+    let client = match connect().await {
+      Some(client) => client,
+      None => return,
+    };
+    let owner = user(&client, "Client", false).await;
+    let awarded = user(&client, "Vendor", true).await;
+    let bidder = user(&client, "Vendor", true).await;
+    let project_id = project(&client, &owner, "Complete").await;
+    project(&client, &owner, "Active").await;
+    bid(&client, &project_id, &bidder).await;
+    client
+      .execute("UPDATE project SET vendor_id = $2 WHERE id = $1;", &[&project_id, &awarded])
+      .await
+      .unwrap();
+    let repo = ProjectRepository::new(client.clone());
+
+    assert!(repo.is_counterparty(&owner, &awarded).await);
+    assert!(repo.is_counterparty(&awarded, &owner).await);
+    assert!(!repo.is_counterparty(&owner, &bidder).await);
+    assert_eq!(repo.count_by_creator(&owner, ProjectStatus::Complete).await, 1);
+
+    repo.soft_delete(&project_id, &owner).await.unwrap();
+    assert!(!repo.is_counterparty(&owner, &awarded).await);
+    assert_eq!(repo.count_by_creator(&owner, ProjectStatus::Complete).await, 0);
+  }
+
+  #[tokio::test]
+  async fn listing_filters_sorts_and_pages_projects() {
+    let client = match connect().await {
+      Some(client) => client,
+      None => return,
+    };
+    let owner = user(&client, "Client", false).await;
+    let scope = ProjectScope::Member(owner.clone());
+    let mut ids = vec![];
+    for (name, city, budget) in [
+      ("Bakery 100% organic", "Berlin", "Under $100k"),
+      ("Bakery fit-out", "Hamburg", "Over $5m"),
+      ("Office", "berlin", "Under $100k"),
+    ] {
+      let id = project(&client, &owner, "Active").await;
+      client
+        .execute("UPDATE project SET name = $2, city = $3, budget_range = $4 WHERE id = $1;", &[&id, &name, &city, &budget])
+        .await
+        .unwrap();
+      ids.push(id);
+    }
+    project(&client, &owner, "Draft").await;
+    let repo = ProjectRepository::new(client.clone());
+    let query = |value: serde_json::Value| serde_json::from_value::<GetProjectByQueryParams>(value).unwrap();
+    let names = |page: Page<Project>| page.items.into_iter().map(|p| p.name).collect::<Vec<String>>();
+
+    let page = repo.find_by_query(&query(serde_json::json!({ "sort": "name", "order": "asc" })), &scope, None).await;
+    assert_eq!(page.total, 3);
+    assert_eq!(names(page), vec!["Bakery 100% organic", "Bakery fit-out", "Office"]);
+
+    let page = repo.find_by_query(&query(serde_json::json!({ "name": " BAKERY " })), &scope, None).await;
+    assert_eq!(page.total, 2);
+    let page = repo.find_by_query(&query(serde_json::json!({ "name": "0%" })), &scope, None).await;
+    assert_eq!(names(page), vec!["Bakery 100% organic"]);
+
+    let page = repo.find_by_query(&query(serde_json::json!({ "city": "Berlin", "budgetRange": "Under $100k" })), &scope, None).await;
+    assert_eq!(page.total, 2);
+    let page = repo.find_by_query(&query(serde_json::json!({ "status": "Ongoing" })), &scope, None).await;
+    assert_eq!(page.total, 0);
+
+    let page = repo
+      .find_by_query(&query(serde_json::json!({ "sort": "name", "order": "desc", "limit": 2, "skip": 1 })), &scope, None)
+      .await;
+    assert_eq!(page.total, 3);
+    assert_eq!(names(page), vec!["Bakery fit-out", "Bakery 100% organic"]);
+  }
 }
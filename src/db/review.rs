@@ -0,0 +1,269 @@
+use crate::app::{
+    entities::{audit::AuditRecord, review::{RatingSummary, Review, ReviewScores}},
+    services::review::CreateReviewParams,
+    traits::repositories::review::TReviewRepositories,
+};
+use async_trait::async_trait;
+use std::{sync::Arc, time::SystemTime};
+use tokio_postgres::{Client, Row};
+
+use super::{audit::audit_insert, from_row::profile_from_row};
+
+const REVIEW_FIELDS: &str = "r.*,
+    (r.quality + r.communication + r.timeliness + r.value)::FLOAT8 / 4 AS rating,
+    a.id AS author_user_id,
+    a.first_name AS author_first_name,
+    a.last_name AS author_last_name,
+    a.company AS author_company,
+    a.created_at AS author_created_at,
+    a.type AS author_type,
+    v.id AS vendor_user_id,
+    v.first_name AS vendor_first_name,
+    v.last_name AS vendor_last_name,
+    v.company AS vendor_company,
+    v.created_at AS vendor_created_at,
+    v.type AS vendor_type";
+
+const REVIEW_JOINS: &str = "FROM reviews AS r
+    JOIN users AS a ON a.id = r.author_id
+    JOIN users AS v ON v.id = r.vendor_id";
+
+impl Review {
+    fn from_row(row: &Row) -> Self {
+        Review {
+            id: row.get::<&str, i32>("id"),
+            project_id: row.get::<&str, String>("project_id"),
+            scores: ReviewScores {
+                quality: row.get::<&str, i16>("quality"),
+                communication: row.get::<&str, i16>("communication"),
+                timeliness: row.get::<&str, i16>("timeliness"),
+                value: row.get::<&str, i16>("value"),
+            },
+            rating: row.get::<&str, f64>("rating"),
+            text: row.get::<&str, String>("text"),
+            reply: row.get::<&str, Option<String>>("reply"),
+            replied_at: row
+                .get::<&str, Option<SystemTime>>("replied_at")
+                .map(|date| date.into()),
+            is_hidden: row.get::<&str, bool>("is_hidden"),
+            moderation_note: row.get::<&str, Option<String>>("moderation_note"),
+            created_at: row.get::<&str, SystemTime>("created_at").into(),
+            author: profile_from_row(row, "author"),
+            vendor: profile_from_row(row, "vendor"),
+        }
+    }
+}
+
+pub struct ReviewRepository {
+    client: Arc<Client>,
+}
+
+impl ReviewRepository {
+    pub fn new(client: Arc<Client>) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl TReviewRepositories for ReviewRepository {
+    async fn insert(&self, project_id: &str, author_id: &str, vendor_id: &str, data: &CreateReviewParams) -> Result<i32, String> {
+        let statement = "
+            INSERT INTO reviews (project_id, author_id, vendor_id, quality, communication, timeliness, value, text)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            RETURNING id";
+
+        let res = self
+            .client
+            .query_one(
+                statement,
+                &[
+                    &project_id,
+                    &author_id,
+                    &vendor_id,
+                    &data.quality,
+                    &data.communication,
+                    &data.timeliness,
+                    &data.value,
+                    &data.text,
+                ],
+            )
+            .await;
+
+        match res {
+            Ok(row) => Ok(row.get::<&str, i32>("id")),
+            Err(err) => match err.as_db_error() {
+                Some(err) if err.constraint() == Some("reviews_project_id_key") => {
+                    Err("The project has already been reviewed".to_string())
+                }
+                Some(err) => Err(err.message().to_string()),
+                None => Err(err.to_string()),
+            },
+        }
+    }
+
+    async fn find_by_id(&self, id: i32) -> Option<Review> {
+        let statement = format!("SELECT {REVIEW_FIELDS} {REVIEW_JOINS} WHERE r.id = $1;");
+
+        match self.client.query_one(&statement, &[&id]).await {
+            Ok(row) => Some(Review::from_row(&row)),
+            Err(_) => None,
+        }
+    }
+
+    async fn find_by_project(&self, project_id: &str) -> Option<Review> {
+        let statement = format!("SELECT {REVIEW_FIELDS} {REVIEW_JOINS} WHERE r.project_id = $1;");
+
+        match self.client.query_one(&statement, &[&project_id]).await {
+            Ok(row) => Some(Review::from_row(&row)),
+            Err(_) => None,
+        }
+    }
+
+    async fn find_by_vendor(&self, vendor_id: &str, with_hidden: bool) -> Vec<Review> {
+        let statement = format!("
+            SELECT {REVIEW_FIELDS} {REVIEW_JOINS}
+                WHERE r.vendor_id = $1 AND ($2 OR r.is_hidden = false)
+                ORDER BY r.created_at DESC;");
+
+        match self.client.query(&statement, &[&vendor_id, &with_hidden]).await {
+            Ok(rows) => rows.iter().map(Review::from_row).collect(),
+            Err(_) => vec![],
+        }
+    }
+
+    async fn summary(&self, vendor_id: &str) -> RatingSummary {
+        let statement = "
+            SELECT COUNT(*) AS count,
+                AVG((quality + communication + timeliness + value)::FLOAT8 / 4) AS average,
+                AVG(quality)::FLOAT8 AS quality,
+                AVG(communication)::FLOAT8 AS communication,
+                AVG(timeliness)::FLOAT8 AS timeliness,
+                AVG(value)::FLOAT8 AS value
+            FROM reviews WHERE vendor_id = $1 AND is_hidden = false;";
+
+        match self.client.query_one(statement, &[&vendor_id]).await {
+            Ok(row) => RatingSummary {
+                count: row.get::<&str, i64>("count"),
+                average: row.get::<&str, Option<f64>>("average"),
+                quality: row.get::<&str, Option<f64>>("quality"),
+                communication: row.get::<&str, Option<f64>>("communication"),
+                timeliness: row.get::<&str, Option<f64>>("timeliness"),
+                value: row.get::<&str, Option<f64>>("value"),
+            },
+            Err(_) => RatingSummary::default(),
+        }
+    }
+
+    async fn set_reply(&self, id: i32, reply: &str) -> Result<bool, String> {
+        let res = self
+            .client
+            .execute(
+                "UPDATE reviews SET reply = $2, replied_at = NOW(), updated_at = NOW() WHERE id = $1 AND reply IS NULL;",
+                &[&id, &reply],
+            )
+            .await;
+
+        match res {
+            Ok(row) => Ok(row != 0),
+            Err(err) => match err.as_db_error() {
+                Some(err) => Err(err.message().to_string()),
+                None => Err(err.to_string()),
+            },
+        }
+    }
+
+    async fn set_hidden(&self, id: i32, is_hidden: bool, note: Option<&str>, audit: &AuditRecord) -> Result<bool, String> {
+        let statement = format!("
+            WITH moderated AS (
+                UPDATE reviews SET is_hidden = $2, moderation_note = $3, updated_at = NOW() WHERE id = $1
+                RETURNING id
+            )
+            {};", audit_insert("moderated", 4));
+
+        let res = self
+            .client
+            .execute(
+                &statement,
+                &[&id, &is_hidden, &note, &audit.admin_id, &audit.target_user_id, &audit.action.as_str(), &audit.details],
+            )
+            .await;
+
+        match res {
+            Ok(row) => Ok(row != 0),
+            Err(err) => match err.as_db_error() {
+                Some(err) => Err(err.message().to_string()),
+                None => Err(err.to_string()),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        app::entities::audit::audit_action::AuditAction,
+        db::test_support::{connect, project, user},
+    };
+
+    fn params(quality: i16, communication: i16, timeliness: i16, value: i16) -> CreateReviewParams {
+        CreateReviewParams {
+            quality,
+            communication,
+            timeliness,
+            value,
+            text: "Delivered the fit-out as agreed".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn projects_are_reviewed_once_and_replied_to_once() {
+        let client = match connect().await {
+            Some(client) => client,
+            None => return,
+        };
+        let owner = user(&client, "Client", false).await;
+        let vendor = user(&client, "Vendor", true).await;
+        let project_id = project(&client, &owner, "Complete").await;
+        let repo = ReviewRepository::new(client.clone());
+
+        let id = repo.insert(&project_id, &owner, &vendor, &params(5, 4, 3, 4)).await.unwrap();
+        assert_eq!(
+            repo.insert(&project_id, &owner, &vendor, &params(1, 1, 1, 1)).await,
+            Err("The project has already been reviewed".to_string())
+        );
+        assert_eq!(repo.find_by_project(&project_id).await.unwrap().rating, 4.0);
+
+        assert_eq!(repo.set_reply(id, "Thank you").await, Ok(true));
+        assert_eq!(repo.set_reply(id, "Thanks again").await, Ok(false));
+        assert_eq!(repo.find_by_id(id).await.unwrap().reply.as_deref(), Some("Thank you"));
+    }
+
+    #[tokio::test]
+    async fn hidden_reviews_are_left_out_of_the_summary() {
+        let client = match connect().await {
+            Some(client) => client,
+            None => return,
+        };
+        let admin = user(&client, "Admin", false).await;
+        let owner = user(&client, "Client", false).await;
+        let vendor = user(&client, "Vendor", true).await;
+        let repo = ReviewRepository::new(client.clone());
+        let mut ids = vec![];
+        for scores in [params(5, 5, 5, 5), params(3, 3, 3, 3), params(1, 1, 1, 1)] {
+            let project_id = project(&client, &owner, "Complete").await;
+            ids.push(repo.insert(&project_id, &owner, &vendor, &scores).await.unwrap());
+        }
+
+        let summary = repo.summary(&vendor).await;
+        assert_eq!((summary.count, summary.average), (3, Some(3.0)));
+
+        let audit = AuditRecord::new(&admin, &owner, AuditAction::ModerateReview, serde_json::json!({ "reviewId": ids[2] }));
+        assert_eq!(repo.set_hidden(ids[2], true, Some("Abusive language"), &audit).await, Ok(true));
+
+        let summary = repo.summary(&vendor).await;
+        assert_eq!((summary.count, summary.average, summary.quality), (2, Some(4.0), Some(4.0)));
+        assert_eq!(repo.find_by_vendor(&vendor, false).await.len(), 2);
+        assert_eq!(repo.find_by_vendor(&vendor, true).await.len(), 3);
+    }
+}
@@ -0,0 +1,254 @@
+use crate::app::{
+    entities::saved_search::{frequency::AlertFrequency, SavedSearch, SearchMatch},
+    services::saved_search::{CreateSavedSearchParams, UpdateSavedSearchParams},
+    traits::repositories::saved_search::TSavedSearchRepositories,
+};
+use async_trait::async_trait;
+use serde_json::Value;
+use std::{sync::Arc, time::SystemTime};
+use tokio_postgres::{Client, Row};
+
+use super::from_row::base_user_from_row;
+
+impl SavedSearch {
+    fn from_row(row: &Row) -> Self {
+        SavedSearch {
+            id: row.get::<&str, String>("id"),
+            user_id: row.get::<&str, String>("user_id"),
+            name: row.get::<&str, String>("name"),
+            query: row.get::<&str, Value>("query"),
+            frequency: AlertFrequency::from_str(row.get::<&str, &str>("frequency")).unwrap(),
+            created_at: row.get::<&str, SystemTime>("created_at").into(),
+            updated_at: row.get::<&str, SystemTime>("updated_at").into(),
+        }
+    }
+}
+
+impl SearchMatch {
+    fn from_row(row: &Row) -> Self {
+        SearchMatch {
+            search_name: row.get::<&str, String>("search_name"),
+            project_id: row.get::<&str, String>("project_id"),
+            project_name: row.get::<&str, String>("project_name"),
+            project_city: row.get::<&str, String>("project_city"),
+            user: base_user_from_row(row, "vendor"),
+        }
+    }
+}
+
+pub struct SavedSearchRepository {
+    client: Arc<Client>,
+}
+
+impl SavedSearchRepository {
+    pub fn new(client: Arc<Client>) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl TSavedSearchRepositories for SavedSearchRepository {
+    async fn insert(&self, user_id: &str, data: &CreateSavedSearchParams) -> Result<String, String> {
+        let query = match serde_json::to_value(&data.query) {
+            Ok(query) => query,
+            Err(e) => return Err(e.to_string()),
+        };
+
+        let res = self
+            .client
+            .query_one(
+                "INSERT INTO saved_searches (user_id, name, query, frequency) VALUES ($1, $2, $3, $4) RETURNING id;",
+                &[&user_id, &data.name, &query, &data.frequency],
+            )
+            .await;
+
+        match res {
+            Ok(row) => Ok(row.get::<&str, String>("id")),
+            Err(err) => match err.as_db_error() {
+                Some(err) => Err(err.message().to_string()),
+                None => Err(err.to_string()),
+            },
+        }
+    }
+
+    async fn find_by_id(&self, id: &str) -> Option<SavedSearch> {
+        match self
+            .client
+            .query_one("SELECT * FROM saved_searches WHERE id = $1;", &[&id])
+            .await
+        {
+            Ok(row) => Some(SavedSearch::from_row(&row)),
+            Err(_) => None,
+        }
+    }
+
+    async fn find_by_user(&self, user_id: &str) -> Vec<SavedSearch> {
+        match self
+            .client
+            .query(
+                "SELECT * FROM saved_searches WHERE user_id = $1 ORDER BY created_at DESC;",
+                &[&user_id],
+            )
+            .await
+        {
+            Ok(rows) => rows.iter().map(SavedSearch::from_row).collect(),
+            Err(_) => vec![],
+        }
+    }
+
+    async fn count_by_user(&self, user_id: &str) -> i64 {
+        match self
+            .client
+            .query_one(
+                "SELECT COUNT(*) AS total FROM saved_searches WHERE user_id = $1;",
+                &[&user_id],
+            )
+            .await
+        {
+            Ok(row) => row.get::<&str, i64>("total"),
+            Err(_) => 0,
+        }
+    }
+
+    async fn update(&self, id: &str, data: &UpdateSavedSearchParams) -> Result<bool, String> {
+        let query = match data.query.as_ref().map(serde_json::to_value).transpose() {
+            Ok(query) => query,
+            Err(e) => return Err(e.to_string()),
+        };
+
+        let res = self
+            .client
+            .execute(
+                "UPDATE saved_searches SET
+                    name = COALESCE($2, name),
+                    query = COALESCE($3, query),
+                    frequency = COALESCE($4, frequency),
+                    updated_at = NOW()
+                  WHERE id = $1;",
+                &[&id, &data.name, &query, &data.frequency],
+            )
+            .await;
+
+        match res {
+            Ok(row) => Ok(row != 0),
+            Err(err) => match err.as_db_error() {
+                Some(err) => Err(err.message().to_string()),
+                None => Err(err.to_string()),
+            },
+        }
+    }
+
+    async fn delete(&self, id: &str) -> Result<bool, String> {
+        let res = self
+            .client
+            .execute("DELETE FROM saved_searches WHERE id = $1;", &[&id])
+            .await;
+
+        match res {
+            Ok(row) => Ok(row != 0),
+            Err(err) => match err.as_db_error() {
+                Some(err) => Err(err.message().to_string()),
+                None => Err(err.to_string()),
+            },
+        }
+    }
+
+    async fn find_alerting(&self) -> Vec<SavedSearch> {
+        let statement = "
+            SELECT s.* FROM saved_searches AS s
+                JOIN users AS v ON v.id = s.user_id AND v.type = 'Vendor' AND v.is_compliant = true
+            WHERE s.frequency <> 'Off';";
+
+        match self.client.query(statement, &[]).await {
+            Ok(rows) => rows.iter().map(SavedSearch::from_row).collect(),
+            Err(_) => vec![],
+        }
+    }
+
+    async fn insert_matches(&self, project_id: &str, search_ids: &[&str]) -> Result<Vec<String>, String> {
+        let statement = "
+            INSERT INTO saved_search_matches (search_id, project_id)
+                SELECT UNNEST($2::VARCHAR[]), $1
+            ON CONFLICT ON CONSTRAINT uq_saved_search_matches DO NOTHING
+            RETURNING search_id;";
+
+        let res = self.client.query(statement, &[&project_id, &search_ids]).await;
+
+        match res {
+            Ok(rows) => Ok(rows.iter().map(|row| row.get::<&str, String>("search_id")).collect()),
+            Err(err) => match err.as_db_error() {
+                Some(err) => Err(err.message().to_string()),
+                None => Err(err.to_string()),
+            },
+        }
+    }
+
+    async fn claim_pending_digest(&self) -> Result<Vec<SearchMatch>, String> {
+        let statement = "
+            WITH claimed AS (
+                UPDATE saved_search_matches AS m SET emailed_at = NOW()
+                    FROM saved_searches AS s, project AS p
+                    WHERE m.emailed_at IS NULL
+                        AND s.id = m.search_id AND s.frequency IN ('Daily', 'InstantAndDaily')
+                        AND p.id = m.project_id AND p.status = 'Active' AND p.deleted_at IS NULL
+                RETURNING m.search_id, m.project_id, m.created_at
+            )
+            SELECT m.project_id, s.name AS search_name, p.name AS project_name, p.city AS project_city,
+                ve.email AS vendor_email,
+                v.id AS vendor_user_id,
+                v.first_name AS vendor_first_name,
+                v.last_name AS vendor_last_name,
+                v.company AS vendor_company,
+                v.created_at AS vendor_created_at,
+                v.type AS vendor_type,
+                v.is_compliant AS vendor_is_compliant
+            FROM claimed AS m
+                JOIN saved_searches AS s ON s.id = m.search_id
+                JOIN project AS p ON p.id = m.project_id
+                JOIN users AS v ON v.id = s.user_id
+                JOIN user_emails AS ve ON ve.user_id = v.id AND ve.is_primary = true
+            ORDER BY v.id, m.created_at;";
+
+        match self.client.query(statement, &[]).await {
+            Ok(rows) => Ok(rows.iter().map(SearchMatch::from_row).collect()),
+            Err(err) => match err.as_db_error() {
+                Some(err) => Err(err.message().to_string()),
+                None => Err(err.to_string()),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::test_support::{connect, project, user};
+
+    #[tokio::test]
+    async fn pending_digest_matches_are_claimed_once() {
+        let client = match connect().await {
+            Some(client) => client,
+            None => return,
+        };
+        let owner = user(&client, "Client", false).await;
+        let vendor = user(&client, "Vendor", true).await;
+        let project_id = project(&client, &owner, "Active").await;
+        let row = client
+            .query_one(
+                "INSERT INTO saved_searches (user_id, name, frequency) VALUES ($1, 'Berlin offices', 'Daily') RETURNING id;",
+                &[&vendor],
+            )
+            .await
+            .unwrap();
+        let search_id = row.get::<&str, String>("id");
+        let repo = SavedSearchRepository::new(client.clone());
+
+        assert_eq!(repo.insert_matches(&project_id, &[&search_id]).await, Ok(vec![search_id.clone()]));
+
+        let claimed = repo.claim_pending_digest().await.unwrap();
+        assert!(claimed.iter().any(|m| m.project_id == project_id && m.user.id == vendor));
+
+        let claimed = repo.claim_pending_digest().await.unwrap();
+        assert!(!claimed.iter().any(|m| m.project_id == project_id));
+    }
+}
@@ -0,0 +1,84 @@
+use std::sync::Arc;
+use tokio::sync::OnceCell;
+use tokio_postgres::{Client, NoTls};
+
+static SCHEMA: OnceCell<()> = OnceCell::const_new();
+
+// Database tests are skipped unless DATABASE_URL points at a scratch database
+pub async fn connect() -> Option<Arc<Client>> {
+    let url = match std::env::var("DATABASE_URL") {
+        Ok(url) => url,
+        Err(_) => {
+            eprintln!("DATABASE_URL is not set, skipping database test");
+            return None;
+        }
+    };
+
+    let (client, connection) = tokio_postgres::connect(&url, NoTls).await.expect("Database not created");
+    tokio::spawn(async move {
+        if let Err(e) = connection.await {
+            eprintln!("connection error: {}", e);
+        }
+    });
+
+    SCHEMA
+        .get_or_init(|| async {
+            let schema = std::fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/schema.sql")).expect("Not read file");
+            client.batch_execute(&schema).await.expect("schema should apply");
+        })
+        .await;
+
+    Some(Arc::new(client))
+}
+
+pub async fn user(client: &Client, u_type: &str, is_compliant: bool) -> String {
+    let row = client
+        .query_one(
+            "INSERT INTO users (first_name, last_name, type, is_compliant, password_alg, password_hash)
+                VALUES ('Test', 'User', $1, $2, '', '') RETURNING id;",
+            &[&u_type, &is_compliant],
+        )
+        .await
+        .expect("user should be inserted");
+    let id = row.get::<&str, String>("id");
+
+    client
+        .execute(
+            "INSERT INTO user_emails (user_id, email, is_verified, is_primary) VALUES ($1, $2, true, true);",
+            &[&id, &format!("{}@example.test", id)],
+        )
+        .await
+        .expect("email should be inserted");
+
+    id
+}
+
+pub async fn project(client: &Client, user_id: &str, status: &str) -> String {
+    let row = client
+        .query_one(
+            "INSERT INTO project (name, city, status, user_id, building_type, is_save_carbon, appropriate_status,
+                budget_range, square_range, commercial_work, has_financing_secured, completion_date, architectural_services)
+                VALUES ('Test project', 'Berlin', $2, $1, 'Office', false, 'Ready to Hire',
+                    'Under $100k', 'Under 2,000 sqft', 'Renovation', true, NOW() + INTERVAL '90 days', '{}')
+                RETURNING id;",
+            &[&user_id, &status],
+        )
+        .await
+        .expect("project should be inserted");
+
+    row.get::<&str, String>("id")
+}
+
+pub async fn bid(client: &Client, project_id: &str, vendor_id: &str) -> String {
+    let row = client
+        .query_one(
+            "INSERT INTO bids (project_id, vendor_id, price, duration_days, start_date, message)
+                VALUES ($1, $2, 100000, 30, NOW() + INTERVAL '7 days', 'We can deliver this project')
+                RETURNING id;",
+            &[&project_id, &vendor_id],
+        )
+        .await
+        .expect("bid should be inserted");
+
+    row.get::<&str, String>("id")
+}
@@ -1,11 +1,21 @@
 use crate::app::{
-    entities::user::{user_email::UserEmail, user_token::UserToken, user_type::UserType, User},
+    entities::{
+        audit::AuditRecord,
+        location::UserLocation,
+        page::Page,
+        user::{user_email::UserEmail, user_session::UserSession, user_token::UserToken, user_type::UserType, NewUser, User},
+    },
+    services::user::GetAllParams,
     traits::repositories::user::TUserRepositories,
 };
 use async_trait::async_trait;
 use std::{sync::Arc, time::SystemTime};
 use tokio_postgres::{Client, Row};
 
+use super::audit::audit_insert;
+
+use super::gazetteer::{country, locate};
+
 pub struct UserRepository {
     client: Arc<Client>,
 }
@@ -17,9 +27,12 @@ impl User {
             email: rows[0].get::<&str, String>("email"),
             first_name: rows[0].get::<&str, String>("first_name"),
             last_name: rows[0].get::<&str, String>("last_name"),
+            company: rows[0].get::<&str, Option<String>>("company"),
+            phone: rows[0].get::<&str, Option<String>>("phone"),
             password_alg: rows[0].get::<&str, String>("password_alg"),
             password_hash: rows[0].get::<&str, String>("password_hash"),
             u_type: UserType::from_str(rows[0].get::<&str, String>("type").as_str()),
+            is_compliant: rows[0].get::<&str, bool>("is_compliant"),
             created_at: rows[0].get::<&str, SystemTime>("created_at").into(),
             tokens: rows
                 .iter()
@@ -45,6 +58,16 @@ impl UserEmail {
     }
 }
 
+impl UserSession {
+    fn from_row(row: &Row) -> Self {
+        UserSession {
+            id: row.get::<&str, i32>("id"),
+            used_for: row.get::<&str, String>("type"),
+            created_at: row.get::<&str, SystemTime>("created_at").into(),
+        }
+    }
+}
+
 impl UserRepository {
     pub fn new(client: Arc<Client>) -> Self {
         Self { client }
@@ -53,21 +76,10 @@ impl UserRepository {
 
 #[async_trait]
 impl TUserRepositories for UserRepository {
-    async fn insert(
-        &self,
-        first_name: &str,
-        last_name: &str,
-        phone: Option<&str>,
-        email: &str,
-        p_hash: &str,
-        p_alg: &str,
-        u_type: &str,
-        is_verify: bool,
-        is_primary: bool,
-    ) -> Result<String, String> {
+    async fn insert(&self, data: &NewUser<'_>) -> Result<String, String> {
         let user_insert =
-            "INSERT INTO users (password_alg, password_hash, type, first_name, last_name, phone) 
-                VALUES ($1, $2, $3, $7, $8, $9) RETURNING *";
+            "INSERT INTO users (password_alg, password_hash, type, first_name, last_name, phone, company) 
+                VALUES ($1, $2, $3, $7, $8, $9, $10) RETURNING *";
 
         let email_insert = "
             INSERT INTO user_emails (email, is_primary, is_verified, user_id) 
@@ -78,15 +90,16 @@ impl TUserRepositories for UserRepository {
             .query_one(
                 format!("WITH \"user\" AS ({}) {};", user_insert, email_insert).as_str(),
                 &[
-                    &p_alg,
-                    &p_hash,
-                    &u_type,
-                    &email,
-                    &is_primary,
-                    &is_verify,
-                    &first_name,
-                    &last_name,
-                    &phone,
+                    &data.password_alg,
+                    &data.password_hash,
+                    &data.u_type,
+                    &data.email,
+                    &data.is_primary,
+                    &data.is_verified,
+                    &data.first_name,
+                    &data.last_name,
+                    &data.phone,
+                    &data.company,
                 ],
             )
             .await;
@@ -149,15 +162,12 @@ impl TUserRepositories for UserRepository {
         }
     }
 
-    async fn find(&self, types: Vec<UserType>, value: Option<&str>, limit: Option<i64>, skip: Option<i64>) -> Vec<User> {
+    async fn find(&self, types: Vec<UserType>) -> Vec<User> {
         let user_types: Vec<String> = types.iter().map( | t | t.to_string()).collect();
-        let value = format!("%{}%", value.unwrap_or("").split_whitespace().collect::<String>()).to_lowercase();
-        let statement =
-            format!("SELECT u.*, e.email as email FROM users AS u 
-                JOIN user_emails AS e ON u.id = e.user_id AND u.type = ANY($1) AND lower(CONCAT(u.first_name, u.last_name)) LIKE ($2)
-                LIMIT $3 OFFSET $4;");
+        let statement = "SELECT u.*, e.email as email FROM users AS u 
+                JOIN user_emails AS e ON u.id = e.user_id AND e.is_primary = true AND u.type = ANY($1);";
 
-        let res = self.client.query(&statement, &[&user_types, &value, &limit, &skip]).await;
+        let res = self.client.query(statement, &[&user_types]).await;
         match res {
             Ok(rows) => rows
                 .into_iter()
@@ -167,6 +177,71 @@ impl TUserRepositories for UserRepository {
         }
     }
 
+    async fn search(&self, types: Vec<UserType>, params: &GetAllParams) -> Page<User> {
+        let user_types: Vec<String> = types.iter().map( | t | t.to_string()).collect();
+        let value = params
+            .search
+            .as_deref()
+            .unwrap_or("")
+            .split_whitespace()
+            .collect::<Vec<&str>>()
+            .join(" ")
+            .to_lowercase();
+        let terms: Vec<&str> = value.split(' ').filter(|term| !term.is_empty()).collect();
+        let pattern = format!("%{}%", value.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_"));
+        let order = if params.order.as_deref() == Some("asc") { "ASC" } else { "DESC" };
+        let sort = match params.sort.as_deref() {
+            Some("name") => format!("u.first_name {order}, u.last_name {order}"),
+            Some("rating") => format!("(
+                SELECT AVG((r.quality + r.communication + r.timeliness + r.value)::FLOAT8 / 4)
+                    FROM reviews AS r WHERE r.vendor_id = u.id AND r.is_hidden = false
+            ) {order} NULLS LAST"),
+            Some("reviews") => format!("(
+                SELECT COUNT(*) FROM reviews AS r WHERE r.vendor_id = u.id AND r.is_hidden = false
+            ) {order}"),
+            _ if !value.is_empty() => format!("word_similarity($2, user_search_text(u.first_name, u.last_name, u.company)) DESC, u.created_at {order}"),
+            _ => format!("u.created_at {order}"),
+        };
+
+        let condition = "FROM users AS u 
+            JOIN user_emails AS e ON u.id = e.user_id AND e.is_primary = true
+            WHERE u.type = ANY($1) AND (
+                $2 = ''
+                OR NOT EXISTS (
+                    SELECT 1 FROM unnest($4::text[]) AS t(term)
+                        WHERE strpos(user_search_text(u.first_name, u.last_name, u.company), t.term) = 0
+                            AND (length(t.term) < 4 OR NOT t.term <% user_search_text(u.first_name, u.last_name, u.company))
+                )
+                OR EXISTS (SELECT 1 FROM user_emails AS s WHERE s.user_id = u.id AND lower(s.email) LIKE $3)
+            )";
+
+        let total = match self
+            .client
+            .query_one(&format!("SELECT COUNT(*) AS total {condition};"), &[&user_types, &value, &pattern, &terms])
+            .await
+        {
+            Ok(row) => row.get::<&str, i64>("total"),
+            Err(_) => 0,
+        };
+
+        let statement = format!("SELECT u.*, e.email as email {condition} ORDER BY {sort}, u.id LIMIT $5 OFFSET $6;");
+        let skip = params.skip.unwrap_or(0);
+
+        let res = self
+            .client
+            .query(&statement, &[&user_types, &value, &pattern, &terms, &params.limit, &skip])
+            .await;
+        let items = match res {
+            Ok(rows) => rows
+                .into_iter()
+                .map(|row| User::from_rows(&vec![row]))
+                .collect(),
+            Err(_) =>  vec![]
+        };
+
+        Page { items, total, limit: params.limit, skip }
+    }
+
     async fn update_password(&self, user_id: &str, alg: &str, hash: &str) -> Result<bool, String> {
         let res = self
             .client
@@ -203,6 +278,32 @@ impl TUserRepositories for UserRepository {
         }
     }
 
+    async fn force_verify_email(&self, user_id: &str, email: &str, audit: &AuditRecord) -> Result<bool, String> {
+        let statement = format!("
+            WITH verified AS (
+                UPDATE user_emails SET is_verified = true
+                    WHERE user_id = $1 AND email = $2 AND is_verified = false
+                RETURNING user_id
+            )
+            {};", audit_insert("verified", 3));
+
+        let res = self
+            .client
+            .execute(
+                &statement,
+                &[&user_id, &email, &audit.admin_id, &audit.target_user_id, &audit.action.as_str(), &audit.details],
+            )
+            .await;
+
+        match res {
+            Ok(row) => Ok(row != 0),
+            Err(err) => match err.as_db_error() {
+                Some(err) => Err(err.message().to_string()),
+                None => Err(err.to_string()),
+            },
+        }
+    }
+
     async fn upsert_user_token(
         &self,
         user_id: &str,
@@ -213,7 +314,7 @@ impl TUserRepositories for UserRepository {
             .client
             .execute(
                 "
-                    WITH upsert AS (UPDATE user_tokens SET token = $2 WHERE user_id = $1 AND type = $3 RETURNING *)
+                    WITH upsert AS (UPDATE user_tokens SET token = $2, created_at = NOW() WHERE user_id = $1 AND type = $3 RETURNING *)
                     INSERT INTO user_tokens (user_id, token, type) SELECT $1,$2,$3 WHERE NOT EXISTS (SELECT * FROM upsert);",
                 &[&user_id, &token, &used_for],
             )
@@ -245,4 +346,273 @@ impl TUserRepositories for UserRepository {
             },
         }
     }
+
+    async fn find_emails(&self, user_id: &str) -> Vec<UserEmail> {
+        let res = self
+            .client
+            .query(
+                "SELECT * FROM user_emails WHERE user_id = $1 ORDER BY is_primary DESC, email;",
+                &[&user_id],
+            )
+            .await;
+
+        match res {
+            Ok(rows) => rows.iter().map(UserEmail::from_row).collect(),
+            Err(_) => vec![],
+        }
+    }
+
+    async fn find_sessions(&self, user_id: &str) -> Vec<UserSession> {
+        let res = self
+            .client
+            .query(
+                "SELECT id, type, created_at FROM user_tokens WHERE user_id = $1 AND type = 'WEB' ORDER BY created_at DESC;",
+                &[&user_id],
+            )
+            .await;
+
+        match res {
+            Ok(rows) => rows.iter().map(UserSession::from_row).collect(),
+            Err(_) => vec![],
+        }
+    }
+
+    async fn update_type(&self, user_id: &str, u_type: &str, audit: &AuditRecord) -> Result<bool, String> {
+        let statement = format!("
+            WITH updated AS (
+                UPDATE users SET type = $2, updated_at = NOW() WHERE id = $1
+                RETURNING id
+            )
+            {};", audit_insert("updated", 3));
+
+        let res = self
+            .client
+            .execute(
+                &statement,
+                &[&user_id, &u_type, &audit.admin_id, &audit.target_user_id, &audit.action.as_str(), &audit.details],
+            )
+            .await;
+
+        match res {
+            Ok(row) => Ok(row != 0),
+            Err(err) => match err.as_db_error() {
+                Some(err) => Err(err.message().to_string()),
+                None => Err(err.to_string()),
+            },
+        }
+    }
+
+    async fn update_location(&self, user_id: &str, city: Option<&str>, zip_code: Option<&str>) -> Result<bool, String> {
+        let statement = format!(
+            "UPDATE users SET city = $2, zip_code = $3, (latitude, longitude) = {}, updated_at = NOW() WHERE id = $1;",
+            locate("$3::VARCHAR", "$2::VARCHAR", "$4")
+        );
+
+        let res = self.client.execute(&statement, &[&user_id, &city, &zip_code, &country()]).await;
+
+        match res {
+            Ok(row) => Ok(row != 0),
+            Err(err) => match err.as_db_error() {
+                Some(err) => Err(err.message().to_string()),
+                None => Err(err.to_string()),
+            },
+        }
+    }
+
+    async fn find_location(&self, user_id: &str) -> Option<UserLocation> {
+        let res = self
+            .client
+            .query_one(
+                "SELECT city, zip_code, latitude, longitude FROM users WHERE id = $1;",
+                &[&user_id],
+            )
+            .await;
+
+        match res {
+            Ok(row) => Some(UserLocation {
+                city: row.get::<&str, Option<String>>("city"),
+                zip_code: row.get::<&str, Option<String>>("zip_code"),
+                latitude: row.get::<&str, Option<f64>>("latitude"),
+                longitude: row.get::<&str, Option<f64>>("longitude"),
+            }),
+            Err(_) => None,
+        }
+    }
+
+    async fn locate_missing(&self) -> Result<u64, String> {
+        let statement = format!(
+            "UPDATE users AS u SET (latitude, longitude) = {}
+                WHERE u.latitude IS NULL AND (u.zip_code IS NOT NULL OR u.city IS NOT NULL);",
+            locate("u.zip_code", "u.city", "$1")
+        );
+
+        match self.client.execute(&statement, &[&country()]).await {
+            Ok(rows) => Ok(rows),
+            Err(err) => match err.as_db_error() {
+                Some(err) => Err(err.message().to_string()),
+                None => Err(err.to_string()),
+            },
+        }
+    }
+
+    async fn delete(&self, user_id: &str, audit: &AuditRecord) -> Result<bool, String> {
+        let statement = format!("
+            WITH deleted AS (
+                DELETE FROM users WHERE id = $1
+                RETURNING id
+            )
+            {};", audit_insert("deleted", 2));
+
+        let res = self
+            .client
+            .execute(
+                &statement,
+                &[&user_id, &audit.admin_id, &audit.target_user_id, &audit.action.as_str(), &audit.details],
+            )
+            .await;
+
+        match res {
+            Ok(row) => Ok(row != 0),
+            Err(err) => match err.as_db_error() {
+                Some(err) if err.constraint() == Some("fk_project_creator") => {
+                    Err("The user still owns projects".to_string())
+                }
+                Some(err) => Err(err.message().to_string()),
+                None => Err(err.to_string()),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        app::{entities::audit::{audit_action::AuditAction, AuditEntry}, traits::repositories::audit::TAuditRepositories},
+        db::{audit::AuditRepository, test_support::{connect, project, user}},
+    };
+
+    // Every test tags its users with a fresh company name so that searches ignore rows left by other tests
+    async fn marker(client: &Client) -> String {
+        let row = client.query_one("SELECT 'co' || substr(md5(random()::text), 1, 12) AS marker;", &[]).await.unwrap();
+        row.get::<&str, String>("marker")
+    }
+
+    async fn insert(repo: &UserRepository, first_name: &str, last_name: &str, company: &str, u_type: &str) -> String {
+        let email = format!("{}.{}.{}@{}.test", first_name, last_name, u_type, company).to_lowercase();
+        repo.insert(&NewUser {
+            first_name,
+            last_name,
+            company: Some(company),
+            phone: None,
+            email: &email,
+            password_hash: "",
+            password_alg: "",
+            u_type,
+            is_verified: true,
+            is_primary: true,
+        })
+        .await
+        .unwrap()
+    }
+
+    fn params(search: &str, sort: Option<&str>, order: Option<&str>, limit: Option<i64>) -> GetAllParams {
+        GetAllParams {
+            u_type: None,
+            limit,
+            skip: None,
+            search: Some(search.to_string()),
+            sort: sort.map(|s| s.to_string()),
+            order: order.map(|o| o.to_string()),
+        }
+    }
+
+    fn names(page: &Page<User>) -> Vec<&str> {
+        page.items.iter().map(|u| u.first_name.as_str()).collect()
+    }
+
+    #[tokio::test]
+    async fn search_matches_every_term_and_counts_all_matches() {
+        let client = match connect().await {
+            Some(client) => client,
+            None => return,
+        };
+        let repo = UserRepository::new(client.clone());
+        let company = marker(&client).await;
+        insert(&repo, "Anna", "Schmidt", &company, "Vendor").await;
+        insert(&repo, "Anna", "Weber", &company, "Vendor").await;
+        insert(&repo, "Boris", "Schmidt", &company, "Vendor").await;
+        insert(&repo, "Anna", "Schmidt", &company, "Client").await;
+        let vendors = vec![UserType::Vendor];
+
+        let page = repo.search(vendors.clone(), &params(&format!("{company} anna"), None, None, None)).await;
+        assert_eq!(page.total, 2);
+
+        let page = repo.search(vendors.clone(), &params(&format!("  ANNA   {company}  schmidt "), None, None, None)).await;
+        assert_eq!(page.total, 1);
+        assert_eq!(page.items[0].last_name, "Schmidt");
+
+        let page = repo.search(vendors.clone(), &params(&company, None, None, Some(2))).await;
+        assert_eq!(page.total, 3);
+        assert_eq!(page.items.len(), 2);
+
+        let page = repo.search(vec![UserType::Client, UserType::Vendor], &params(&company, None, None, None)).await;
+        assert_eq!(page.total, 4);
+    }
+
+    #[tokio::test]
+    async fn search_sorts_by_name_in_both_orders() {
+        let client = match connect().await {
+            Some(client) => client,
+            None => return,
+        };
+        let repo = UserRepository::new(client.clone());
+        let company = marker(&client).await;
+        insert(&repo, "Clara", "Fischer", &company, "Client").await;
+        insert(&repo, "Anna", "Fischer", &company, "Client").await;
+        insert(&repo, "Boris", "Fischer", &company, "Client").await;
+        let clients = vec![UserType::Client];
+
+        let page = repo.search(clients.clone(), &params(&company, Some("name"), Some("asc"), None)).await;
+        assert_eq!(names(&page), vec!["Anna", "Boris", "Clara"]);
+
+        let page = repo.search(clients.clone(), &params(&company, Some("name"), Some("desc"), None)).await;
+        assert_eq!(names(&page), vec!["Clara", "Boris", "Anna"]);
+
+        let page = repo.search(clients, &params(&company, Some("createdAt"), Some("asc"), None)).await;
+        assert_eq!(names(&page), vec!["Clara", "Anna", "Boris"]);
+    }
+
+    #[tokio::test]
+    async fn admin_mutations_are_audited_only_when_they_apply() {
+        let client = match connect().await {
+            Some(client) => client,
+            None => return,
+        };
+        let admin = user(&client, "Admin", false).await;
+        let owner = user(&client, "Client", false).await;
+        let target = user(&client, "Client", false).await;
+        project(&client, &owner, "Active").await;
+        let repo = UserRepository::new(client.clone());
+        let audits = AuditRepository::new(client.clone());
+        let record = |target: &str, action: AuditAction| AuditRecord::new(&admin, target, action, serde_json::json!({}));
+        let actions = |entries: Vec<AuditEntry>| entries.into_iter().map(|e| e.action).collect::<Vec<AuditAction>>();
+
+        assert_eq!(repo.update_type(&target, "Vendor", &record(&target, AuditAction::ChangeType)).await, Ok(true));
+        assert_eq!(repo.find_by_id(&target, false).await.unwrap().u_type, UserType::Vendor);
+
+        let email = format!("{}@example.test", target);
+        assert_eq!(repo.force_verify_email(&target, &email, &record(&target, AuditAction::VerifyEmail)).await, Ok(false));
+        assert_eq!(actions(audits.find_by_target(&target).await), vec![AuditAction::ChangeType]);
+
+        assert_eq!(
+            repo.delete(&owner, &record(&owner, AuditAction::DeleteUser)).await,
+            Err("The user still owns projects".to_string())
+        );
+        assert!(audits.find_by_target(&owner).await.is_empty());
+
+        assert_eq!(repo.delete(&target, &record(&target, AuditAction::DeleteUser)).await, Ok(true));
+        assert!(repo.find_by_id(&target, false).await.is_none());
+        assert_eq!(actions(audits.find_by_target(&target).await), vec![AuditAction::DeleteUser, AuditAction::ChangeType]);
+    }
 }
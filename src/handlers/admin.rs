@@ -0,0 +1,145 @@
+use axum::{
+    body::Body,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::{get, patch, post},
+    Json, Router,
+};
+use serde_json::json;
+use std::sync::Arc;
+
+use crate::{
+    app::services::admin::{AdminService, ChangeTypeParams, ModerateReviewParams, TrashParams, VerifyEmailParams},
+    AppState,
+};
+
+use super::extra::{extract::AuthData, json_validate_rejection::JsonInput};
+
+fn admin_service<'a>(state: &'a AppState, token: &'a str) -> AdminService<'a> {
+    AdminService::new(
+        state.db.users.as_ref(),
+        state.db.audit.as_ref(),
+        state.db.reviews.as_ref(),
+        state.db.projects.as_ref(),
+        token,
+    )
+}
+
+pub fn build_routes() -> Router<Arc<AppState>, Body> {
+    Router::new()
+        .route("/admin/users/:id", get(get_user).delete(delete_user))
+        .route("/admin/users/:id/audit", get(get_audit))
+        .route("/admin/users/:id/type", patch(change_type))
+        .route("/admin/users/:id/verify-email", post(verify_email))
+        .route("/admin/users/:id/reset-password", post(reset_password))
+        .route("/admin/reviews/:id", patch(moderate_review))
+        .route("/admin/projects/trash", get(get_trash))
+        .route("/admin/projects/trash/drafts", get(get_draft_trash))
+        .route("/admin/projects/:id/restore", post(restore_project))
+}
+
+async fn get_user(Path(id): Path<String>, State(state): State<Arc<AppState>>, auth: AuthData) -> Response {
+    let service = admin_service(&state, &auth.token);
+
+    match service.get_user(&id).await {
+        Ok(data) => (StatusCode::OK, Json(json!({ "data":  data }))).into_response(),
+        Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+    }
+}
+
+async fn get_audit(Path(id): Path<String>, State(state): State<Arc<AppState>>, auth: AuthData) -> Response {
+    let service = admin_service(&state, &auth.token);
+
+    match service.get_audit(&id).await {
+        Ok(data) => (StatusCode::OK, Json(json!({ "data":  data }))).into_response(),
+        Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+    }
+}
+
+async fn change_type(
+    Path(id): Path<String>,
+    State(state): State<Arc<AppState>>,
+    auth: AuthData,
+    JsonInput(body): JsonInput<ChangeTypeParams>,
+) -> Response {
+    let service = admin_service(&state, &auth.token);
+
+    match service.change_type(&id, body).await {
+        Ok(_) => (StatusCode::OK, Json(json!({ "data":  {} }))).into_response(),
+        Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+    }
+}
+
+async fn verify_email(
+    Path(id): Path<String>,
+    State(state): State<Arc<AppState>>,
+    auth: AuthData,
+    JsonInput(body): JsonInput<VerifyEmailParams>,
+) -> Response {
+    let service = admin_service(&state, &auth.token);
+
+    match service.verify_email(&id, body).await {
+        Ok(_) => (StatusCode::OK, Json(json!({ "data":  {} }))).into_response(),
+        Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+    }
+}
+
+async fn reset_password(Path(id): Path<String>, State(state): State<Arc<AppState>>, auth: AuthData) -> Response {
+    let service = admin_service(&state, &auth.token);
+
+    match service.send_password_reset(&id).await {
+        Ok(_) => (StatusCode::OK, Json(json!({ "data":  {} }))).into_response(),
+        Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+    }
+}
+
+async fn delete_user(Path(id): Path<String>, State(state): State<Arc<AppState>>, auth: AuthData) -> Response {
+    let service = admin_service(&state, &auth.token);
+
+    match service.delete_user(&id).await {
+        Ok(_) => (StatusCode::OK, Json(json!({ "data":  {} }))).into_response(),
+        Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+    }
+}
+
+async fn moderate_review(
+    Path(id): Path<i32>,
+    State(state): State<Arc<AppState>>,
+    auth: AuthData,
+    JsonInput(body): JsonInput<ModerateReviewParams>,
+) -> Response {
+    let service = admin_service(&state, &auth.token);
+
+    match service.moderate_review(id, body).await {
+        Ok(_) => (StatusCode::OK, Json(json!({ "data":  {} }))).into_response(),
+        Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+    }
+}
+
+async fn get_trash(Query(params): Query<TrashParams>, State(state): State<Arc<AppState>>, auth: AuthData) -> Response {
+    let service = admin_service(&state, &auth.token);
+
+    match service.get_trash(params).await {
+        Ok(data) => (StatusCode::OK, Json(json!({ "data":  data }))).into_response(),
+        Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+    }
+}
+
+async fn get_draft_trash(Query(params): Query<TrashParams>, State(state): State<Arc<AppState>>, auth: AuthData) -> Response {
+    let service = admin_service(&state, &auth.token);
+
+    match service.get_draft_trash(params).await {
+        Ok(data) => (StatusCode::OK, Json(json!({ "data":  data }))).into_response(),
+        Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+    }
+}
+
+async fn restore_project(Path(id): Path<String>, State(state): State<Arc<AppState>>, auth: AuthData) -> Response {
+    let service = admin_service(&state, &auth.token);
+
+    match service.restore_project(&id).await {
+        Ok(_) => (StatusCode::OK, Json(json!({ "data":  {} }))).into_response(),
+        Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+    }
+}
@@ -0,0 +1,134 @@
+use axum::{
+    body::Body,
+    extract::{DefaultBodyLimit, Path, Query, State},
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    routing::{get, post},
+    Json, Router,
+};
+use serde::Deserialize;
+use serde_json::json;
+use std::sync::Arc;
+
+use crate::{
+    app::services::attachment::{AttachmentService, MAX_ATTACHMENT_SIZE},
+    AppState,
+};
+
+use super::extra::{extract::AuthData, upload::UploadInput};
+
+#[derive(Deserialize)]
+pub struct DownloadParams {
+    token: String,
+}
+
+fn attachment_service<'a>(state: &'a AppState, token: &'a str) -> AttachmentService<'a> {
+    AttachmentService::new(
+        state.db.users.as_ref(),
+        state.db.projects.as_ref(),
+        state.db.organizations.as_ref(),
+        state.db.attachments.as_ref(),
+        state.db.bids.as_ref(),
+        token,
+    )
+}
+
+pub fn build_routes() -> Router<Arc<AppState>, Body> {
+    Router::new()
+        .route("/projects/:id/attachments", get(get_attachments).post(post_attachment))
+        .route(
+            "/projects/:id/attachments/:file_id/versions",
+            get(get_versions).post(post_version),
+        )
+        .route("/bids/:id/attachments", post(post_bid_attachment))
+        .layer(DefaultBodyLimit::max(MAX_ATTACHMENT_SIZE + 64 * 1024))
+        .route("/attachments/:id/download", get(download))
+}
+
+async fn post_attachment(
+    Path(id): Path<String>,
+    State(state): State<Arc<AppState>>,
+    auth: AuthData,
+    UploadInput(body): UploadInput,
+) -> Response {
+    let service = attachment_service(&state, &auth.token);
+
+    match service.upload(&id, body).await {
+        Ok(data) => (StatusCode::OK, Json(json!({ "data":  data }))).into_response(),
+        Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+    }
+}
+
+async fn post_bid_attachment(
+    Path(id): Path<String>,
+    State(state): State<Arc<AppState>>,
+    auth: AuthData,
+    UploadInput(body): UploadInput,
+) -> Response {
+    let service = attachment_service(&state, &auth.token);
+
+    match service.upload_to_bid(&id, body).await {
+        Ok(data) => (StatusCode::OK, Json(json!({ "data":  data }))).into_response(),
+        Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+    }
+}
+
+async fn get_attachments(Path(id): Path<String>, State(state): State<Arc<AppState>>, auth: AuthData) -> Response {
+    let service = attachment_service(&state, &auth.token);
+
+    match service.get_by_project(&id).await {
+        Ok(data) => (StatusCode::OK, Json(json!({ "data":  data }))).into_response(),
+        Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+    }
+}
+
+async fn post_version(
+    Path((id, file_id)): Path<(String, String)>,
+    State(state): State<Arc<AppState>>,
+    auth: AuthData,
+    UploadInput(body): UploadInput,
+) -> Response {
+    let service = attachment_service(&state, &auth.token);
+
+    match service.replace(&id, &file_id, body).await {
+        Ok(data) => (StatusCode::OK, Json(json!({ "data":  data }))).into_response(),
+        Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+    }
+}
+
+async fn get_versions(
+    Path((id, file_id)): Path<(String, String)>,
+    State(state): State<Arc<AppState>>,
+    auth: AuthData,
+) -> Response {
+    let service = attachment_service(&state, &auth.token);
+
+    match service.get_versions(&id, &file_id).await {
+        Ok(data) => (StatusCode::OK, Json(json!({ "data":  data }))).into_response(),
+        Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+    }
+}
+
+async fn download(
+    Path(id): Path<String>,
+    Query(params): Query<DownloadParams>,
+    State(state): State<Arc<AppState>>,
+) -> Response {
+    let service = attachment_service(&state, "");
+
+    match service.download(&id, &params.token).await {
+        Ok((attachment, data)) => (
+            StatusCode::OK,
+            [
+                (header::CONTENT_TYPE, attachment.content_type),
+                (
+                    header::CONTENT_DISPOSITION,
+                    format!("attachment; filename=\"{}\"", attachment.name.replace('"', "")),
+                ),
+            ],
+            data,
+        )
+            .into_response(),
+        Err(err) => (StatusCode::FORBIDDEN, Json(json!({ "data":  err }))).into_response(),
+    }
+}
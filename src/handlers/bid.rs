@@ -0,0 +1,150 @@
+use axum::{
+    body::Body,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::{get, post, put},
+    Json, Router,
+};
+use serde_json::json;
+use std::sync::Arc;
+
+use crate::{
+    app::services::bid::{AwardParams, BidService, CompareBidsParams, CreateBidParams, UpdateBidParams},
+    AppState,
+};
+
+use super::extra::{extract::AuthData, json_validate_rejection::JsonInput};
+
+fn bid_service<'a>(state: &'a AppState, token: &'a str) -> BidService<'a> {
+    BidService::new(
+        state.db.users.as_ref(),
+        state.db.notifications.as_ref(),
+        state.db.projects.as_ref(),
+        state.db.organizations.as_ref(),
+        state.db.preferences.as_ref(),
+        state.db.bids.as_ref(),
+        token,
+    )
+}
+
+pub fn build_routes() -> Router<Arc<AppState>, Body> {
+    Router::new()
+        .route("/projects/:id/bids", get(get_project_bids).post(post_bid))
+        .route("/projects/:id/bids/compare", get(get_comparison))
+        .route("/bids", get(get_current_bids))
+        .route("/bids/:id", get(get_bid).patch(patch_bid))
+        .route("/bids/:id/withdraw", post(post_withdraw))
+        .route("/bids/:id/shortlist", put(put_shortlist).delete(delete_shortlist))
+        .route("/bids/:id/award", post(post_award))
+}
+
+async fn post_bid(
+    Path(id): Path<String>,
+    State(state): State<Arc<AppState>>,
+    auth: AuthData,
+    JsonInput(body): JsonInput<CreateBidParams>,
+) -> Response {
+    let service = bid_service(&state, &auth.token);
+
+    match service.create(&id, body).await {
+        Ok(data) => (StatusCode::OK, Json(json!({ "data":  data }))).into_response(),
+        Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+    }
+}
+
+async fn get_project_bids(Path(id): Path<String>, State(state): State<Arc<AppState>>, auth: AuthData) -> Response {
+    let service = bid_service(&state, &auth.token);
+
+    match service.get_by_project(&id).await {
+        Ok(data) => (StatusCode::OK, Json(json!({ "data":  data }))).into_response(),
+        Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+    }
+}
+
+async fn get_current_bids(State(state): State<Arc<AppState>>, auth: AuthData) -> Response {
+    let service = bid_service(&state, &auth.token);
+
+    match service.get_current().await {
+        Ok(data) => (StatusCode::OK, Json(json!({ "data":  data }))).into_response(),
+        Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+    }
+}
+
+async fn get_bid(Path(id): Path<String>, State(state): State<Arc<AppState>>, auth: AuthData) -> Response {
+    let service = bid_service(&state, &auth.token);
+
+    match service.get_by_id(&id).await {
+        Ok(data) => (StatusCode::OK, Json(json!({ "data":  data }))).into_response(),
+        Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+    }
+}
+
+async fn patch_bid(
+    Path(id): Path<String>,
+    State(state): State<Arc<AppState>>,
+    auth: AuthData,
+    JsonInput(body): JsonInput<UpdateBidParams>,
+) -> Response {
+    let service = bid_service(&state, &auth.token);
+
+    match service.update(&id, body).await {
+        Ok(_) => (StatusCode::OK, Json(json!({ "data":  {} }))).into_response(),
+        Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+    }
+}
+
+async fn post_withdraw(Path(id): Path<String>, State(state): State<Arc<AppState>>, auth: AuthData) -> Response {
+    let service = bid_service(&state, &auth.token);
+
+    match service.withdraw(&id).await {
+        Ok(_) => (StatusCode::OK, Json(json!({ "data":  {} }))).into_response(),
+        Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+    }
+}
+
+async fn put_shortlist(Path(id): Path<String>, State(state): State<Arc<AppState>>, auth: AuthData) -> Response {
+    let service = bid_service(&state, &auth.token);
+
+    match service.shortlist(&id, true).await {
+        Ok(_) => (StatusCode::OK, Json(json!({ "data":  {} }))).into_response(),
+        Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+    }
+}
+
+async fn delete_shortlist(Path(id): Path<String>, State(state): State<Arc<AppState>>, auth: AuthData) -> Response {
+    let service = bid_service(&state, &auth.token);
+
+    match service.shortlist(&id, false).await {
+        Ok(_) => (StatusCode::OK, Json(json!({ "data":  {} }))).into_response(),
+        Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+    }
+}
+
+async fn get_comparison(
+    Path(id): Path<String>,
+    Query(params): Query<CompareBidsParams>,
+    State(state): State<Arc<AppState>>,
+    auth: AuthData,
+) -> Response {
+    let service = bid_service(&state, &auth.token);
+
+    match service.compare(&id, params).await {
+        Ok(data) => (StatusCode::OK, Json(json!({ "data":  data }))).into_response(),
+        Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+    }
+}
+
+async fn post_award(
+    Path(id): Path<String>,
+    State(state): State<Arc<AppState>>,
+    auth: AuthData,
+    JsonInput(body): JsonInput<AwardParams>,
+) -> Response {
+    let service = bid_service(&state, &auth.token);
+
+    match service.award(&id, body).await {
+        Ok(_) => (StatusCode::OK, Json(json!({ "data":  {} }))).into_response(),
+        Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+    }
+}
@@ -0,0 +1,61 @@
+use axum::{
+    body::Body,
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::get,
+    Json, Router,
+};
+use serde_json::json;
+use std::sync::Arc;
+
+use crate::{
+    app::services::compliance::{ComplianceService, UpsertDocumentParams},
+    AppState,
+};
+
+use super::extra::{extract::AuthData, json_validate_rejection::JsonInput};
+
+pub fn build_routes() -> Router<Arc<AppState>, Body> {
+    Router::new()
+        .route(
+            "/users/current/compliance",
+            get(get_current_documents).put(put_document),
+        )
+        .route("/users/:id/compliance", get(get_user_documents))
+}
+
+async fn get_current_documents(State(state): State<Arc<AppState>>, auth: AuthData) -> Response {
+    let service = ComplianceService::new(state.db.users.as_ref(), state.db.compliance.as_ref(), &auth.token);
+
+    match service.get_for_current_user().await {
+        Ok(data) => (StatusCode::OK, Json(json!({ "data":  data }))).into_response(),
+        Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+    }
+}
+
+async fn put_document(
+    State(state): State<Arc<AppState>>,
+    auth: AuthData,
+    JsonInput(body): JsonInput<UpsertDocumentParams>,
+) -> Response {
+    let service = ComplianceService::new(state.db.users.as_ref(), state.db.compliance.as_ref(), &auth.token);
+
+    match service.upsert_document(body).await {
+        Ok(id) => (StatusCode::OK, Json(json!({"data": { "id": id }}))).into_response(),
+        Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+    }
+}
+
+async fn get_user_documents(
+    Path(id): Path<String>,
+    State(state): State<Arc<AppState>>,
+    auth: AuthData,
+) -> Response {
+    let service = ComplianceService::new(state.db.users.as_ref(), state.db.compliance.as_ref(), &auth.token);
+
+    match service.get_by_user(&id).await {
+        Ok(data) => (StatusCode::OK, Json(json!({ "data":  data }))).into_response(),
+        Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+    }
+}
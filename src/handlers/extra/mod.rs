@@ -1,2 +1,3 @@
 pub mod extract;
 pub mod json_validate_rejection;
+pub mod upload;
@@ -0,0 +1,74 @@
+use axum::{
+    async_trait,
+    body::Body,
+    extract::{FromRequest, Multipart},
+    http::{Request, StatusCode},
+};
+use serde_json::{json, Value};
+
+use crate::app::services::attachment::UploadParams;
+
+pub struct UploadInput(pub UploadParams);
+
+#[async_trait]
+impl<S> FromRequest<S, Body> for UploadInput
+where
+    S: Send + Sync,
+{
+    type Rejection = (StatusCode, axum::Json<Value>);
+
+    async fn from_request(req: Request<Body>, state: &S) -> Result<Self, Self::Rejection> {
+        let mut multipart = match Multipart::from_request(req, state).await {
+            Ok(multipart) => multipart,
+            Err(rejection) => return Err(reject(rejection.status(), rejection.body_text())),
+        };
+
+        let mut category = None;
+        let mut file = None;
+
+        loop {
+            let field = match multipart.next_field().await {
+                Ok(Some(field)) => field,
+                Ok(None) => break,
+                Err(err) => return Err(reject(err.status(), err.body_text())),
+            };
+
+            match field.name() {
+                Some("category") => match field.text().await {
+                    Ok(text) => category = Some(text),
+                    Err(err) => return Err(reject(err.status(), err.body_text())),
+                },
+                Some("file") => {
+                    let name = field.file_name().unwrap_or("file").to_string();
+                    let content_type = field
+                        .content_type()
+                        .unwrap_or("application/octet-stream")
+                        .to_string();
+
+                    match field.bytes().await {
+                        Ok(data) => file = Some((name, content_type, data.to_vec())),
+                        Err(err) => return Err(reject(err.status(), err.body_text())),
+                    }
+                }
+                _ => (),
+            }
+        }
+
+        match (category, file) {
+            (Some(category), Some((name, content_type, data))) => Ok(Self(UploadParams {
+                category,
+                name,
+                content_type,
+                data,
+            })),
+            _ => Err(reject(
+                StatusCode::BAD_REQUEST,
+                "The category and file fields are required".to_string(),
+            )),
+        }
+    }
+}
+
+fn reject(status: StatusCode, message: String) -> (StatusCode, axum::Json<Value>) {
+    (status, axum::Json(json!({ "data": {"message": message} })))
+}
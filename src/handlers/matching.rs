@@ -0,0 +1,75 @@
+use axum::{
+    body::Body,
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::get,
+    Json, Router,
+};
+use serde_json::json;
+use std::sync::Arc;
+
+use crate::{
+    app::services::matching::{MatchingService, RecommendationParams, UpdateCapabilitiesParams},
+    AppState,
+};
+
+use super::extra::{extract::AuthData, json_validate_rejection::JsonInput};
+
+fn matching_service<'a>(state: &'a AppState, token: &'a str) -> MatchingService<'a> {
+    MatchingService::new(
+        state.db.users.as_ref(),
+        state.db.projects.as_ref(),
+        state.db.capabilities.as_ref(),
+        state.db.organizations.as_ref(),
+        token,
+    )
+}
+
+pub fn build_routes() -> Router<Arc<AppState>, Body> {
+    Router::new()
+        .route("/users/current/capabilities", get(get_capabilities).put(put_capabilities))
+        .route("/projects/recommended", get(get_recommended_projects))
+        .route("/projects/:id/recommended-vendors", get(get_recommended_vendors))
+}
+
+async fn get_capabilities(State(state): State<Arc<AppState>>, auth: AuthData) -> Response {
+    let service = matching_service(&state, &auth.token);
+
+    match service.get_capabilities().await {
+        Ok(capabilities) => (StatusCode::OK, Json(json!({ "data":  capabilities }))).into_response(),
+        Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+    }
+}
+
+async fn put_capabilities(State(state): State<Arc<AppState>>, auth: AuthData, JsonInput(body): JsonInput<UpdateCapabilitiesParams>) -> Response {
+    let service = matching_service(&state, &auth.token);
+
+    match service.update_capabilities(body).await {
+        Ok(capabilities) => (StatusCode::OK, Json(json!({ "data":  capabilities }))).into_response(),
+        Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+    }
+}
+
+async fn get_recommended_projects(Query(params): Query<RecommendationParams>, State(state): State<Arc<AppState>>, auth: AuthData) -> Response {
+    let service = matching_service(&state, &auth.token);
+
+    match service.recommend_projects(params).await {
+        Ok(projects) => (StatusCode::OK, Json(json!({ "data":  projects }))).into_response(),
+        Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+    }
+}
+
+async fn get_recommended_vendors(
+    Path(id): Path<String>,
+    Query(params): Query<RecommendationParams>,
+    State(state): State<Arc<AppState>>,
+    auth: AuthData,
+) -> Response {
+    let service = matching_service(&state, &auth.token);
+
+    match service.recommend_vendors(&id, params).await {
+        Ok(vendors) => (StatusCode::OK, Json(json!({ "data":  vendors }))).into_response(),
+        Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+    }
+}
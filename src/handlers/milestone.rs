@@ -0,0 +1,145 @@
+use axum::{
+    body::Body,
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::{get, patch, post, put},
+    Json, Router,
+};
+use serde_json::json;
+use std::sync::Arc;
+
+use crate::{
+    app::services::milestone::{
+        CreateMilestoneParams, CreateTaskParams, MilestoneService, ReorderParams, UpdateMilestoneParams,
+        UpdateTaskParams,
+    },
+    AppState,
+};
+
+use super::extra::{extract::AuthData, json_validate_rejection::JsonInput};
+
+fn milestone_service<'a>(state: &'a AppState, token: &'a str) -> MilestoneService<'a> {
+    MilestoneService::new(
+        state.db.users.as_ref(),
+        state.db.notifications.as_ref(),
+        state.db.projects.as_ref(),
+        state.db.organizations.as_ref(),
+        state.db.preferences.as_ref(),
+        state.db.milestones.as_ref(),
+        token,
+    )
+}
+
+pub fn build_routes() -> Router<Arc<AppState>, Body> {
+    Router::new()
+        .route("/projects/:id/milestones", get(get_milestones).post(post_milestone))
+        .route("/projects/:id/milestones/order", put(put_order))
+        .route("/milestones/:id", patch(patch_milestone))
+        .route("/milestones/:id/complete", post(post_complete))
+        .route("/milestones/:id/tasks", post(post_task))
+        .route("/milestones/:id/tasks/order", put(put_task_order))
+        .route("/milestones/:id/tasks/:task_id", patch(patch_task))
+}
+
+async fn post_milestone(
+    Path(id): Path<String>,
+    State(state): State<Arc<AppState>>,
+    auth: AuthData,
+    JsonInput(body): JsonInput<CreateMilestoneParams>,
+) -> Response {
+    let service = milestone_service(&state, &auth.token);
+
+    match service.create(&id, body).await {
+        Ok(data) => (StatusCode::OK, Json(json!({ "data":  data }))).into_response(),
+        Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+    }
+}
+
+async fn get_milestones(Path(id): Path<String>, State(state): State<Arc<AppState>>, auth: AuthData) -> Response {
+    let service = milestone_service(&state, &auth.token);
+
+    match service.get_by_project(&id).await {
+        Ok(data) => (StatusCode::OK, Json(json!({ "data":  data }))).into_response(),
+        Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+    }
+}
+
+async fn put_order(
+    Path(id): Path<String>,
+    State(state): State<Arc<AppState>>,
+    auth: AuthData,
+    JsonInput(body): JsonInput<ReorderParams<String>>,
+) -> Response {
+    let service = milestone_service(&state, &auth.token);
+
+    match service.reorder(&id, body).await {
+        Ok(_) => (StatusCode::OK, Json(json!({ "data":  {} }))).into_response(),
+        Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+    }
+}
+
+async fn patch_milestone(
+    Path(id): Path<String>,
+    State(state): State<Arc<AppState>>,
+    auth: AuthData,
+    JsonInput(body): JsonInput<UpdateMilestoneParams>,
+) -> Response {
+    let service = milestone_service(&state, &auth.token);
+
+    match service.update(&id, body).await {
+        Ok(_) => (StatusCode::OK, Json(json!({ "data":  {} }))).into_response(),
+        Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+    }
+}
+
+async fn post_complete(Path(id): Path<String>, State(state): State<Arc<AppState>>, auth: AuthData) -> Response {
+    let service = milestone_service(&state, &auth.token);
+
+    match service.complete(&id).await {
+        Ok(_) => (StatusCode::OK, Json(json!({ "data":  {} }))).into_response(),
+        Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+    }
+}
+
+async fn post_task(
+    Path(id): Path<String>,
+    State(state): State<Arc<AppState>>,
+    auth: AuthData,
+    JsonInput(body): JsonInput<CreateTaskParams>,
+) -> Response {
+    let service = milestone_service(&state, &auth.token);
+
+    match service.add_task(&id, body).await {
+        Ok(data) => (StatusCode::OK, Json(json!({ "data":  data }))).into_response(),
+        Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+    }
+}
+
+async fn put_task_order(
+    Path(id): Path<String>,
+    State(state): State<Arc<AppState>>,
+    auth: AuthData,
+    JsonInput(body): JsonInput<ReorderParams<i32>>,
+) -> Response {
+    let service = milestone_service(&state, &auth.token);
+
+    match service.reorder_tasks(&id, body).await {
+        Ok(_) => (StatusCode::OK, Json(json!({ "data":  {} }))).into_response(),
+        Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+    }
+}
+
+async fn patch_task(
+    Path((id, task_id)): Path<(String, i32)>,
+    State(state): State<Arc<AppState>>,
+    auth: AuthData,
+    JsonInput(body): JsonInput<UpdateTaskParams>,
+) -> Response {
+    let service = milestone_service(&state, &auth.token);
+
+    match service.update_task(&id, task_id, body).await {
+        Ok(_) => (StatusCode::OK, Json(json!({ "data":  {} }))).into_response(),
+        Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+    }
+}
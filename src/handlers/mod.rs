@@ -1,7 +1,17 @@
+pub mod admin;
+pub mod attachment;
 pub mod auth;
+pub mod bid;
+pub mod compliance;
+pub mod milestone;
 pub mod conversation;
 pub mod extra;
+pub mod matching;
 pub mod notification;
+pub mod organization;
+pub mod preferences;
+pub mod review;
+pub mod saved_search;
 pub mod user;
 pub mod ws;
-pub mod project;
\ No newline at end of file
+pub mod project;
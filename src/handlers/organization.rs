@@ -0,0 +1,106 @@
+use axum::{
+    body::Body,
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::{get, patch, post},
+    Json, Router,
+};
+use serde_json::json;
+use std::sync::Arc;
+
+use crate::{
+    app::services::organization::{
+        AddMemberParams, CreateOrganizationParams, OrganizationService, UpdateMemberParams,
+    },
+    AppState,
+};
+
+use super::extra::{extract::AuthData, json_validate_rejection::JsonInput};
+
+fn organization_service<'a>(state: &'a AppState, token: &'a str) -> OrganizationService<'a> {
+    OrganizationService::new(state.db.users.as_ref(), state.db.organizations.as_ref(), token)
+}
+
+pub fn build_routes() -> Router<Arc<AppState>, Body> {
+    Router::new()
+        .route("/organizations", post(post_organization).get(get_organizations))
+        .route("/organizations/:id", get(get_organization))
+        .route("/organizations/:id/members", post(post_member))
+        .route(
+            "/organizations/:id/members/:user_id",
+            patch(patch_member).delete(delete_member),
+        )
+}
+
+async fn post_organization(
+    State(state): State<Arc<AppState>>,
+    auth: AuthData,
+    JsonInput(body): JsonInput<CreateOrganizationParams>,
+) -> Response {
+    let service = organization_service(&state, &auth.token);
+
+    match service.create(body).await {
+        Ok(data) => (StatusCode::OK, Json(json!({ "data":  data }))).into_response(),
+        Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+    }
+}
+
+async fn get_organizations(State(state): State<Arc<AppState>>, auth: AuthData) -> Response {
+    let service = organization_service(&state, &auth.token);
+
+    match service.get_for_current_user().await {
+        Ok(data) => (StatusCode::OK, Json(json!({ "data":  data }))).into_response(),
+        Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+    }
+}
+
+async fn get_organization(Path(id): Path<String>, State(state): State<Arc<AppState>>, auth: AuthData) -> Response {
+    let service = organization_service(&state, &auth.token);
+
+    match service.get_by_id(&id).await {
+        Ok(data) => (StatusCode::OK, Json(json!({ "data":  data }))).into_response(),
+        Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+    }
+}
+
+async fn post_member(
+    Path(id): Path<String>,
+    State(state): State<Arc<AppState>>,
+    auth: AuthData,
+    JsonInput(body): JsonInput<AddMemberParams>,
+) -> Response {
+    let service = organization_service(&state, &auth.token);
+
+    match service.add_member(&id, body).await {
+        Ok(_) => (StatusCode::OK, Json(json!({ "data":  {} }))).into_response(),
+        Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+    }
+}
+
+async fn patch_member(
+    Path((id, user_id)): Path<(String, String)>,
+    State(state): State<Arc<AppState>>,
+    auth: AuthData,
+    JsonInput(body): JsonInput<UpdateMemberParams>,
+) -> Response {
+    let service = organization_service(&state, &auth.token);
+
+    match service.update_member(&id, &user_id, body).await {
+        Ok(_) => (StatusCode::OK, Json(json!({ "data":  {} }))).into_response(),
+        Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+    }
+}
+
+async fn delete_member(
+    Path((id, user_id)): Path<(String, String)>,
+    State(state): State<Arc<AppState>>,
+    auth: AuthData,
+) -> Response {
+    let service = organization_service(&state, &auth.token);
+
+    match service.remove_member(&id, &user_id).await {
+        Ok(_) => (StatusCode::OK, Json(json!({ "data":  {} }))).into_response(),
+        Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+    }
+}
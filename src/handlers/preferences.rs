@@ -0,0 +1,46 @@
+use axum::{
+    body::Body,
+    extract::State,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::get,
+    Json, Router,
+};
+use serde_json::json;
+use std::sync::Arc;
+
+use crate::{
+    app::services::preferences::{PreferenceService, UpdatePreferencesParams},
+    AppState,
+};
+
+use super::extra::{extract::AuthData, json_validate_rejection::JsonInput};
+
+pub fn build_routes() -> Router<Arc<AppState>, Body> {
+    Router::new().route(
+        "/users/current/preferences",
+        get(get_preferences).patch(patch_preferences),
+    )
+}
+
+async fn get_preferences(State(state): State<Arc<AppState>>, auth: AuthData) -> Response {
+    let service = PreferenceService::new(state.db.users.as_ref(), state.db.preferences.as_ref(), &auth.token);
+
+    match service.get_for_current_user().await {
+        Ok(data) => (StatusCode::OK, Json(json!({ "data":  data }))).into_response(),
+        Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+    }
+}
+
+async fn patch_preferences(
+    State(state): State<Arc<AppState>>,
+    auth: AuthData,
+    JsonInput(body): JsonInput<UpdatePreferencesParams>,
+) -> Response {
+    let service = PreferenceService::new(state.db.users.as_ref(), state.db.preferences.as_ref(), &auth.token);
+
+    match service.update_for_current_user(body).await {
+        Ok(data) => (StatusCode::OK, Json(json!({ "data":  data }))).into_response(),
+        Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+    }
+}
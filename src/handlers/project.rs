@@ -1,32 +1,55 @@
 use axum::{
-    body::Body,
+    body::{Body, StreamBody},
     extract::{State, Path, Query},
-    http::StatusCode,
+    http::{header, StatusCode},
     response::{IntoResponse, Response},
     Json, Router,   
-    routing::{post, get},
+    routing::{post, get, patch, put, delete},
 };
+use futures::stream::{self, StreamExt};
 use serde_json::json;
 use std::sync::Arc;
 
-use crate::{AppState, app::services::project::{CreateParams, ProjectService, GetProjectByQueryParams}};
+use crate::{AppState, app::services::project::{CreateDraftParams, CreateParams, ExportParams, ProjectService, SaveTemplateParams, GetProjectByQueryParams, TransitionParams, UpdateOrganizationParams, UpdateParams}};
 
 use super::extra::{extract::AuthData, json_validate_rejection::JsonInput};
 
+fn project_service<'a>(state: &'a AppState, token: &'a str) -> ProjectService<'a> {
+    ProjectService::new(
+      state.db.users.as_ref(), 
+      state.db.notifications.as_ref(), 
+      state.db.projects.as_ref(), 
+      state.db.preferences.as_ref(), 
+      state.db.organizations.as_ref(), 
+      state.db.attachments.as_ref(), 
+      token)
+}
+
 pub fn build_routes() -> Router<Arc<AppState>, Body> {
     Router::new()
     .route("/projects", post(post_project))
     .route("/projects", get(get_projects))
-    .route("/projects/:id", get(get_project))
+    .route("/projects/drafts", post(post_draft).get(get_drafts))
+    .route("/projects/drafts/:id", get(get_draft).patch(patch_draft))
+    .route("/projects/watched", get(get_watched_projects))
+    .route("/projects/export", get(export_projects))
+    .route("/projects/:id", get(get_project).patch(patch_project).delete(delete_project))
+    .route("/projects/:id/archive", put(put_archive).delete(delete_archive))
+    .route("/projects/:id/watch", put(put_watch).delete(delete_watch))
+    .route("/projects/:id/submit", post(submit_draft))
+    .route("/projects/:id/organization", patch(patch_project_organization))
+    .route("/projects/:id/history", get(get_project_history))
+    .route("/projects/:id/export", get(export_project))
+    .route("/projects/:id/duplicate", post(post_duplicate))
+    .route("/projects/:id/template", post(post_template))
+    .route("/project-templates", get(get_templates))
+    .route("/project-templates/:id", delete(delete_template))
+    .route("/project-templates/:id/projects", post(post_project_from_template))
+    .route("/projects/:id/transitions", get(get_project_transitions).post(post_project_transition))
 }
 
 async fn post_project(State(state): State<Arc<AppState>>, auth: AuthData, JsonInput(body): JsonInput<CreateParams>) -> Response {
-    let service = ProjectService::new(
-      state.db.users.as_ref(), 
-      state.db.notifications.as_ref(), 
-      state.db.projects.as_ref(), 
-      &auth.token);
-    
+    let service = project_service(&state, &auth.token);
 
     match service.create(body).await {
         Ok(user) => (StatusCode::OK, Json(json!({ "data":  user }))).into_response(),
@@ -35,12 +58,7 @@ async fn post_project(State(state): State<Arc<AppState>>, auth: AuthData, JsonIn
 }
 
 async fn get_project(Path(id): Path<String>, State(state): State<Arc<AppState>>,  auth: AuthData) -> Response {
-  let service = ProjectService::new(
-    state.db.users.as_ref(), 
-    state.db.notifications.as_ref(), 
-    state.db.projects.as_ref(), 
-    &auth.token);
-  
+  let service = project_service(&state, &auth.token);
 
   match service.get_by_id(&id).await {
       Ok(user) => (StatusCode::OK, Json(json!({ "data":  user }))).into_response(),
@@ -48,17 +66,251 @@ async fn get_project(Path(id): Path<String>, State(state): State<Arc<AppState>>,
   }
 }
 
+async fn patch_project(Path(id): Path<String>, State(state): State<Arc<AppState>>, auth: AuthData, JsonInput(body): JsonInput<UpdateParams>) -> Response {
+  let service = project_service(&state, &auth.token);
+
+  match service.update(&id, body).await {
+      Ok(project) => (StatusCode::OK, Json(json!({ "data":  project }))).into_response(),
+      Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+  }
+}
 
 async fn get_projects(Query(params): Query<GetProjectByQueryParams>, State(state): State<Arc<AppState>>, auth: AuthData) -> Response {
-  let service: ProjectService<'_> = ProjectService::new(
-    state.db.users.as_ref(), 
-    state.db.notifications.as_ref(), 
-    state.db.projects.as_ref(), 
-    &auth.token);
-  
+  let service = project_service(&state, &auth.token);
 
   match service.get(&params).await {
       Ok(user) => (StatusCode::OK, Json(json!({ "data":  user }))).into_response(),
       Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
   }
 }
+
+async fn patch_project_organization(Path(id): Path<String>, State(state): State<Arc<AppState>>, auth: AuthData, JsonInput(body): JsonInput<UpdateOrganizationParams>) -> Response {
+  let service = project_service(&state, &auth.token);
+
+  match service.update_organization(&id, body).await {
+      Ok(_) => (StatusCode::OK, Json(json!({ "data":  {} }))).into_response(),
+      Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+  }
+}
+
+async fn post_project_transition(Path(id): Path<String>, State(state): State<Arc<AppState>>, auth: AuthData, JsonInput(body): JsonInput<TransitionParams>) -> Response {
+  let service = project_service(&state, &auth.token);
+
+  match service.transition(&id, body).await {
+      Ok(_) => (StatusCode::OK, Json(json!({ "data":  {} }))).into_response(),
+      Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+  }
+}
+
+async fn get_project_transitions(Path(id): Path<String>, State(state): State<Arc<AppState>>, auth: AuthData) -> Response {
+  let service = project_service(&state, &auth.token);
+
+  match service.get_status_history(&id).await {
+      Ok(history) => (StatusCode::OK, Json(json!({ "data":  history }))).into_response(),
+      Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+  }
+}
+
+async fn get_project_history(Path(id): Path<String>, State(state): State<Arc<AppState>>, auth: AuthData) -> Response {
+  let service = project_service(&state, &auth.token);
+
+  match service.get_history(&id).await {
+      Ok(history) => (StatusCode::OK, Json(json!({ "data":  history }))).into_response(),
+      Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+  }
+}
+
+async fn post_draft(State(state): State<Arc<AppState>>, auth: AuthData, JsonInput(body): JsonInput<CreateDraftParams>) -> Response {
+  let service = project_service(&state, &auth.token);
+
+  match service.create_draft(body).await {
+      Ok(id) => (StatusCode::OK, Json(json!({ "data":  id }))).into_response(),
+      Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+  }
+}
+
+async fn get_drafts(State(state): State<Arc<AppState>>, auth: AuthData) -> Response {
+  let service = project_service(&state, &auth.token);
+
+  match service.get_drafts().await {
+      Ok(drafts) => (StatusCode::OK, Json(json!({ "data":  drafts }))).into_response(),
+      Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+  }
+}
+
+async fn get_draft(Path(id): Path<String>, State(state): State<Arc<AppState>>, auth: AuthData) -> Response {
+  let service = project_service(&state, &auth.token);
+
+  match service.get_draft(&id).await {
+      Ok(draft) => (StatusCode::OK, Json(json!({ "data":  draft }))).into_response(),
+      Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+  }
+}
+
+async fn patch_draft(Path(id): Path<String>, State(state): State<Arc<AppState>>, auth: AuthData, JsonInput(body): JsonInput<UpdateParams>) -> Response {
+  let service = project_service(&state, &auth.token);
+
+  match service.update_draft(&id, body).await {
+      Ok(draft) => (StatusCode::OK, Json(json!({ "data":  draft }))).into_response(),
+      Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+  }
+}
+
+async fn submit_draft(Path(id): Path<String>, State(state): State<Arc<AppState>>, auth: AuthData) -> Response {
+  let service = project_service(&state, &auth.token);
+
+  match service.submit(&id).await {
+      Ok(_) => (StatusCode::OK, Json(json!({ "data":  {} }))).into_response(),
+      Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+  }
+}
+
+async fn delete_project(Path(id): Path<String>, State(state): State<Arc<AppState>>, auth: AuthData) -> Response {
+  let service = project_service(&state, &auth.token);
+
+  match service.delete(&id).await {
+      Ok(_) => (StatusCode::OK, Json(json!({ "data":  {} }))).into_response(),
+      Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+  }
+}
+
+async fn put_archive(Path(id): Path<String>, State(state): State<Arc<AppState>>, auth: AuthData) -> Response {
+  let service = project_service(&state, &auth.token);
+
+  match service.archive(&id, true).await {
+      Ok(_) => (StatusCode::OK, Json(json!({ "data":  {} }))).into_response(),
+      Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+  }
+}
+
+async fn delete_archive(Path(id): Path<String>, State(state): State<Arc<AppState>>, auth: AuthData) -> Response {
+  let service = project_service(&state, &auth.token);
+
+  match service.archive(&id, false).await {
+      Ok(_) => (StatusCode::OK, Json(json!({ "data":  {} }))).into_response(),
+      Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+  }
+}
+
+async fn put_watch(Path(id): Path<String>, State(state): State<Arc<AppState>>, auth: AuthData) -> Response {
+  let service = project_service(&state, &auth.token);
+
+  match service.watch(&id).await {
+      Ok(_) => (StatusCode::OK, Json(json!({ "data":  {} }))).into_response(),
+      Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+  }
+}
+
+async fn delete_watch(Path(id): Path<String>, State(state): State<Arc<AppState>>, auth: AuthData) -> Response {
+  let service = project_service(&state, &auth.token);
+
+  match service.unwatch(&id).await {
+      Ok(_) => (StatusCode::OK, Json(json!({ "data":  {} }))).into_response(),
+      Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+  }
+}
+
+async fn get_watched_projects(State(state): State<Arc<AppState>>, auth: AuthData) -> Response {
+  let service = project_service(&state, &auth.token);
+
+  match service.get_watched().await {
+      Ok(projects) => (StatusCode::OK, Json(json!({ "data":  projects }))).into_response(),
+      Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+  }
+}
+
+async fn export_project(Path(id): Path<String>, Query(export): Query<ExportParams>, State(state): State<Arc<AppState>>, auth: AuthData) -> Response {
+  let service = project_service(&state, &auth.token);
+
+  match service.export_pdf(&id, &export).await {
+      Ok((project, data)) => (
+        StatusCode::OK,
+        [
+          (header::CONTENT_TYPE, "application/pdf".to_string()),
+          (header::CONTENT_DISPOSITION, format!("attachment; filename=\"project-{}.pdf\"", project.id)),
+        ],
+        data,
+      ).into_response(),
+      Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+  }
+}
+
+async fn export_projects(Query(params): Query<GetProjectByQueryParams>, Query(export): Query<ExportParams>, State(state): State<Arc<AppState>>, auth: AuthData) -> Response {
+  let service = project_service(&state, &auth.token);
+
+  let (first, next) = match service.export_csv(&params, &export, 0).await {
+      Ok(chunk) => chunk,
+      Err(err) => return (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+  };
+
+  let rest = stream::unfold(next, move |skip| {
+    let state = state.clone();
+    let token = auth.token.clone();
+    let params = params.clone();
+    let export = export.clone();
+    async move {
+      let service = project_service(&state, &token);
+      match service.export_csv(&params, &export, skip?).await {
+          Ok((chunk, next)) => Some((Ok(chunk), next)),
+          Err(err) => {
+            eprintln!("project export error: {}", err.message);
+            Some((Err(std::io::Error::other(err.message)), None))
+          }
+      }
+    }
+  });
+
+  (
+    StatusCode::OK,
+    [
+      (header::CONTENT_TYPE, "text/csv; charset=utf-8"),
+      (header::CONTENT_DISPOSITION, "attachment; filename=\"projects.csv\""),
+    ],
+    StreamBody::new(stream::once(async move { Ok(first) }).chain(rest)),
+  ).into_response()
+}
+
+async fn post_duplicate(Path(id): Path<String>, State(state): State<Arc<AppState>>, auth: AuthData, JsonInput(body): JsonInput<UpdateParams>) -> Response {
+  let service = project_service(&state, &auth.token);
+
+  match service.duplicate(&id, body).await {
+      Ok(id) => (StatusCode::OK, Json(json!({ "data":  id }))).into_response(),
+      Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+  }
+}
+
+async fn post_template(Path(id): Path<String>, State(state): State<Arc<AppState>>, auth: AuthData, JsonInput(body): JsonInput<SaveTemplateParams>) -> Response {
+  let service = project_service(&state, &auth.token);
+
+  match service.save_template(&id, body).await {
+      Ok(id) => (StatusCode::OK, Json(json!({ "data":  id }))).into_response(),
+      Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+  }
+}
+
+async fn get_templates(State(state): State<Arc<AppState>>, auth: AuthData) -> Response {
+  let service = project_service(&state, &auth.token);
+
+  match service.get_templates().await {
+      Ok(templates) => (StatusCode::OK, Json(json!({ "data":  templates }))).into_response(),
+      Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+  }
+}
+
+async fn delete_template(Path(id): Path<String>, State(state): State<Arc<AppState>>, auth: AuthData) -> Response {
+  let service = project_service(&state, &auth.token);
+
+  match service.delete_template(&id).await {
+      Ok(_) => (StatusCode::OK, Json(json!({ "data":  {} }))).into_response(),
+      Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+  }
+}
+
+async fn post_project_from_template(Path(id): Path<String>, State(state): State<Arc<AppState>>, auth: AuthData, JsonInput(body): JsonInput<UpdateParams>) -> Response {
+  let service = project_service(&state, &auth.token);
+
+  match service.create_from_template(&id, body).await {
+      Ok(id) => (StatusCode::OK, Json(json!({ "data":  id }))).into_response(),
+      Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+  }
+}
@@ -0,0 +1,82 @@
+use axum::{
+    body::Body,
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::{get, post},
+    Json, Router,
+};
+use serde_json::json;
+use std::sync::Arc;
+
+use crate::{
+    app::services::review::{CreateReviewParams, ReplyParams, ReviewService},
+    AppState,
+};
+
+use super::extra::{extract::AuthData, json_validate_rejection::JsonInput};
+
+fn review_service<'a>(state: &'a AppState, token: &'a str) -> ReviewService<'a> {
+    ReviewService::new(
+        state.db.users.as_ref(),
+        state.db.notifications.as_ref(),
+        state.db.projects.as_ref(),
+        state.db.organizations.as_ref(),
+        state.db.preferences.as_ref(),
+        state.db.reviews.as_ref(),
+        token,
+    )
+}
+
+pub fn build_routes() -> Router<Arc<AppState>, Body> {
+    Router::new()
+        .route("/projects/:id/review", get(get_project_review).post(post_review))
+        .route("/users/:id/reviews", get(get_vendor_reviews))
+        .route("/reviews/:id/reply", post(post_reply))
+}
+
+async fn post_review(
+    Path(id): Path<String>,
+    State(state): State<Arc<AppState>>,
+    auth: AuthData,
+    JsonInput(body): JsonInput<CreateReviewParams>,
+) -> Response {
+    let service = review_service(&state, &auth.token);
+
+    match service.create(&id, body).await {
+        Ok(data) => (StatusCode::OK, Json(json!({ "data":  data }))).into_response(),
+        Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+    }
+}
+
+async fn get_project_review(Path(id): Path<String>, State(state): State<Arc<AppState>>, auth: AuthData) -> Response {
+    let service = review_service(&state, &auth.token);
+
+    match service.get_by_project(&id).await {
+        Ok(data) => (StatusCode::OK, Json(json!({ "data":  data }))).into_response(),
+        Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+    }
+}
+
+async fn get_vendor_reviews(Path(id): Path<String>, State(state): State<Arc<AppState>>, auth: AuthData) -> Response {
+    let service = review_service(&state, &auth.token);
+
+    match service.get_by_vendor(&id).await {
+        Ok(data) => (StatusCode::OK, Json(json!({ "data":  data }))).into_response(),
+        Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+    }
+}
+
+async fn post_reply(
+    Path(id): Path<i32>,
+    State(state): State<Arc<AppState>>,
+    auth: AuthData,
+    JsonInput(body): JsonInput<ReplyParams>,
+) -> Response {
+    let service = review_service(&state, &auth.token);
+
+    match service.reply(id, body).await {
+        Ok(_) => (StatusCode::OK, Json(json!({ "data":  {} }))).into_response(),
+        Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+    }
+}
@@ -0,0 +1,68 @@
+use axum::{
+    body::Body,
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::{get, patch},
+    Json, Router,
+};
+use serde_json::json;
+use std::sync::Arc;
+
+use crate::{
+    app::services::saved_search::{CreateSavedSearchParams, SavedSearchService, UpdateSavedSearchParams},
+    AppState,
+};
+
+use super::extra::{extract::AuthData, json_validate_rejection::JsonInput};
+
+fn saved_search_service<'a>(state: &'a AppState, token: &'a str) -> SavedSearchService<'a> {
+    SavedSearchService::new(state.db.users.as_ref(), state.db.saved_searches.as_ref(), token)
+}
+
+pub fn build_routes() -> Router<Arc<AppState>, Body> {
+    Router::new()
+        .route("/saved-searches", get(get_saved_searches).post(post_saved_search))
+        .route("/saved-searches/:id", patch(patch_saved_search).delete(delete_saved_search))
+}
+
+async fn get_saved_searches(State(state): State<Arc<AppState>>, auth: AuthData) -> Response {
+    let service = saved_search_service(&state, &auth.token);
+
+    match service.get_all().await {
+        Ok(searches) => (StatusCode::OK, Json(json!({ "data":  searches }))).into_response(),
+        Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+    }
+}
+
+async fn post_saved_search(State(state): State<Arc<AppState>>, auth: AuthData, JsonInput(body): JsonInput<CreateSavedSearchParams>) -> Response {
+    let service = saved_search_service(&state, &auth.token);
+
+    match service.create(body).await {
+        Ok(search) => (StatusCode::OK, Json(json!({ "data":  search }))).into_response(),
+        Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+    }
+}
+
+async fn patch_saved_search(
+    Path(id): Path<String>,
+    State(state): State<Arc<AppState>>,
+    auth: AuthData,
+    JsonInput(body): JsonInput<UpdateSavedSearchParams>,
+) -> Response {
+    let service = saved_search_service(&state, &auth.token);
+
+    match service.update(&id, body).await {
+        Ok(search) => (StatusCode::OK, Json(json!({ "data":  search }))).into_response(),
+        Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+    }
+}
+
+async fn delete_saved_search(Path(id): Path<String>, State(state): State<Arc<AppState>>, auth: AuthData) -> Response {
+    let service = saved_search_service(&state, &auth.token);
+
+    match service.delete(&id).await {
+        Ok(_) => (StatusCode::OK, Json(json!({ "data":  {} }))).into_response(),
+        Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+    }
+}
@@ -1,20 +1,23 @@
-use crate::{app::services::user::{UserService, GetAllParams}, AppState};
+use crate::{app::services::{profile::{ProfileService, UpdateLocationParams}, user::{UserService, GetAllParams}}, AppState};
 use axum::{
     body::Body,
-    extract::{State, Query},
+    extract::{Path, State, Query},
     http::StatusCode,
     response::{IntoResponse, Response},
-    routing::get,
+    routing::{get, put},
     Json, Router,
 };
 use serde_json::json;
 use std::sync::Arc;
 
-use super::extra::extract::AuthData;
+use super::extra::{extract::AuthData, json_validate_rejection::JsonInput};
 
 pub fn build_routes() -> Router<Arc<AppState>, Body> {
-    Router::new().route("/users/current", get(get_current_user)).route("/users", get(get_all))
-    
+    Router::new()
+        .route("/users/current", get(get_current_user))
+        .route("/users/current/location", put(put_location))
+        .route("/users", get(get_all))
+        .route("/users/:id", get(get_profile))
 }
 
 async fn get_current_user(State(state): State<Arc<AppState>>, auth: AuthData) -> Response {
@@ -33,4 +36,31 @@ async fn get_all(Query(params): Query<GetAllParams>, State(state): State<Arc<App
         Ok(user) => (StatusCode::OK, Json(json!({ "data":  user }))).into_response(),
         Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
     }
-}
\ No newline at end of file
+}
+
+fn profile_service<'a>(state: &'a AppState, token: &'a str) -> ProfileService<'a> {
+    ProfileService::new(
+        state.db.users.as_ref(),
+        state.db.projects.as_ref(),
+        state.db.reviews.as_ref(),
+        token,
+    )
+}
+
+async fn get_profile(Path(id): Path<String>, State(state): State<Arc<AppState>>, auth: AuthData) -> Response {
+    let service = profile_service(&state, &auth.token);
+
+    match service.get_by_id(&id).await {
+        Ok(profile) => (StatusCode::OK, Json(json!({ "data":  profile }))).into_response(),
+        Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+    }
+}
+
+async fn put_location(State(state): State<Arc<AppState>>, auth: AuthData, JsonInput(body): JsonInput<UpdateLocationParams>) -> Response {
+    let service = profile_service(&state, &auth.token);
+
+    match service.update_location(body).await {
+        Ok(location) => (StatusCode::OK, Json(json!({ "data":  location }))).into_response(),
+        Err(err) => (StatusCode::BAD_REQUEST, Json(json!({ "data":  err }))).into_response(),
+    }
+}
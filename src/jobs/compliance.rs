@@ -0,0 +1,32 @@
+use std::{sync::Arc, time::Duration};
+
+use crate::{app::services::compliance::ComplianceMonitor, AppState};
+
+const INTERVAL: Duration = Duration::from_secs(24 * 60 * 60);
+
+pub fn spawn(state: Arc<AppState>) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(INTERVAL);
+        loop {
+            interval.tick().await;
+            run(&state).await;
+        }
+    });
+}
+
+async fn run(state: &AppState) {
+    let monitor = ComplianceMonitor::new(
+        state.db.users.as_ref(),
+        state.db.notifications.as_ref(),
+        state.db.compliance.as_ref(),
+        state.db.preferences.as_ref(),
+    );
+
+    if let Err(err) = monitor.send_reminders().await {
+        eprintln!("compliance reminders error: {}", err.message);
+    }
+
+    if let Err(err) = monitor.expire_documents().await {
+        eprintln!("compliance expiry error: {}", err.message);
+    }
+}
@@ -0,0 +1,22 @@
+use std::sync::Arc;
+
+use crate::{app::services::gazetteer::GazetteerLoader, AppState};
+
+pub fn spawn(state: Arc<AppState>) {
+    let path = match std::env::var("GAZETTEER_FILE_PATH") {
+        Ok(path) if !path.is_empty() => path,
+        _ => return,
+    };
+
+    tokio::spawn(async move {
+        let loader = GazetteerLoader::new(
+            state.db.gazetteer.as_ref(),
+            state.db.projects.as_ref(),
+            state.db.users.as_ref(),
+        );
+
+        if let Err(err) = loader.load(&path).await {
+            eprintln!("gazetteer load error: {}", err.message);
+        }
+    });
+}
@@ -0,0 +1,17 @@
+use std::sync::Arc;
+
+use crate::AppState;
+
+mod compliance;
+mod gazetteer;
+mod project;
+mod retention;
+mod saved_search;
+
+pub fn spawn(state: Arc<AppState>) {
+    compliance::spawn(state.clone());
+    gazetteer::spawn(state.clone());
+    project::spawn(state.clone());
+    retention::spawn(state.clone());
+    saved_search::spawn(state);
+}
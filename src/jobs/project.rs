@@ -0,0 +1,43 @@
+use std::{sync::Arc, time::Duration};
+
+use crate::{
+    app::services::{milestone::MilestoneMonitor, project::ProjectMonitor},
+    AppState,
+};
+
+const INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+pub fn spawn(state: Arc<AppState>) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(INTERVAL);
+        loop {
+            interval.tick().await;
+            run(&state).await;
+        }
+    });
+}
+
+async fn run(state: &AppState) {
+    let monitor = ProjectMonitor::new(
+        state.db.users.as_ref(),
+        state.db.notifications.as_ref(),
+        state.db.projects.as_ref(),
+        state.db.preferences.as_ref(),
+    );
+
+    if let Err(err) = monitor.mark_overdue().await {
+        eprintln!("project overdue error: {}", err.message);
+    }
+
+    let monitor = MilestoneMonitor::new(
+        state.db.users.as_ref(),
+        state.db.notifications.as_ref(),
+        state.db.projects.as_ref(),
+        state.db.preferences.as_ref(),
+        state.db.milestones.as_ref(),
+    );
+
+    if let Err(err) = monitor.notify_slipped().await {
+        eprintln!("milestone slip error: {}", err.message);
+    }
+}
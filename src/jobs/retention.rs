@@ -0,0 +1,40 @@
+use std::{sync::Arc, time::Duration};
+
+use crate::{app::services::project::ProjectMonitor, AppState};
+
+const INTERVAL: Duration = Duration::from_secs(24 * 60 * 60);
+const DEFAULT_RETENTION_DAYS: i32 = 30;
+
+pub fn spawn(state: Arc<AppState>) {
+    let retention_days = std::env::var("PROJECT_RETENTION_DAYS")
+        .ok()
+        .and_then(|days| days.parse::<i32>().ok())
+        .filter(|days| *days >= 1)
+        .unwrap_or(DEFAULT_RETENTION_DAYS);
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(INTERVAL);
+        loop {
+            interval.tick().await;
+            run(&state, retention_days).await;
+        }
+    });
+}
+
+async fn run(state: &AppState, retention_days: i32) {
+    let monitor = ProjectMonitor::new(
+        state.db.users.as_ref(),
+        state.db.notifications.as_ref(),
+        state.db.projects.as_ref(),
+        state.db.preferences.as_ref(),
+    );
+
+    match monitor.purge_deleted(retention_days).await {
+        Ok(failed) => {
+            for id in failed {
+                eprintln!("project {id} files cleanup error");
+            }
+        }
+        Err(err) => eprintln!("project retention error: {}", err.message),
+    }
+}
@@ -0,0 +1,40 @@
+use std::{sync::Arc, time::Duration};
+
+use crate::{app::services::saved_search::SavedSearchMonitor, AppState};
+
+const ALERT_INTERVAL: Duration = Duration::from_secs(60);
+const DIGEST_INTERVAL: Duration = Duration::from_secs(24 * 60 * 60);
+
+pub fn spawn(state: Arc<AppState>) {
+    let digest_state = state.clone();
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(ALERT_INTERVAL);
+        loop {
+            interval.tick().await;
+            if let Err(err) = monitor(&state).alert_new_projects().await {
+                eprintln!("saved search alert error: {}", err.message);
+            }
+        }
+    });
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval_at(tokio::time::Instant::now() + DIGEST_INTERVAL, DIGEST_INTERVAL);
+        loop {
+            interval.tick().await;
+            if let Err(err) = monitor(&digest_state).send_digests().await {
+                eprintln!("saved search digest error: {}", err.message);
+            }
+        }
+    });
+}
+
+fn monitor(state: &AppState) -> SavedSearchMonitor<'_> {
+    SavedSearchMonitor::new(
+        state.db.users.as_ref(),
+        state.db.notifications.as_ref(),
+        state.db.projects.as_ref(),
+        state.db.saved_searches.as_ref(),
+        state.db.preferences.as_ref(),
+    )
+}
@@ -2,7 +2,7 @@ use axum::{http::Method, Router};
 use app::entities::notification::Notification;
 use db::DB;
 use dotenv::dotenv;
-use handlers::{auth, conversation, notification, user, ws, project};
+use handlers::{admin, attachment, auth, bid, compliance, conversation, matching, milestone, notification, organization, preferences, review, saved_search, user, ws, project};
 use serde::Serialize;
 use std::{net::SocketAddr, sync::Arc};
 use tokio::sync::broadcast;
@@ -11,6 +11,7 @@ use tower_http::cors::{Any, CorsLayer};
 mod app;
 mod db;
 mod handlers;
+mod jobs;
 
 #[derive(Debug, Clone, Serialize)]
 pub enum Event {
@@ -42,6 +43,8 @@ async fn main() {
     let (tx, _rx) = broadcast::channel(100);
     let app_state = Arc::new(AppState { db, tx });
 
+    jobs::spawn(app_state.clone());
+
     let app = Router::new()
         .merge(auth::build_routes())
         .merge(conversation::build_routes())
@@ -49,6 +52,16 @@ async fn main() {
         .merge(ws::build_routes())
         .merge(notification::build_routes())
         .merge(project::build_routes())
+        .merge(compliance::build_routes())
+        .merge(admin::build_routes())
+        .merge(preferences::build_routes())
+        .merge(organization::build_routes())
+        .merge(review::build_routes())
+        .merge(attachment::build_routes())
+        .merge(bid::build_routes())
+        .merge(milestone::build_routes())
+        .merge(matching::build_routes())
+        .merge(saved_search::build_routes())
         .layer(cors)
         .with_state(app_state);
 
@@ -0,0 +1,24 @@
+use tokio_postgres::NoTls;
+
+#[tokio::test]
+async fn schema_applies_cleanly_and_is_idempotent() {
+    let url = match std::env::var("DATABASE_URL") {
+        Ok(url) => url,
+        Err(_) => {
+            eprintln!("DATABASE_URL is not set, skipping schema test");
+            return;
+        }
+    };
+
+    let (client, connection) = tokio_postgres::connect(&url, NoTls).await.expect("Database not created");
+    tokio::spawn(async move {
+        if let Err(e) = connection.await {
+            eprintln!("connection error: {}", e);
+        }
+    });
+
+    let schema = std::fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/schema.sql")).expect("Not read file");
+
+    client.batch_execute(&schema).await.expect("schema should apply to an empty database");
+    client.batch_execute(&schema).await.expect("schema should apply again without changes");
+}